@@ -0,0 +1,491 @@
+use crate::{Account, AccountSnapshot, Deal, EntrustType, Money, Price};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 年化交易日数
+pub const TRADE_DAYS_PER_YEAR: f64 = 252.0;
+
+/// 回测绩效
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct Performance {
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+    // 交易日数
+    pub days: usize,
+
+    // 初始资金
+    pub cash_init: f64,
+    // 期末净值
+    pub net_value: f64,
+    // 总收益率
+    pub total_return: f64,
+    // 年化收益率
+    pub annual_return: f64,
+    // 年化波动率
+    pub volatility: f64,
+    // 夏普比率
+    pub sharpe: f64,
+    // 索提诺比率
+    pub sortino: f64,
+    // 最大回撤
+    pub max_drawdown: f64,
+    // 最大回撤所在回撤区间的持续交易日数(从高点到恢复, 未恢复则到期末)
+    pub max_drawdown_days: usize,
+    // 最大回撤开始日期(高点)
+    pub max_drawdown_start: Option<NaiveDate>,
+    // 最大回撤结束日期(低点)
+    pub max_drawdown_end: Option<NaiveDate>,
+
+    // 信号数
+    pub signal_count: usize,
+    // 成交笔数
+    pub deal_count: usize,
+    // 平仓笔数
+    pub close_count: usize,
+    // 胜率
+    pub win_rate: f64,
+    // 盈亏比, 总盈利 / 总亏损, 无亏损平仓时为空
+    pub profit_factor: Option<f64>,
+    // 平均持仓天数
+    pub avg_holding_days: f64,
+    // 换手率, 成交额 / 2 / 平均净值
+    pub turnover: f64,
+    // 手续费
+    pub fee: f64,
+
+    // 相对基准绩效
+    pub benchmark: Option<BenchmarkPerformance>,
+}
+
+/// 基准对比点, 基准净值按首个对比日的账户净值折算
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct BenchmarkPoint {
+    pub date: Option<NaiveDate>,
+    // 账户净值
+    pub net_value: f64,
+    // 基准净值
+    pub benchmark_value: f64,
+}
+
+/// 相对基准绩效, 账户与基准均以首个有基准价格的交易日收盘为基数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct BenchmarkPerformance {
+    // 基准代码
+    pub code: String,
+    // 基准总收益率
+    pub total_return: f64,
+    // 基准年化收益率
+    pub annual_return: f64,
+    // 超额收益率, 同一区间账户收益率 - 基准总收益率
+    pub excess_return: f64,
+    // 年化 alpha
+    pub alpha: f64,
+    pub beta: f64,
+    // 年化跟踪误差
+    pub tracking_error: f64,
+    // 信息比率
+    pub information_ratio: f64,
+    // 与账户资金曲线对齐的基准净值
+    pub series: Vec<BenchmarkPoint>,
+}
+
+/// 资金曲线按交易日取收盘净值
+pub fn daily_net_value(snapshot: &[AccountSnapshot]) -> BTreeMap<NaiveDate, f64> {
+    snapshot
+        .iter()
+        .filter_map(|s| s.time.map(|t| (t.date(), s.net_value.to_f64())))
+        .collect()
+}
+
+/// 净值序列转收益率序列, `base` 为首个净值的前值
+pub fn returns(base: f64, values: &[f64]) -> Vec<f64> {
+    let mut pre = base;
+    values
+        .iter()
+        .map(|v| {
+            let r = if pre > 0.0 { v / pre - 1.0 } else { 0.0 };
+            pre = *v;
+            r
+        })
+        .collect()
+}
+
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// 样本标准差
+pub fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let m = mean(values);
+    let var = values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    var.sqrt()
+}
+
+impl Performance {
+    /// 由账户(资金曲线/成交/信号)计算绩效, `risk_free` 为年化无风险利率
+    pub fn from_account(account: &Account, risk_free: f64) -> Self {
+        let mut perf = Self {
+            cash_init: account.cash_init.to_f64(),
+            signal_count: account.signal.len(),
+            ..Default::default()
+        };
+        let daily = daily_net_value(&account.snapshot);
+        perf.analyze_equity(&daily, risk_free);
+        let amount = perf.analyze_deal(&account.deal);
+
+        let avg_net_value = mean(&daily.values().copied().collect::<Vec<_>>());
+        if avg_net_value > 0.0 {
+            perf.turnover = amount / 2.0 / avg_net_value;
+        }
+        if let Some(code) = &account.benchmark {
+            perf.benchmark =
+                BenchmarkPerformance::from_snapshot(code, &account.snapshot, risk_free);
+        }
+        perf
+    }
+
+    fn analyze_equity(&mut self, daily: &BTreeMap<NaiveDate, f64>, risk_free: f64) {
+        if daily.is_empty() {
+            return;
+        }
+        let dates: Vec<NaiveDate> = daily.keys().copied().collect();
+        let values: Vec<f64> = daily.values().copied().collect();
+
+        self.start = dates.first().copied();
+        self.end = dates.last().copied();
+        self.days = values.len();
+        self.net_value = *values.last().unwrap();
+
+        let base = if self.cash_init > 0.0 {
+            self.cash_init
+        } else {
+            values[0]
+        };
+        if base > 0.0 {
+            self.total_return = self.net_value / base - 1.0;
+            self.annual_return =
+                (1.0 + self.total_return).powf(TRADE_DAYS_PER_YEAR / self.days as f64) - 1.0;
+        }
+
+        let rs = returns(base, &values);
+        let rf = risk_free / TRADE_DAYS_PER_YEAR;
+        let std = std_dev(&rs);
+        self.volatility = std * TRADE_DAYS_PER_YEAR.sqrt();
+        let excess = mean(&rs) - rf;
+        if std > 0.0 {
+            self.sharpe = excess / std * TRADE_DAYS_PER_YEAR.sqrt();
+        }
+        let downside = (rs.iter().map(|r| (r - rf).min(0.0).powi(2)).sum::<f64>()
+            / rs.len() as f64)
+            .sqrt();
+        if downside > 0.0 {
+            self.sortino = excess / downside * TRADE_DAYS_PER_YEAR.sqrt();
+        }
+
+        // 当前回撤区间包含最大回撤时, 以该区间的持续时间作为最大回撤持续时间
+        let (mut peak, mut peak_index) = (base, None);
+        let mut drawdown_start: Option<usize> = None;
+        let mut is_max = false;
+        for (i, v) in values.iter().enumerate() {
+            if *v >= peak {
+                if let Some(start) = drawdown_start.take() {
+                    if is_max {
+                        self.max_drawdown_days = i - start;
+                        is_max = false;
+                    }
+                }
+                peak = *v;
+                peak_index = Some(i);
+                continue;
+            }
+            let start = *drawdown_start.get_or_insert(peak_index.unwrap_or(0));
+            let dd = (peak - v) / peak;
+            if dd > self.max_drawdown {
+                self.max_drawdown = dd;
+                self.max_drawdown_start = Some(dates[start]);
+                self.max_drawdown_end = Some(dates[i]);
+                is_max = true;
+            }
+        }
+        if let Some(start) = drawdown_start {
+            if is_max {
+                self.max_drawdown_days = values.len() - 1 - start;
+            }
+        }
+    }
+
+    /// 统计成交, 返回成交额
+    fn analyze_deal(&mut self, deals: &[Deal]) -> f64 {
+        self.deal_count = deals.len();
+
+        let (mut win, mut gain, mut loss) = (0, Money::ZERO, Money::ZERO);
+        let (mut holding_days, mut holding_volume) = (0.0, 0.0);
+        let (mut amount, mut fee) = (Money::ZERO, Money::ZERO);
+        for deal in deals {
+            amount += deal.price * deal.volume;
+            fee += deal.fee;
+            match deal.deal_type {
+                EntrustType::Sell => {
+                    self.close_count += 1;
+                    if deal.profit > Money::ZERO {
+                        win += 1;
+                        gain += deal.profit;
+                    } else {
+                        loss -= deal.profit;
+                    }
+
+                    // 持有天数按平仓批次加权
+                    for lot in deal.close_lots.iter() {
+                        if lot.open_time.is_some() && lot.close_time.is_some() {
+                            holding_days += lot.holding_days * lot.volume as f64;
+                            holding_volume += lot.volume as f64;
+                        }
+                    }
+                }
+                EntrustType::Buy | EntrustType::Cancel => {}
+            }
+        }
+        if self.close_count > 0 {
+            self.win_rate = win as f64 / self.close_count as f64;
+        }
+        self.fee = fee.to_f64();
+        if loss > Money::ZERO {
+            self.profit_factor = Some(gain / loss);
+        }
+        if holding_volume > 0.0 {
+            self.avg_holding_days = holding_days / holding_volume;
+        }
+        amount.to_f64()
+    }
+}
+
+impl BenchmarkPerformance {
+    /// 由资金曲线中记录的基准价格计算, 无基准价格返回空
+    pub fn from_snapshot(code: &str, snapshot: &[AccountSnapshot], risk_free: f64) -> Option<Self> {
+        let daily: BTreeMap<NaiveDate, (f64, f64)> = snapshot
+            .iter()
+            .filter(|s| s.benchmark > Price::ZERO)
+            .filter_map(|s| {
+                s.time
+                    .map(|t| (t.date(), (s.net_value.to_f64(), s.benchmark.to_f64())))
+            })
+            .collect();
+        if daily.is_empty() {
+            return None;
+        }
+        let values: Vec<f64> = daily.values().map(|(v, _)| *v).collect();
+        let prices: Vec<f64> = daily.values().map(|(_, p)| *p).collect();
+        let (base, scale) = (prices[0], values[0]);
+
+        let mut perf = Self {
+            code: code.to_string(),
+            total_return: prices[prices.len() - 1] / base - 1.0,
+            series: daily
+                .iter()
+                .map(|(date, (v, p))| BenchmarkPoint {
+                    date: Some(*date),
+                    net_value: *v,
+                    benchmark_value: scale * p / base,
+                })
+                .collect(),
+            ..Default::default()
+        };
+        perf.annual_return =
+            (1.0 + perf.total_return).powf(TRADE_DAYS_PER_YEAR / prices.len() as f64) - 1.0;
+        if scale > 0.0 {
+            perf.excess_return = values[values.len() - 1] / scale - 1.0 - perf.total_return;
+        }
+
+        let ra = returns(scale, &values[1..]);
+        let rb = returns(base, &prices[1..]);
+        if ra.len() < 2 {
+            return Some(perf);
+        }
+        let rf = risk_free / TRADE_DAYS_PER_YEAR;
+        let (ma, mb) = (mean(&ra), mean(&rb));
+        let cov = ra
+            .iter()
+            .zip(rb.iter())
+            .map(|(a, b)| (a - ma) * (b - mb))
+            .sum::<f64>()
+            / (ra.len() - 1) as f64;
+        let var = std_dev(&rb).powi(2);
+        if var > 0.0 {
+            perf.beta = cov / var;
+        }
+        perf.alpha = ((ma - rf) - perf.beta * (mb - rf)) * TRADE_DAYS_PER_YEAR;
+
+        let active: Vec<f64> = ra.iter().zip(rb.iter()).map(|(a, b)| a - b).collect();
+        let te = std_dev(&active);
+        perf.tracking_error = te * TRADE_DAYS_PER_YEAR.sqrt();
+        if te > 0.0 {
+            perf.information_ratio = mean(&active) / te * TRADE_DAYS_PER_YEAR.sqrt();
+        }
+        Some(perf)
+    }
+}
+
+#[cfg(test)]
+mod test_analytics {
+    use super::Performance;
+    use crate::{
+        Account, AccountSnapshot, ClosedLot, Deal, EntrustType, Money, Price, SnapshotType,
+    };
+    use chrono::NaiveDateTime;
+
+    fn time(s: &str) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok()
+    }
+
+    #[test]
+    fn test_performance() {
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(10000.0);
+        let values = [
+            ("2022-03-01 15:00:00", 10000.0),
+            ("2022-03-02 15:00:00", 11000.0),
+            ("2022-03-03 15:00:00", 9900.0),
+            ("2022-03-04 15:00:00", 10450.0),
+            ("2022-03-07 15:00:00", 11500.0),
+        ];
+        for (t, v) in values {
+            acct.snapshot.push(AccountSnapshot {
+                typ: SnapshotType::QuotEnd,
+                time: time(t),
+                net_value: Money::from(v),
+                ..Default::default()
+            });
+        }
+        let deal = |typ: EntrustType, t, price, volume, profit, holding_days| Deal {
+            code: "sh600063".to_string(),
+            deal_type: typ.clone(),
+            time: time(t),
+            price: Price::from(price),
+            volume,
+            profit: Money::from(profit),
+            fee: Money::from(5.0),
+            close_lots: match typ {
+                EntrustType::Sell => vec![ClosedLot {
+                    open_time: time("2022-03-01 10:00:00"),
+                    close_time: time(t),
+                    volume,
+                    holding_days,
+                    ..Default::default()
+                }],
+                _ => vec![],
+            },
+            ..Default::default()
+        };
+        acct.deal = vec![
+            deal(EntrustType::Buy, "2022-03-01 10:00:00", 10.0, 1000, 0.0, 0.0),
+            deal(EntrustType::Sell, "2022-03-03 10:00:00", 11.0, 500, 490.0, 2.0),
+            deal(EntrustType::Sell, "2022-03-05 10:00:00", 9.0, 500, -510.0, 4.0),
+        ];
+
+        let perf = Performance::from_account(&acct, 0.0);
+        assert_eq!(perf.days, 5);
+        assert!((perf.total_return - 0.15).abs() < 1e-9);
+        assert!((perf.max_drawdown - 0.1).abs() < 1e-9);
+        assert_eq!(perf.max_drawdown_days, 3);
+        assert_eq!(perf.max_drawdown_end, time("2022-03-03 00:00:00").map(|t| t.date()));
+        assert!(perf.volatility > 0.0);
+        assert!(perf.sharpe > 0.0);
+        assert!(perf.sortino > perf.sharpe);
+
+        assert_eq!(perf.close_count, 2);
+        assert!((perf.win_rate - 0.5).abs() < 1e-9);
+        assert!((perf.profit_factor.unwrap() - 490.0 / 510.0).abs() < 1e-9);
+        assert!((perf.avg_holding_days - 3.0).abs() < 1e-9);
+        assert!((perf.fee - 15.0).abs() < 1e-9);
+        assert!((perf.turnover - 20000.0 / 2.0 / 10570.0).abs() < 1e-9);
+
+        let js = serde_json::to_string(&perf).unwrap();
+        let _: Performance = serde_json::from_str(&js).unwrap();
+    }
+
+    #[test]
+    fn test_drawdown_days() {
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(10000.0);
+        // 较长的小回撤(3/01-3/07)之后是较短的最大回撤(3/07-3/09)
+        let values = [
+            ("2022-03-01 15:00:00", 10000.0),
+            ("2022-03-02 15:00:00", 9900.0),
+            ("2022-03-03 15:00:00", 9800.0),
+            ("2022-03-04 15:00:00", 9900.0),
+            ("2022-03-07 15:00:00", 10100.0),
+            ("2022-03-08 15:00:00", 8080.0),
+            ("2022-03-09 15:00:00", 10200.0),
+            ("2022-03-10 15:00:00", 10100.0),
+        ];
+        for (t, v) in values {
+            acct.snapshot.push(AccountSnapshot {
+                time: time(t),
+                net_value: Money::from(v),
+                ..Default::default()
+            });
+        }
+        let perf = Performance::from_account(&acct, 0.0);
+        assert!((perf.max_drawdown - 0.2).abs() < 1e-9);
+        assert_eq!(perf.max_drawdown_start, time("2022-03-07 00:00:00").map(|t| t.date()));
+        assert_eq!(perf.max_drawdown_end, time("2022-03-08 00:00:00").map(|t| t.date()));
+        assert_eq!(perf.max_drawdown_days, 2);
+
+        // 最大回撤未恢复时持续到期末
+        acct.snapshot.truncate(6);
+        let perf = Performance::from_account(&acct, 0.0);
+        assert_eq!(perf.max_drawdown_days, 1);
+    }
+
+    #[test]
+    fn test_benchmark() {
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(10000.0);
+        acct.benchmark = Some("sh000300".to_string());
+        // 账户收益为基准的 2 倍
+        let values = [
+            ("2022-03-01 15:00:00", 10000.0, 4000.0),
+            ("2022-03-02 15:00:00", 10200.0, 4040.0),
+            ("2022-03-03 15:00:00", 9792.0, 3959.2),
+            ("2022-03-04 15:00:00", 10575.36, 4117.568),
+        ];
+        for (t, v, b) in values {
+            acct.snapshot.push(AccountSnapshot {
+                time: time(t),
+                net_value: Money::from(v),
+                benchmark: Price::from(b),
+                ..Default::default()
+            });
+        }
+        let perf = Performance::from_account(&acct, 0.0);
+        let bench = perf.benchmark.unwrap();
+        assert_eq!(bench.code, "sh000300");
+        assert!((bench.beta - 2.0).abs() < 1e-6);
+        assert!(bench.alpha.abs() < 1e-6);
+        assert!(bench.tracking_error > 0.0);
+        assert!(bench.information_ratio > 0.0);
+        assert!((bench.excess_return - (perf.total_return - bench.total_return)).abs() < 1e-9);
+        assert_eq!(bench.series.len(), 4);
+        assert!((bench.series[3].benchmark_value - 10293.92).abs() < 1e-6);
+
+        // 首日已有盈亏, 账户与基准同样自首日收盘起算
+        for s in acct.snapshot.iter_mut() {
+            s.net_value = s.net_value * 1.01;
+        }
+        let perf = Performance::from_account(&acct, 0.0);
+        let bench = perf.benchmark.unwrap();
+        assert!((bench.excess_return - (0.057536 - 0.029392)).abs() < 1e-9);
+        assert!((bench.series[0].benchmark_value - 10100.0).abs() < 1e-6);
+        assert!((bench.beta - 2.0).abs() < 1e-6);
+    }
+}
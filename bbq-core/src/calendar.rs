@@ -0,0 +1,280 @@
+use crate::data::MarketDataStore;
+use crate::fetch::Fetcher;
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+// 用于生成交易日的指数
+const CALENDAR_INDEX: &str = "sh000001";
+
+/// 交易时段, 左闭右闭
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct Session {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl Session {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains(&self, time: &NaiveTime) -> bool {
+        *time >= self.start && *time <= self.end
+    }
+}
+
+/// 交易日历
+///
+/// 日期超出日历范围时按工作日推断, 早于日历第一天的日期均不是交易日;
+/// 默认交易时段为 09:30-11:30, 13:00-15:00, 半日市等特殊时段单独设置
+#[derive(Debug, Clone)]
+pub struct TradeCalendar {
+    days: BTreeSet<NaiveDate>,
+    sessions: Vec<Session>,
+    // 特殊交易时段, 如半日市
+    special: BTreeMap<NaiveDate, Vec<Session>>,
+}
+
+impl Default for TradeCalendar {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl TradeCalendar {
+    pub fn new(days: impl IntoIterator<Item = NaiveDate>) -> Self {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        Self {
+            days: days.into_iter().collect(),
+            sessions: vec![
+                Session::new(time(9, 30), time(11, 30)),
+                Session::new(time(13, 0), time(15, 0)),
+            ],
+            special: BTreeMap::new(),
+        }
+    }
+
+    /// 内置交易日历, 截至 2022 年底
+    pub fn builtin() -> Self {
+        Self::parse(include_str!("trade_date.txt")).unwrap()
+    }
+
+    /// 解析交易日, 格式: `19901219,19901220,...`, 分隔符可为逗号或空白
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut days = vec![];
+        for day in s.split(|c: char| c == ',' || c.is_whitespace()) {
+            if day.is_empty() {
+                continue;
+            }
+            let day = NaiveDate::parse_from_str(day, "%Y%m%d")
+                .with_context(|| format!("invalid trade date: {}", day))?;
+            days.push(day);
+        }
+        Ok(Self::new(days))
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let s = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read trade date file: {:?}", path))?;
+        Self::parse(&s)
+    }
+
+    /// 由存储的上证指数日线生成
+    pub async fn from_store(store: &dyn MarketDataStore) -> Result<Self> {
+        let bars = store
+            .index_daily(CALENDAR_INDEX, None, None)
+            .await
+            .with_context(|| "query index daily failed")?;
+        if bars.is_empty() {
+            bail!("no index daily for trade calendar");
+        }
+        Ok(Self::new(bars.iter().map(|bar| bar.time.date())))
+    }
+
+    /// 由行情源上证指数日线生成
+    pub async fn from_fetcher(fetcher: &dyn Fetcher) -> Result<Self> {
+        let bars = fetcher
+            .fetch_index_daily(CALENDAR_INDEX, None, None)
+            .await
+            .with_context(|| "fetch index daily failed")?;
+        if bars.is_empty() {
+            bail!("no index daily for trade calendar");
+        }
+        Ok(Self::new(bars.iter().map(|bar| bar.time.date())))
+    }
+
+    /// 设置默认交易时段
+    pub fn with_sessions(mut self, sessions: Vec<Session>) -> Self {
+        self.sessions = sessions;
+        self
+    }
+
+    /// 设置某日的特殊交易时段
+    pub fn with_special(mut self, date: NaiveDate, sessions: Vec<Session>) -> Self {
+        self.special.insert(date, sessions);
+        self
+    }
+
+    /// 半日市, 仅保留上午时段
+    pub fn with_half_day(self, date: NaiveDate) -> Self {
+        let sessions = self.sessions.iter().take(1).copied().collect();
+        self.with_special(date, sessions)
+    }
+
+    pub fn first(&self) -> Option<NaiveDate> {
+        self.days.iter().next().copied()
+    }
+
+    pub fn last(&self) -> Option<NaiveDate> {
+        self.days.iter().next_back().copied()
+    }
+
+    pub fn is_trading_day(&self, date: NaiveDate) -> bool {
+        match self.last() {
+            Some(last) if date <= last => self.days.contains(&date),
+            _ => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+        }
+    }
+
+    /// 下一交易日, 不含当日
+    pub fn next(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut day = date.succ_opt()?;
+        if let Some(next) = self.days.range(day..).next() {
+            return Some(*next);
+        }
+        while !self.is_trading_day(day) {
+            day = day.succ_opt()?;
+        }
+        Some(day)
+    }
+
+    /// 上一交易日, 不含当日
+    pub fn prev(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut day = date.pred_opt()?;
+        loop {
+            match self.last() {
+                Some(last) if day <= last => {
+                    return self.days.range(..=day).next_back().copied();
+                }
+                _ if self.is_trading_day(day) => return Some(day),
+                _ => day = day.pred_opt()?,
+            }
+        }
+    }
+
+    /// `[start, end]` 内的交易日
+    pub fn trading_days_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        let mut days = vec![];
+        let mut day = if self.is_trading_day(start) {
+            Some(start)
+        } else {
+            self.next(start)
+        };
+        while let Some(d) = day {
+            if d > end {
+                break;
+            }
+            days.push(d);
+            day = self.next(d);
+        }
+        days
+    }
+
+    /// 第 `n` 个交易日, 负数向前; `n` 为 0 时当日须为交易日
+    pub fn offset(&self, date: NaiveDate, n: i32) -> Option<NaiveDate> {
+        if n == 0 {
+            return self.is_trading_day(date).then_some(date);
+        }
+        let mut day = date;
+        for _ in 0..n.unsigned_abs() {
+            day = if n > 0 {
+                self.next(day)?
+            } else {
+                self.prev(day)?
+            };
+        }
+        Some(day)
+    }
+
+    /// 交易时段, 非交易日为空
+    pub fn sessions(&self, date: NaiveDate) -> &[Session] {
+        if !self.is_trading_day(date) {
+            return &[];
+        }
+        self.special.get(&date).unwrap_or(&self.sessions)
+    }
+
+    pub fn is_trading_time(&self, time: &NaiveDateTime) -> bool {
+        self.sessions(time.date())
+            .iter()
+            .any(|session| session.contains(&time.time()))
+    }
+}
+
+#[cfg(test)]
+mod test_calendar {
+    use super::TradeCalendar;
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_calendar() {
+        let cal = TradeCalendar::builtin();
+        assert_eq!(cal.first(), Some(date("1990-12-19")));
+        assert!(cal.is_trading_day(date("2022-03-02")));
+        assert!(cal.is_trading_day(date("2022-03-07")));
+        assert!(!cal.is_trading_day(date("2022-03-06")));
+        assert!(!cal.is_trading_day(date("2022-10-03")));
+        assert!(!cal.is_trading_day(date("1990-12-18")));
+
+        assert_eq!(cal.next(date("2022-03-04")), Some(date("2022-03-07")));
+        assert_eq!(cal.prev(date("2022-03-07")), Some(date("2022-03-04")));
+        assert_eq!(cal.next(date("2022-09-30")), Some(date("2022-10-10")));
+        assert_eq!(cal.prev(date("1990-12-19")), None);
+        assert_eq!(cal.offset(date("2022-03-04"), 2), Some(date("2022-03-08")));
+        assert_eq!(cal.offset(date("2022-03-08"), -2), Some(date("2022-03-04")));
+        assert_eq!(cal.offset(date("2022-03-06"), 0), None);
+        assert_eq!(
+            cal.trading_days_between(date("2022-03-05"), date("2022-03-08")),
+            vec![date("2022-03-07"), date("2022-03-08")]
+        );
+
+        assert!(!cal.is_trading_day(date("2023-01-02")));
+        assert_eq!(cal.next(date("2022-12-30")), Some(date("2023-01-03")));
+        assert!(!cal.is_trading_day(date("2026-10-07")));
+        assert_eq!(cal.last(), Some(date("2026-10-16")));
+
+        // 超出日历范围按工作日推断
+        assert_eq!(cal.next(date("2026-10-16")), Some(date("2026-10-19")));
+        assert_eq!(cal.prev(date("2026-10-19")), Some(date("2026-10-16")));
+        assert!(!cal.is_trading_day(date("2026-10-24")));
+        assert_eq!(cal.offset(date("2026-10-23"), 1), Some(date("2026-10-26")));
+    }
+
+    #[test]
+    fn test_sessions() {
+        let half = date("2022-03-04");
+        let cal = TradeCalendar::parse("20220303,20220304\n20220307")
+            .unwrap()
+            .with_half_day(half);
+        let time = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+
+        assert_eq!(cal.sessions(date("2022-03-03")).len(), 2);
+        assert_eq!(cal.sessions(half).len(), 1);
+        assert!(cal.sessions(date("2022-03-05")).is_empty());
+        assert!(cal.is_trading_time(&time("2022-03-03 14:00:00")));
+        assert!(!cal.is_trading_time(&time("2022-03-03 12:00:00")));
+        assert!(cal.is_trading_time(&time("2022-03-04 10:00:00")));
+        assert!(!cal.is_trading_time(&time("2022-03-04 14:00:00")));
+
+        assert!(TradeCalendar::parse("20220303,2022-03-04").is_err());
+    }
+}
@@ -100,3 +100,46 @@ impl Display for ActionType {
         write!(f, "{}", s)
     }
 }
+
+
+/// 券商同步(资金/持仓)与本地账户不一致时的处理策略
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncPolicy {
+    /// 以券商为准, 覆盖本地
+    #[default]
+    Broker,
+    /// 以本地为准, 仅记录差异
+    Local,
+}
+
+impl Display for SyncPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            SyncPolicy::Broker => "以券商为准",
+            SyncPolicy::Local => "以本地为准",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 卖出时持仓批次的匹配顺序
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LotMethod {
+    /// 先进先出
+    #[default]
+    Fifo,
+    /// 后进先出
+    Lifo,
+}
+
+impl Display for LotMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            LotMethod::Fifo => "先进先出",
+            LotMethod::Lifo => "后进先出",
+        };
+        write!(f, "{}", s)
+    }
+}
@@ -0,0 +1,338 @@
+use crate::data::{DataKind, FqFactor, MarketDataStore, StockIndicator};
+use crate::fetch::{SecurityInfo, StockBar, StockBarList};
+use crate::CorpAction;
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::Path;
+
+/// 本地行情存储(sled), 无需数据库即可回测
+///
+/// - `stock_info`/`index_info`: 代码 -> 证券信息
+/// - `stock_daily`/`index_daily`/`stock_fq_factor`/`stock_corp_action`/`stock_index`:
+///   `代码:yyyymmdd` -> 记录
+#[derive(Clone)]
+pub struct LocalStore {
+    db: sled::Db,
+}
+
+impl LocalStore {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let db = sled::open(path.as_ref())
+            .with_context(|| format!("failed to open local store: {:?}", path.as_ref()))?;
+        Ok(Self { db })
+    }
+
+    fn tree(&self, name: &str) -> Result<sled::Tree> {
+        self.db
+            .open_tree(name)
+            .with_context(|| format!("failed to open tree: {}", name))
+    }
+
+    fn key(code: &str, date: NaiveDate) -> String {
+        format!("{}:{}", code, date.format("%Y%m%d"))
+    }
+
+    fn load_all<T: DeserializeOwned>(&self, name: &str) -> Result<Vec<T>> {
+        let mut list = vec![];
+        for item in self.tree(name)?.iter() {
+            let (_, value) = item.with_context(|| format!("failed to read {}", name))?;
+            list.push(
+                serde_json::from_slice(&value)
+                    .with_context(|| format!("failed to deserialize {}", name))?,
+            );
+        }
+        Ok(list)
+    }
+
+    /// 键按日期有序, 区间扫描即为日期升序
+    fn load_range<T: DeserializeOwned>(
+        &self,
+        name: &str,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<T>> {
+        let lower = match start {
+            Some(start) => Self::key(code, start),
+            None => format!("{}:", code),
+        };
+        let upper = match end {
+            Some(end) => Self::key(code, end),
+            None => format!("{}:99999999", code),
+        };
+        let mut list = vec![];
+        for item in self.tree(name)?.range(lower.as_bytes()..=upper.as_bytes()) {
+            let (_, value) = item.with_context(|| format!("failed to read {}", name))?;
+            list.push(
+                serde_json::from_slice(&value)
+                    .with_context(|| format!("failed to deserialize {}", name))?,
+            );
+        }
+        Ok(list)
+    }
+
+    fn save<'a, T, I>(&self, name: &str, items: I) -> Result<()>
+    where
+        T: Serialize + 'a,
+        I: IntoIterator<Item = (String, &'a T)>,
+    {
+        let tree = self.tree(name)?;
+        let mut batch = sled::Batch::default();
+        for (key, item) in items {
+            let value = serde_json::to_vec(item)
+                .with_context(|| format!("failed to serialize {}", name))?;
+            batch.insert(key.as_bytes(), value);
+        }
+        tree.apply_batch(batch)
+            .with_context(|| format!("failed to save {}", name))?;
+        tree.flush()
+            .with_context(|| format!("failed to flush {}", name))?;
+        Ok(())
+    }
+
+    fn save_daily(&self, name: &str, code: &str, bars: &[StockBar]) -> Result<()> {
+        let bars: Vec<(String, StockBar)> = bars
+            .iter()
+            .map(|bar| {
+                let date = bar.time.date();
+                let mut bar = bar.clone();
+                bar.time = date.and_time(NaiveTime::MIN);
+                (Self::key(code, date), bar)
+            })
+            .collect();
+        self.save(name, bars.iter().map(|(key, bar)| (key.clone(), bar)))
+    }
+}
+
+#[async_trait]
+impl MarketDataStore for LocalStore {
+    async fn last_date(&self, kind: DataKind, code: &str) -> Result<Option<NaiveDate>> {
+        if kind.is_info() {
+            bail!("{} has no trade date", kind.name());
+        }
+        let last = self
+            .tree(kind.name())?
+            .scan_prefix(format!("{}:", code).as_bytes())
+            .next_back();
+        match last {
+            Some(item) => {
+                let (key, _) = item.with_context(|| format!("failed to read {}", kind.name()))?;
+                let key = String::from_utf8_lossy(&key);
+                let date = key.rsplit(':').next().unwrap_or_default();
+                let date = NaiveDate::parse_from_str(date, "%Y%m%d")
+                    .with_context(|| format!("invalid {} key: {}", kind.name(), &key))?;
+                Ok(Some(date))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn stock_info(&self) -> Result<Vec<SecurityInfo>> {
+        self.load_all("stock_info")
+    }
+
+    async fn index_info(&self) -> Result<Vec<SecurityInfo>> {
+        self.load_all("index_info")
+    }
+
+    async fn stock_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        self.load_range("stock_daily", code, start, end)
+    }
+
+    async fn index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        self.load_range("index_daily", code, start, end)
+    }
+
+    async fn fq_factor(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<FqFactor>> {
+        self.load_range("stock_fq_factor", code, start, end)
+    }
+
+    async fn corp_action(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<CorpAction>> {
+        self.load_range("stock_corp_action", code, start, end)
+    }
+
+    async fn stock_indicator(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockIndicator>> {
+        self.load_range("stock_index", code, start, end)
+    }
+
+    async fn save_stock_info(&self, list: &[SecurityInfo]) -> Result<()> {
+        self.save(
+            "stock_info",
+            list.iter().map(|info| (info.code.clone(), info)),
+        )
+    }
+
+    async fn save_index_info(&self, list: &[SecurityInfo]) -> Result<()> {
+        self.save(
+            "index_info",
+            list.iter().map(|info| (info.code.clone(), info)),
+        )
+    }
+
+    async fn save_stock_daily(&self, code: &str, bars: &[StockBar]) -> Result<()> {
+        self.save_daily("stock_daily", code, bars)
+    }
+
+    async fn save_index_daily(&self, code: &str, bars: &[StockBar]) -> Result<()> {
+        self.save_daily("index_daily", code, bars)
+    }
+
+    async fn save_fq_factor(&self, factors: &[FqFactor]) -> Result<()> {
+        self.save(
+            "stock_fq_factor",
+            factors.iter().map(|f| (Self::key(&f.code, f.date), f)),
+        )
+    }
+
+    async fn save_corp_action(&self, actions: &[CorpAction]) -> Result<()> {
+        let mut items = vec![];
+        for a in actions {
+            match a.date {
+                Some(date) => items.push((Self::key(&a.code, date), a)),
+                None => bail!("corp action without date: {}", &a.code),
+            }
+        }
+        self.save("stock_corp_action", items)
+    }
+
+    async fn save_stock_indicator(&self, indicators: &[StockIndicator]) -> Result<()> {
+        self.save(
+            "stock_index",
+            indicators.iter().map(|i| (Self::key(&i.code, i.date), i)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test_local {
+    use super::LocalStore;
+    use crate::data::{DataKind, FqFactor, MarketDataStore};
+    use crate::fetch::{SecurityInfo, StockBar};
+    use crate::{Board, CorpAction, Price};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_local_store() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let path = std::env::temp_dir().join(format!("bbq-local-{}", std::process::id()));
+            let store = LocalStore::open(&path).unwrap();
+            let date = |d: u32| NaiveDate::from_ymd_opt(2022, 3, d).unwrap();
+            let bar = |d: u32, close: f64| StockBar {
+                time: date(d).and_hms_opt(15, 0, 0).unwrap(),
+                open: Price::from(close),
+                high: Price::from(close),
+                low: Price::from(close),
+                close: Price::from(close),
+                vol: 100,
+            };
+
+            store
+                .save_stock_daily("sh600063", &[bar(2, 5.6), bar(1, 5.5), bar(3, 5.7)])
+                .await
+                .unwrap();
+            store
+                .save_stock_daily("sh600064", &[bar(2, 8.0)])
+                .await
+                .unwrap();
+            // 同日覆盖
+            store
+                .save_stock_daily("sh600063", &[bar(3, 5.8)])
+                .await
+                .unwrap();
+
+            let bars = store.stock_daily("sh600063", None, None).await.unwrap();
+            assert_eq!(bars.len(), 3);
+            assert_eq!(bars[0].time.to_string(), "2022-03-01 00:00:00");
+            assert_eq!(bars[2].close, Price::from(5.8));
+            assert_eq!(bars[2].vol, 100);
+            let bars = store
+                .stock_daily("sh600063", Some(date(2)), Some(date(2)))
+                .await
+                .unwrap();
+            assert_eq!(bars.len(), 1);
+            let last = store.last_date(DataKind::StockDaily, "sh600063").await;
+            assert_eq!(last.unwrap(), Some(date(3)));
+            let last = store.last_date(DataKind::StockDaily, "sh60006").await;
+            assert_eq!(last.unwrap(), None);
+            assert!(store
+                .last_date(DataKind::StockInfo, "sh600063")
+                .await
+                .is_err());
+            assert!(store
+                .index_daily("sh600063", None, None)
+                .await
+                .unwrap()
+                .is_empty());
+
+            store
+                .save_fq_factor(&[FqFactor {
+                    code: "sh600063".to_string(),
+                    date: date(2),
+                    hfq_factor: 1.1,
+                    qfq_factor: 1.0,
+                }])
+                .await
+                .unwrap();
+            let factors = store
+                .fq_factor("sh600063", Some(date(1)), None)
+                .await
+                .unwrap();
+            assert_eq!(factors[0].hfq_factor, 1.1);
+
+            let action = CorpAction {
+                code: "sh600063".to_string(),
+                date: None,
+                cash: Price::from(0.1),
+                share: 0.0,
+            };
+            assert!(store.save_corp_action(&[action]).await.is_err());
+
+            store
+                .save_stock_info(&[SecurityInfo {
+                    code: "sh600063".to_string(),
+                    name: "皖维高新".to_string(),
+                    board: Board::Main,
+                    ..Default::default()
+                }])
+                .await
+                .unwrap();
+            let list = store.stock_info().await.unwrap();
+            assert_eq!(list[0].name, "皖维高新");
+
+            drop(store);
+            let _ = std::fs::remove_dir_all(&path);
+        });
+    }
+}
@@ -1 +1,165 @@
+use crate::fetch::{SecurityInfo, StockBar, StockBarList};
+use crate::CorpAction;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
 pub mod mongo;
+pub use mongo::MongoDB;
+
+pub mod local;
+pub use local::LocalStore;
+
+pub mod sync;
+pub use sync::{DataSync, SyncEvent, SyncMode, SyncOpts, SyncStat};
+
+/// 行情数据类别, 名称与 MongoDB 集合一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataKind {
+    StockInfo,
+    StockDaily,
+    StockFqFactor,
+    StockCorpAction,
+    // 股票每日指标
+    StockIndex,
+    IndexInfo,
+    IndexDaily,
+}
+
+impl DataKind {
+    pub const ALL: [DataKind; 7] = [
+        DataKind::StockInfo,
+        DataKind::StockDaily,
+        DataKind::StockFqFactor,
+        DataKind::StockCorpAction,
+        DataKind::StockIndex,
+        DataKind::IndexInfo,
+        DataKind::IndexDaily,
+    ];
+
+    /// 信息类数据无交易日
+    pub fn is_info(&self) -> bool {
+        matches!(self, DataKind::StockInfo | DataKind::IndexInfo)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DataKind::StockInfo => "stock_info",
+            DataKind::StockDaily => "stock_daily",
+            DataKind::StockFqFactor => "stock_fq_factor",
+            DataKind::StockCorpAction => "stock_corp_action",
+            DataKind::StockIndex => "stock_index",
+            DataKind::IndexInfo => "index_info",
+            DataKind::IndexDaily => "index_daily",
+        }
+    }
+}
+
+impl Display for DataKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            DataKind::StockInfo => "股票信息",
+            DataKind::StockDaily => "股票日线",
+            DataKind::StockFqFactor => "股票复权因子",
+            DataKind::StockCorpAction => "股票除权除息",
+            DataKind::StockIndex => "股票指标",
+            DataKind::IndexInfo => "指数信息",
+            DataKind::IndexDaily => "指数日线",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 复权因子
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct FqFactor {
+    pub code: String,
+    // 生效日期
+    pub date: NaiveDate,
+    pub hfq_factor: f64,
+    pub qfq_factor: f64,
+}
+
+/// 股票每日指标
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct StockIndicator {
+    pub code: String,
+    pub date: NaiveDate,
+    // 市盈率
+    pub pe: f64,
+    pub pe_ttm: f64,
+    // 市净率
+    pub pb: f64,
+    // 市销率
+    pub ps: f64,
+    pub ps_ttm: f64,
+    // 股息率
+    pub dv_ratio: f64,
+    pub dv_ttm: f64,
+    // 总市值
+    pub total_mv: f64,
+}
+
+/// 行情数据存储
+///
+/// 日期区间为闭区间, 不指定则不限, 按日期升序返回; 日线时间为交易日 00:00:00。
+/// 保存时按代码及日期覆盖已有数据
+#[async_trait]
+pub trait MarketDataStore: Send + Sync {
+    /// 初始化, 如创建唯一索引
+    async fn init(&self) -> Result<()> {
+        Ok(())
+    }
+    /// 代码最后一条数据的日期, 无数据返回 `None`; 信息类数据不支持
+    async fn last_date(&self, kind: DataKind, code: &str) -> Result<Option<NaiveDate>>;
+    /// 股票列表
+    async fn stock_info(&self) -> Result<Vec<SecurityInfo>>;
+    /// 指数列表
+    async fn index_info(&self) -> Result<Vec<SecurityInfo>>;
+    /// 股票不复权日线
+    async fn stock_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList>;
+    /// 指数日线
+    async fn index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList>;
+    async fn fq_factor(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<FqFactor>>;
+    /// 除权除息
+    async fn corp_action(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<CorpAction>>;
+    async fn stock_indicator(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockIndicator>>;
+
+    async fn save_stock_info(&self, list: &[SecurityInfo]) -> Result<()>;
+    async fn save_index_info(&self, list: &[SecurityInfo]) -> Result<()>;
+    async fn save_stock_daily(&self, code: &str, bars: &[StockBar]) -> Result<()>;
+    async fn save_index_daily(&self, code: &str, bars: &[StockBar]) -> Result<()>;
+    async fn save_fq_factor(&self, factors: &[FqFactor]) -> Result<()>;
+    async fn save_corp_action(&self, actions: &[CorpAction]) -> Result<()>;
+    async fn save_stock_indicator(&self, indicators: &[StockIndicator]) -> Result<()>;
+}
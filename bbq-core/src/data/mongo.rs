@@ -1,19 +1,30 @@
+use crate::data::{DataKind, FqFactor, MarketDataStore, StockIndicator};
+use crate::fetch::{SecurityInfo, StockBar, StockBarList};
+use crate::{Board, CorpAction, Instrument, Price};
 use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
 use futures::TryStreamExt;
-use mongodb::bson::{DateTime, Document};
-use mongodb::options::FindOptions;
-use mongodb::{Client, Collection, Database};
+use mongodb::bson::{doc, DateTime, Document};
+use mongodb::options::{FindOneOptions, FindOptions, IndexOptions};
+use mongodb::{Client, Collection, Database, IndexModel};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
+// 批量写入每次命令的最大条数
+const BULK_SIZE: usize = 1000;
+
 // 股票信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StockInfo {
     pub code: String,
     pub name: String,
-    pub listing_date: DateTime,
+    #[serde(default)]
+    pub listing_date: Option<DateTime>,
+    #[serde(default)]
     pub block: String,
+    #[serde(default)]
     pub is_margin: f64,
 }
 
@@ -27,7 +38,9 @@ pub struct StockDaily {
     pub high: f64,
     pub low: f64,
     pub volume: f64,
+    #[serde(default)]
     pub turnover: f64,
+    #[serde(default)]
     pub hfq_factor: String,
 }
 
@@ -55,6 +68,18 @@ pub struct StockFqFactor {
     pub qfq_factor: f64,
 }
 
+// 股票除权除息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCorpAction {
+    pub code: String,
+    // 除权除息日
+    pub trade_date: DateTime,
+    // 每股派现
+    pub cash: f64,
+    // 每股送转股数
+    pub share: f64,
+}
+
 // 指数信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexInfo {
@@ -91,6 +116,7 @@ impl MongoDB {
                 "stock_daily",
                 "stock_index",
                 "stock_fq_factor",
+                "stock_corp_action",
             ],
             fund_coll: vec!["index_info", "index_daily"],
         }
@@ -128,11 +154,387 @@ impl MongoDB {
         }
         Ok(list)
     }
+
+    /// 按代码及日期区间查询, 日期升序
+    async fn find_range<T>(
+        &self,
+        coll: &str,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Unpin + Send + Sync,
+    {
+        let opts = FindOptions::builder().sort(doc! {"trade_date": 1}).build();
+        self.find(coll, date_filter(code, start, end), opts)
+            .await
+            .with_context(|| format!("query {} failed", coll))
+    }
+
+    /// 批量 upsert, 每 `BULK_SIZE` 条合并为一条 update 命令, 不覆盖未更新的字段
+    async fn upsert_many(&self, coll: &str, rows: Vec<(Document, Document)>) -> Result<()> {
+        let db = self.get_db(coll)?;
+        for chunk in rows.chunks(BULK_SIZE) {
+            let updates: Vec<Document> = chunk
+                .iter()
+                .map(|(filter, update)| {
+                    doc! {"q": filter.clone(), "u": {"$set": update.clone()}, "upsert": true}
+                })
+                .collect();
+            let rs = db
+                .run_command(
+                    doc! {"update": coll, "updates": updates, "ordered": false},
+                    None,
+                )
+                .await
+                .with_context(|| format!("upsert {} failed", coll))?;
+            if let Some(e) = rs.get_array("writeErrors").ok().and_then(|e| e.first()) {
+                bail!("upsert {} failed: {}", coll, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_daily(&self, coll: &str, code: &str, bars: &[StockBar]) -> Result<()> {
+        let mut rows = Vec::with_capacity(bars.len());
+        for bar in bars {
+            let trade_date = to_bson_date(bar.time.date());
+            let mut update = doc! {
+                "code": code,
+                "trade_date": trade_date,
+                "open": bar.open.to_f64(),
+                "high": bar.high.to_f64(),
+                "low": bar.low.to_f64(),
+                "close": bar.close.to_f64(),
+            };
+            // 指数成交量为整数
+            if coll == "index_daily" {
+                update.insert("volume", bar.vol as i64);
+            } else {
+                update.insert("volume", bar.vol as f64);
+            }
+            rows.push((doc! {"code": code, "trade_date": trade_date}, update));
+        }
+        self.upsert_many(coll, rows).await
+    }
+}
+
+/// 交易日存储为 UTC 00:00:00
+pub fn to_bson_date(date: NaiveDate) -> DateTime {
+    DateTime::from_chrono(Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN)))
+}
+
+pub fn from_bson_date(date: &DateTime) -> NaiveDate {
+    date.to_chrono().date_naive()
+}
+
+/// 代码及交易日区间过滤, 不指定则不限
+pub fn date_filter(code: &str, start: Option<NaiveDate>, end: Option<NaiveDate>) -> Document {
+    let mut filter = doc! {"code": code};
+    let mut range = Document::new();
+    if let Some(start) = start {
+        range.insert("$gte", to_bson_date(start));
+    }
+    if let Some(end) = end {
+        range.insert("$lte", to_bson_date(end));
+    }
+    if !range.is_empty() {
+        filter.insert("trade_date", range);
+    }
+    filter
+}
+
+fn daily_bar(date: &DateTime, open: f64, high: f64, low: f64, close: f64, vol: u64) -> StockBar {
+    StockBar {
+        time: from_bson_date(date).and_time(NaiveTime::MIN),
+        open: Price::from(open),
+        high: Price::from(high),
+        low: Price::from(low),
+        close: Price::from(close),
+        vol,
+    }
+}
+
+#[async_trait]
+impl MarketDataStore for MongoDB {
+    /// 信息类按代码唯一, 其他按代码及交易日唯一
+    async fn init(&self) -> Result<()> {
+        for kind in DataKind::ALL {
+            let keys = if kind.is_info() {
+                doc! {"code": 1}
+            } else {
+                doc! {"code": 1, "trade_date": 1}
+            };
+            let index = IndexModel::builder()
+                .keys(keys)
+                .options(IndexOptions::builder().unique(true).build())
+                .build();
+            self.get_coll::<Document>(kind.name())?
+                .create_index(index, None)
+                .await
+                .with_context(|| format!("create {} index failed", kind.name()))?;
+        }
+        Ok(())
+    }
+
+    async fn last_date(&self, kind: DataKind, code: &str) -> Result<Option<NaiveDate>> {
+        if kind.is_info() {
+            bail!("{} has no trade date", kind.name());
+        }
+        let opts = FindOneOptions::builder()
+            .sort(doc! {"trade_date": -1})
+            .projection(doc! {"trade_date": 1})
+            .build();
+        let item = self
+            .get_coll::<Document>(kind.name())?
+            .find_one(doc! {"code": code}, opts)
+            .await
+            .with_context(|| format!("query {} failed", kind.name()))?;
+        match item {
+            Some(item) => {
+                let date = item
+                    .get_datetime("trade_date")
+                    .with_context(|| format!("invalid {} trade_date", kind.name()))?;
+                Ok(Some(from_bson_date(date)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn stock_info(&self) -> Result<Vec<SecurityInfo>> {
+        let list: Vec<StockInfo> = self
+            .find("stock_info", None, None)
+            .await
+            .with_context(|| "query stock_info failed")?;
+        Ok(list
+            .into_iter()
+            .map(|info| SecurityInfo {
+                board: Instrument::parse(&info.code)
+                    .map(|instrument| instrument.board)
+                    .unwrap_or_default(),
+                st: info.name.to_uppercase().contains("ST"),
+                listing_date: info.listing_date.as_ref().map(from_bson_date),
+                code: info.code,
+                name: info.name,
+                suspended: false,
+            })
+            .collect())
+    }
+
+    async fn index_info(&self) -> Result<Vec<SecurityInfo>> {
+        let list: Vec<IndexInfo> = self
+            .find("index_info", None, None)
+            .await
+            .with_context(|| "query index_info failed")?;
+        Ok(list
+            .into_iter()
+            .map(|info| SecurityInfo {
+                code: info.code,
+                name: info.name,
+                board: Board::Index,
+                ..Default::default()
+            })
+            .collect())
+    }
+
+    async fn stock_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        let list: Vec<StockDaily> = self.find_range("stock_daily", code, start, end).await?;
+        Ok(list
+            .iter()
+            .map(|d| {
+                daily_bar(
+                    &d.trade_date,
+                    d.open,
+                    d.high,
+                    d.low,
+                    d.close,
+                    d.volume as u64,
+                )
+            })
+            .collect())
+    }
+
+    async fn index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        let list: Vec<IndexDaily> = self.find_range("index_daily", code, start, end).await?;
+        Ok(list
+            .iter()
+            .map(|d| daily_bar(&d.trade_date, d.open, d.high, d.low, d.close, d.volume))
+            .collect())
+    }
+
+    async fn fq_factor(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<FqFactor>> {
+        let list: Vec<StockFqFactor> = self.find_range("stock_fq_factor", code, start, end).await?;
+        Ok(list
+            .into_iter()
+            .map(|f| FqFactor {
+                date: from_bson_date(&f.trade_date),
+                code: f.code,
+                hfq_factor: f.hfq_factor,
+                qfq_factor: f.qfq_factor,
+            })
+            .collect())
+    }
+
+    async fn corp_action(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<CorpAction>> {
+        let list: Vec<StockCorpAction> = self
+            .find_range("stock_corp_action", code, start, end)
+            .await?;
+        Ok(list
+            .into_iter()
+            .map(|a| CorpAction {
+                date: Some(from_bson_date(&a.trade_date)),
+                code: a.code,
+                cash: Price::from(a.cash),
+                share: a.share,
+            })
+            .collect())
+    }
+
+    async fn stock_indicator(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockIndicator>> {
+        let list: Vec<StockIndex> = self.find_range("stock_index", code, start, end).await?;
+        Ok(list
+            .into_iter()
+            .map(|i| StockIndicator {
+                date: from_bson_date(&i.trade_date),
+                code: i.code,
+                pe: i.pe,
+                pe_ttm: i.pe_ttm,
+                pb: i.pb,
+                ps: i.ps,
+                ps_ttm: i.ps_ttm,
+                dv_ratio: i.dv_ratio,
+                dv_ttm: i.dv_ttm,
+                total_mv: i.total_mv,
+            })
+            .collect())
+    }
+
+    /// 不覆盖融资融券标记
+    async fn save_stock_info(&self, list: &[SecurityInfo]) -> Result<()> {
+        let mut rows = Vec::with_capacity(list.len());
+        for info in list {
+            let mut update = doc! {
+                "code": &info.code,
+                "name": &info.name,
+                "block": info.board.to_string(),
+            };
+            if let Some(date) = info.listing_date {
+                update.insert("listing_date", to_bson_date(date));
+            }
+            rows.push((doc! {"code": &info.code}, update));
+        }
+        self.upsert_many("stock_info", rows).await
+    }
+
+    async fn save_index_info(&self, list: &[SecurityInfo]) -> Result<()> {
+        let rows = list
+            .iter()
+            .map(|info| {
+                let update = doc! {"code": &info.code, "name": &info.name};
+                (doc! {"code": &info.code}, update)
+            })
+            .collect();
+        self.upsert_many("index_info", rows).await
+    }
+
+    async fn save_stock_daily(&self, code: &str, bars: &[StockBar]) -> Result<()> {
+        self.save_daily("stock_daily", code, bars).await
+    }
+
+    async fn save_index_daily(&self, code: &str, bars: &[StockBar]) -> Result<()> {
+        self.save_daily("index_daily", code, bars).await
+    }
+
+    async fn save_fq_factor(&self, factors: &[FqFactor]) -> Result<()> {
+        let rows = factors
+            .iter()
+            .map(|f| {
+                let trade_date = to_bson_date(f.date);
+                let update = doc! {
+                    "code": &f.code,
+                    "trade_date": trade_date,
+                    "hfq_factor": f.hfq_factor,
+                    "qfq_factor": f.qfq_factor,
+                };
+                (doc! {"code": &f.code, "trade_date": trade_date}, update)
+            })
+            .collect();
+        self.upsert_many("stock_fq_factor", rows).await
+    }
+
+    async fn save_corp_action(&self, actions: &[CorpAction]) -> Result<()> {
+        let mut rows = Vec::with_capacity(actions.len());
+        for a in actions {
+            let date = match a.date {
+                Some(date) => to_bson_date(date),
+                None => bail!("corp action without date: {}", &a.code),
+            };
+            let update = doc! {
+                "code": &a.code,
+                "trade_date": date,
+                "cash": a.cash.to_f64(),
+                "share": a.share,
+            };
+            rows.push((doc! {"code": &a.code, "trade_date": date}, update));
+        }
+        self.upsert_many("stock_corp_action", rows).await
+    }
+
+    async fn save_stock_indicator(&self, indicators: &[StockIndicator]) -> Result<()> {
+        let rows = indicators
+            .iter()
+            .map(|i| {
+                let trade_date = to_bson_date(i.date);
+                let update = doc! {
+                    "code": &i.code,
+                    "trade_date": trade_date,
+                    "pe": i.pe,
+                    "pe_ttm": i.pe_ttm,
+                    "pb": i.pb,
+                    "ps": i.ps,
+                    "ps_ttm": i.ps_ttm,
+                    "dv_ratio": i.dv_ratio,
+                    "dv_ttm": i.dv_ttm,
+                    "total_mv": i.total_mv,
+                };
+                (doc! {"code": &i.code, "trade_date": trade_date}, update)
+            })
+            .collect();
+        self.upsert_many("stock_index", rows).await
+    }
 }
 
 #[cfg(test)]
 mod test_db {
-    use crate::data::mongo::{IndexInfo, MongoDB, StockDaily, StockInfo};
+    use crate::data::mongo::{
+        date_filter, from_bson_date, to_bson_date, IndexInfo, MongoDB, StockDaily, StockInfo,
+    };
     use anyhow::Result;
     use chrono::Utc;
     use chrono::{offset::TimeZone, NaiveDate};
@@ -140,6 +542,27 @@ mod test_db {
     use mongodb::{bson::doc, Client};
     use std::time::Duration;
 
+    #[test]
+    fn test_date_filter() {
+        let date = NaiveDate::from_ymd_opt(2022, 3, 1).unwrap();
+        assert_eq!(from_bson_date(&to_bson_date(date)), date);
+        assert_eq!(
+            date_filter("sh600063", None, None),
+            doc! {"code": "sh600063"}
+        );
+        assert_eq!(
+            date_filter("sh600063", Some(date), None),
+            doc! {"code": "sh600063", "trade_date": {"$gte": to_bson_date(date)}}
+        );
+        assert_eq!(
+            date_filter("sh600063", Some(date), Some(date)),
+            doc! {
+                "code": "sh600063",
+                "trade_date": {"$gte": to_bson_date(date), "$lte": to_bson_date(date)},
+            }
+        );
+    }
+
     #[test]
     fn test_db() {
         async fn test() -> Result<()> {
@@ -150,9 +573,7 @@ mod test_db {
             let client = Client::with_options(clt_opts)?;
 
             let s = MongoDB::new(client);
-            let find_opts = FindOptions::builder()
-                .limit(15)
-                .build();
+            let find_opts = FindOptions::builder().limit(15).build();
             let rs: Vec<IndexInfo> = s.find("index_info", None, find_opts).await.unwrap();
             for v in rs {
                 println!("item2: {:?}", v)
@@ -176,9 +597,10 @@ mod test_db {
             println!("stock daily");
             let nd = NaiveDate::parse_from_str("2022-03-01", "%Y-%m-%d")
                 .unwrap()
-                .and_hms(0, 0, 0);
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
 
-             let ss=   Utc.from_local_datetime(&nd).unwrap();
+            let ss = Utc.from_local_datetime(&nd).unwrap();
             // let ss: DateTime<Utc> = DateTime::from(&nd);
 
             // let nd2 = NaiveDate::parse_from_str("2022-03-02", "%Y-%m-%d")
@@ -187,20 +609,12 @@ mod test_db {
 
             // let ss2 = Local.from_local_datetime(&nd2).unwrap();
 
-
             let filter = doc!(
                 "code": "sh600063",
                 "trade_date": ss,
             );
-    
-            let rs: Vec<StockDaily> = s
-                .find(
-                    "stock_daily",
-                    filter,
-                    None,
-                )
-                .await
-                .unwrap();
+
+            let rs: Vec<StockDaily> = s.find("stock_daily", filter, None).await.unwrap();
 
             for v in rs {
                 println!("item4: {:?}", v)
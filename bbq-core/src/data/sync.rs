@@ -0,0 +1,645 @@
+use crate::data::{DataKind, FqFactor, MarketDataStore};
+use crate::fetch::{AdjustMode, Fetcher, SecurityInfo};
+use crate::{CorpAction, Price, TradeCalendar};
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, NaiveTime};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc::UnboundedSender};
+
+/// 同步模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncMode {
+    // 全量, 自起始日期重新拉取并覆盖
+    Full,
+    // 增量, 自各代码最后一个交易日之后拉取
+    #[default]
+    Incremental,
+}
+
+impl Display for SyncMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            SyncMode::Full => "全量同步",
+            SyncMode::Incremental => "增量同步",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 同步参数
+///
+/// 目前没有行情源提供股票指标, `kinds` 不支持 `stock_index`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct SyncOpts {
+    // 定时同步模式
+    pub mode: SyncMode,
+    // 同步数据类别, 按依赖顺序执行, 不支持 stock_index
+    pub kinds: Vec<DataKind>,
+    // 指定代码, 为空则同步列表内全部代码
+    pub codes: Vec<String>,
+    // 全量同步及无历史数据时的起始日期, 不指定则不限
+    pub start_date: Option<NaiveDate>,
+    // 每个交易日的同步时间, 应在收盘之后
+    pub time: NaiveTime,
+}
+
+impl Default for SyncOpts {
+    fn default() -> Self {
+        Self {
+            mode: Default::default(),
+            kinds: vec![
+                DataKind::StockInfo,
+                DataKind::StockDaily,
+                DataKind::StockFqFactor,
+                DataKind::StockCorpAction,
+                DataKind::IndexInfo,
+                DataKind::IndexDaily,
+            ],
+            codes: vec![],
+            start_date: None,
+            time: NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+        }
+    }
+}
+
+impl SyncOpts {
+    pub fn validate(&self) -> Result<()> {
+        if self.kinds.contains(&DataKind::StockIndex) {
+            bail!(
+                "{} sync not supported: no fetcher provides stock indicators",
+                DataKind::StockIndex.name()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// 同步起始日期, 外层 `None` 为已是最新, 内层 `None` 为不限
+type SyncStart = Option<Option<NaiveDate>>;
+
+/// 单个数据类别的同步结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SyncStat {
+    pub kind: Option<DataKind>,
+    // 代码数
+    pub total: usize,
+    // 写入记录数
+    pub count: usize,
+    // 失败代码数
+    pub errors: usize,
+    // 完成时间
+    pub time: Option<NaiveDateTime>,
+}
+
+/// 同步进度
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncEvent {
+    Start {
+        kind: DataKind,
+        mode: SyncMode,
+        total: usize,
+    },
+    Progress {
+        kind: DataKind,
+        code: String,
+        done: usize,
+        total: usize,
+        count: usize,
+    },
+    Error {
+        kind: DataKind,
+        code: String,
+        error: String,
+    },
+    End(SyncStat),
+}
+
+/// 行情数据同步, 经 `Fetcher` 拉取后按代码及日期写入存储
+///
+/// 单个代码失败只记录错误, 不影响其他代码
+pub struct DataSync {
+    store: Arc<dyn MarketDataStore>,
+    fetcher: Box<dyn Fetcher>,
+    calendar: Arc<TradeCalendar>,
+    opts: SyncOpts,
+    progress: Option<UnboundedSender<SyncEvent>>,
+}
+
+impl DataSync {
+    pub fn new(
+        store: Arc<dyn MarketDataStore>,
+        fetcher: Box<dyn Fetcher>,
+        calendar: Arc<TradeCalendar>,
+        opts: SyncOpts,
+    ) -> Self {
+        Self {
+            store,
+            fetcher,
+            calendar,
+            opts,
+            progress: None,
+        }
+    }
+
+    pub fn with_progress(mut self, tx: UnboundedSender<SyncEvent>) -> Self {
+        self.progress = Some(tx);
+        self
+    }
+
+    fn notify(&self, event: SyncEvent) {
+        if let Some(tx) = &self.progress {
+            let _ = tx.send(event);
+        }
+    }
+
+    /// 每个交易日在 `opts.time` 按 `opts.mode` 同步, 直至收到退出信号
+    pub async fn run(&self, mut shutdown: broadcast::Receiver<bool>) {
+        loop {
+            let now = Local::now().naive_local();
+            let next = self.next_run(now);
+            info!("next data sync at {}", next);
+            let wait = (next - now).to_std().unwrap_or_default();
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {
+                    if let Err(e) = self.sync(self.opts.mode).await {
+                        error!("data sync failed: {:#}", e);
+                    }
+                }
+                _ = shutdown.recv() => {
+                    info!("data sync shutdown");
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 下一次同步时间, 当日为交易日且未到同步时间则为当日
+    fn next_run(&self, now: NaiveDateTime) -> NaiveDateTime {
+        let today = now.date();
+        let date = if self.calendar.is_trading_day(today) && now.time() < self.opts.time {
+            today
+        } else {
+            self.calendar
+                .next(today)
+                .unwrap_or_else(|| today + Duration::days(1))
+        };
+        date.and_time(self.opts.time)
+    }
+
+    /// 同步 `opts.kinds` 中的数据, 截止当日
+    pub async fn sync(&self, mode: SyncMode) -> Result<Vec<SyncStat>> {
+        self.sync_until(mode, Local::now().date_naive()).await
+    }
+
+    async fn sync_until(&self, mode: SyncMode, end: NaiveDate) -> Result<Vec<SyncStat>> {
+        self.opts.validate()?;
+        self.store
+            .init()
+            .await
+            .with_context(|| "init data store failed")?;
+
+        info!("{} until {}", mode, end);
+        let mut stats = vec![];
+        let (mut stocks, mut indexes) = (None, None);
+        for kind in DataKind::ALL {
+            if !self.opts.kinds.contains(&kind) {
+                continue;
+            }
+            let stat = match kind {
+                DataKind::StockInfo | DataKind::IndexInfo => {
+                    let list = self.sync_info(kind).await?;
+                    let stat = SyncStat {
+                        kind: Some(kind),
+                        total: list.len(),
+                        count: list.len(),
+                        errors: 0,
+                        time: Some(Local::now().naive_local()),
+                    };
+                    if kind == DataKind::StockInfo {
+                        stocks = Some(list);
+                    } else {
+                        indexes = Some(list);
+                    }
+                    stat
+                }
+                DataKind::IndexDaily => {
+                    if indexes.is_none() {
+                        indexes = Some(self.load_info(DataKind::IndexInfo).await?);
+                    }
+                    let codes = self.codes(indexes.as_deref().unwrap_or_default());
+                    self.sync_codes(kind, mode, end, &codes).await
+                }
+                _ => {
+                    if stocks.is_none() {
+                        stocks = Some(self.load_info(DataKind::StockInfo).await?);
+                    }
+                    let codes = self.codes(stocks.as_deref().unwrap_or_default());
+                    self.sync_codes(kind, mode, end, &codes).await
+                }
+            };
+            info!(
+                "{} done, codes: {}, records: {}, errors: {}",
+                kind, stat.total, stat.count, stat.errors
+            );
+            self.notify(SyncEvent::End(stat.clone()));
+            stats.push(stat);
+        }
+        Ok(stats)
+    }
+
+    fn codes(&self, list: &[SecurityInfo]) -> Vec<String> {
+        list.iter()
+            .filter(|info| self.opts.codes.is_empty() || self.opts.codes.contains(&info.code))
+            .map(|info| info.code.clone())
+            .collect()
+    }
+
+    /// 证券列表总是全量拉取
+    async fn sync_info(&self, kind: DataKind) -> Result<Vec<SecurityInfo>> {
+        self.notify(SyncEvent::Start {
+            kind,
+            mode: SyncMode::Full,
+            total: 0,
+        });
+        let list = if kind == DataKind::StockInfo {
+            let list = self.fetcher.fetch_stock_list().await?;
+            self.store.save_stock_info(&list).await?;
+            list
+        } else {
+            let list = self.fetcher.fetch_index_list().await?;
+            self.store.save_index_info(&list).await?;
+            list
+        };
+        Ok(list)
+    }
+
+    /// 未同步证券列表时取已存储的列表, 存储为空则从行情源拉取
+    async fn load_info(&self, kind: DataKind) -> Result<Vec<SecurityInfo>> {
+        let list = if kind == DataKind::StockInfo {
+            self.store.stock_info().await?
+        } else {
+            self.store.index_info().await?
+        };
+        if !list.is_empty() {
+            return Ok(list);
+        }
+        if kind == DataKind::StockInfo {
+            self.fetcher.fetch_stock_list().await
+        } else {
+            self.fetcher.fetch_index_list().await
+        }
+    }
+
+    async fn sync_codes(
+        &self,
+        kind: DataKind,
+        mode: SyncMode,
+        end: NaiveDate,
+        codes: &[String],
+    ) -> SyncStat {
+        let total = codes.len();
+        self.notify(SyncEvent::Start { kind, mode, total });
+        let mut stat = SyncStat {
+            kind: Some(kind),
+            total,
+            ..Default::default()
+        };
+        for (i, code) in codes.iter().enumerate() {
+            let count = match self.sync_code(kind, mode, end, code).await {
+                Ok(count) => count,
+                Err(e) => {
+                    error!("sync {} {} failed: {:#}", kind.name(), code, e);
+                    stat.errors += 1;
+                    self.notify(SyncEvent::Error {
+                        kind,
+                        code: code.clone(),
+                        error: format!("{:#}", e),
+                    });
+                    0
+                }
+            };
+            stat.count += count;
+            self.notify(SyncEvent::Progress {
+                kind,
+                code: code.clone(),
+                done: i + 1,
+                total,
+                count,
+            });
+        }
+        stat.time = Some(Local::now().naive_local());
+        stat
+    }
+
+    /// 同步起始日期, 增量同步已是最新则返回 `None`
+    async fn start_date(
+        &self,
+        kind: DataKind,
+        mode: SyncMode,
+        end: NaiveDate,
+        code: &str,
+    ) -> Result<SyncStart> {
+        if mode == SyncMode::Full {
+            return Ok(Some(self.opts.start_date));
+        }
+        let last = match self.store.last_date(kind, code).await? {
+            Some(last) => last,
+            None => return Ok(Some(self.opts.start_date)),
+        };
+        let next = self
+            .calendar
+            .next(last)
+            .unwrap_or_else(|| last + Duration::days(1));
+        if next > end {
+            return Ok(None);
+        }
+        Ok(Some(Some(next)))
+    }
+
+    /// 单个代码同步, 返回写入记录数
+    async fn sync_code(
+        &self,
+        kind: DataKind,
+        mode: SyncMode,
+        end: NaiveDate,
+        code: &str,
+    ) -> Result<usize> {
+        match kind {
+            DataKind::StockFqFactor => return self.sync_fq_factor(mode, code).await,
+            DataKind::StockCorpAction => return self.sync_corp_action(mode, code).await,
+            _ => (),
+        }
+        let start = match self.start_date(kind, mode, end, code).await? {
+            Some(start) => start,
+            None => return Ok(0),
+        };
+        let count = match kind {
+            DataKind::StockDaily => {
+                let bars = self
+                    .fetcher
+                    .fetch_daily(code, start, Some(end), AdjustMode::None)
+                    .await?;
+                self.store.save_stock_daily(code, &bars).await?;
+                bars.len()
+            }
+            DataKind::IndexDaily => {
+                let bars = self
+                    .fetcher
+                    .fetch_index_daily(code, start, Some(end))
+                    .await?;
+                self.store.save_index_daily(code, &bars).await?;
+                bars.len()
+            }
+            _ => {
+                let indicators = self
+                    .fetcher
+                    .fetch_stock_indicator(code, start, Some(end))
+                    .await?;
+                self.store.save_stock_indicator(&indicators).await?;
+                indicators.len()
+            }
+        };
+        Ok(count)
+    }
+
+    /// 复权因子只能全部拉取, 增量时只写入最后日期之后的部分
+    async fn sync_fq_factor(&self, mode: SyncMode, code: &str) -> Result<usize> {
+        let mut factors = self.fetcher.fetch_fq_factor(code).await?;
+        if mode == SyncMode::Incremental {
+            if let Some(last) = self.store.last_date(DataKind::StockFqFactor, code).await? {
+                factors.retain(|f| f.date > last);
+            }
+        }
+        self.store.save_fq_factor(&factors).await?;
+        Ok(factors.len())
+    }
+
+    /// 除权除息由已存储的复权因子及不复权日线推算
+    async fn sync_corp_action(&self, mode: SyncMode, code: &str) -> Result<usize> {
+        let last = match mode {
+            SyncMode::Full => None,
+            SyncMode::Incremental => {
+                self.store
+                    .last_date(DataKind::StockCorpAction, code)
+                    .await?
+            }
+        };
+        let factors = self.store.fq_factor(code, None, None).await?;
+        let bars = self.store.stock_daily(code, None, None).await?;
+        let closes: BTreeMap<NaiveDate, Price> = bars
+            .iter()
+            .map(|bar| (bar.time.date(), bar.close))
+            .collect();
+        let actions = corp_actions(code, &factors, &closes, last);
+        self.store.save_corp_action(&actions).await?;
+        Ok(actions.len())
+    }
+}
+
+fn corp_actions(
+    code: &str,
+    factors: &[FqFactor],
+    closes: &BTreeMap<NaiveDate, Price>,
+    after: Option<NaiveDate>,
+) -> Vec<CorpAction> {
+    factors
+        .windows(2)
+        .filter(|w| after.map(|after| w[1].date > after).unwrap_or(true))
+        .filter_map(|w| {
+            let (_, pre_close) = closes.range(..w[1].date).next_back()?;
+            CorpAction::from_factor(
+                code,
+                w[1].date,
+                *pre_close,
+                w[0].hfq_factor,
+                w[1].hfq_factor,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_sync {
+    use super::{DataSync, SyncEvent, SyncMode, SyncOpts};
+    use crate::data::{DataKind, FqFactor, LocalStore, MarketDataStore};
+    use crate::fetch::{AdjustMode, Fetcher, RtQuot, SecurityInfo, StockBar, StockBarList};
+    use crate::{Price, TradeCalendar};
+    use anyhow::{bail, Result};
+    use async_trait::async_trait;
+    use chrono::{NaiveDate, NaiveTime};
+    use std::sync::{Arc, Mutex};
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 3, d).unwrap()
+    }
+
+    // 请求的日线代码及起始日期
+    type Ranges = Arc<Mutex<Vec<(String, Option<NaiveDate>)>>>;
+
+    struct MockFetcher {
+        // 请求的日线区间
+        ranges: Ranges,
+    }
+
+    #[async_trait]
+    impl Fetcher for MockFetcher {
+        async fn fetch_stock_minute(&self, _code: &str, _min: u32) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_rt_quot(&self, _codes: &Vec<String>) -> Result<RtQuot> {
+            Ok(Default::default())
+        }
+
+        async fn fetch_daily(
+            &self,
+            code: &str,
+            start: Option<NaiveDate>,
+            end: Option<NaiveDate>,
+            _adjust: AdjustMode,
+        ) -> Result<StockBarList> {
+            if code == "sz000001" {
+                bail!("mock error");
+            }
+            self.ranges.lock().unwrap().push((code.to_string(), start));
+            Ok([1, 2, 3, 4]
+                .into_iter()
+                .map(|d| StockBar {
+                    time: date(d).and_hms_opt(0, 0, 0).unwrap(),
+                    open: Price::from(10.0),
+                    high: Price::from(10.0),
+                    low: Price::from(10.0),
+                    close: Price::from(10.0),
+                    vol: 100,
+                })
+                .filter(|bar| start.map(|s| bar.time.date() >= s).unwrap_or(true))
+                .filter(|bar| end.map(|e| bar.time.date() <= e).unwrap_or(true))
+                .collect())
+        }
+
+        async fn fetch_index_daily(
+            &self,
+            _code: &str,
+            _start: Option<NaiveDate>,
+            _end: Option<NaiveDate>,
+        ) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_stock_list(&self) -> Result<Vec<SecurityInfo>> {
+            Ok(["sh600063", "sz000001"]
+                .into_iter()
+                .map(|code| SecurityInfo {
+                    code: code.to_string(),
+                    ..Default::default()
+                })
+                .collect())
+        }
+
+        async fn fetch_index_list(&self) -> Result<Vec<SecurityInfo>> {
+            Ok(vec![])
+        }
+
+        async fn fetch_fq_factor(&self, code: &str) -> Result<Vec<FqFactor>> {
+            let factor = |d: u32, hfq_factor: f64| FqFactor {
+                code: code.to_string(),
+                date: date(d),
+                hfq_factor,
+                qfq_factor: 1.0,
+            };
+            Ok(vec![factor(1, 1.0), factor(3, 2.0)])
+        }
+    }
+
+    #[test]
+    fn test_sync() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let path = std::env::temp_dir().join(format!("bbq-sync-{}", std::process::id()));
+            let store = Arc::new(LocalStore::open(&path).unwrap());
+            let ranges = Arc::new(Mutex::new(vec![]));
+            let fetcher = MockFetcher {
+                ranges: ranges.clone(),
+            };
+            let calendar = TradeCalendar::new([1, 2, 3, 4, 7].into_iter().map(date));
+            let opts = SyncOpts {
+                start_date: Some(date(1)),
+                ..Default::default()
+            };
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+            let sync = DataSync::new(store.clone(), Box::new(fetcher), Arc::new(calendar), opts)
+                .with_progress(tx);
+
+            let stats = sync.sync_until(SyncMode::Full, date(3)).await.unwrap();
+            assert_eq!(stats.len(), 6);
+            assert_eq!(stats[0].kind, Some(DataKind::StockInfo));
+            assert_eq!(stats[0].count, 2);
+            let daily = &stats[1];
+            assert_eq!((daily.total, daily.count, daily.errors), (2, 3, 1));
+            assert_eq!(stats[3].kind, Some(DataKind::StockCorpAction));
+            assert_eq!(stats[3].count, 1);
+            let actions = store.corp_action("sh600063", None, None).await.unwrap();
+            assert_eq!(actions[0].date, Some(date(3)));
+            assert!((actions[0].share - 1.0).abs() < 1e-9);
+
+            let mut errors = vec![];
+            while let Ok(event) = rx.try_recv() {
+                if let SyncEvent::Error { code, .. } = event {
+                    errors.push(code);
+                }
+            }
+            assert!(errors.contains(&"sz000001".to_string()));
+
+            // 增量从最后交易日之后开始, 已是最新则不请求
+            ranges.lock().unwrap().clear();
+            let stats = sync
+                .sync_until(SyncMode::Incremental, date(7))
+                .await
+                .unwrap();
+            assert_eq!(stats[1].count, 1);
+            assert_eq!(stats[2].count, 0);
+            assert_eq!(stats[3].count, 0);
+            assert_eq!(
+                ranges.lock().unwrap()[0],
+                ("sh600063".to_string(), Some(date(4)))
+            );
+            ranges.lock().unwrap().clear();
+            sync.sync_until(SyncMode::Incremental, date(4))
+                .await
+                .unwrap();
+            assert!(ranges.lock().unwrap().is_empty());
+
+            let bars = store.stock_daily("sh600063", None, None).await.unwrap();
+            assert_eq!(bars.len(), 4);
+
+            let next = sync.next_run(date(4).and_hms_opt(9, 0, 0).unwrap());
+            assert_eq!(next, date(4).and_hms_opt(16, 30, 0).unwrap());
+            let next = sync.next_run(date(4).and_hms_opt(17, 0, 0).unwrap());
+            assert_eq!(next.date(), date(7));
+            assert_eq!(next.time(), NaiveTime::from_hms_opt(16, 30, 0).unwrap());
+
+            // 没有行情源提供股票指标, 不支持同步
+            assert!(SyncOpts::default().validate().is_ok());
+            let opts = SyncOpts {
+                kinds: vec![DataKind::StockDaily, DataKind::StockIndex],
+                ..Default::default()
+            };
+            assert!(opts.validate().is_err());
+
+            drop(sync);
+            drop(store);
+            let _ = std::fs::remove_dir_all(&path);
+        });
+    }
+}
@@ -0,0 +1,490 @@
+use crate::data::{FqFactor, StockIndicator};
+use crate::fetch::{AdjustMode, Fetcher, RtQuot, RtQuotBatch, SecurityInfo, StockBarList};
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use log::{info, warn};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 多行情源切换参数
+#[derive(Debug, Clone)]
+pub struct FailoverOpts {
+    // 错误率超过该值时降级
+    pub error_rate: f64,
+    // 降级时长
+    pub demote: Duration,
+    // 错误率/耗时移动平均系数
+    pub alpha: f64,
+    // 双源行情比对的最大价差比例, None 不比对
+    pub cross_check: Option<f64>,
+}
+
+impl Default for FailoverOpts {
+    fn default() -> Self {
+        Self {
+            error_rate: 0.5,
+            demote: Duration::from_secs(60),
+            alpha: 0.3,
+            cross_check: None,
+        }
+    }
+}
+
+/// 行情源健康状态
+#[derive(Debug, Clone, Default)]
+pub struct SourceHealth {
+    pub name: String,
+    pub success: u64,
+    pub failure: u64,
+    // 错误率(移动平均)
+    pub error_rate: f64,
+    // 请求耗时(移动平均)
+    pub latency: Duration,
+    // 与其他源报价不一致次数
+    pub mismatch: u64,
+    // 降级截止时间
+    pub demoted_until: Option<Instant>,
+}
+
+impl SourceHealth {
+    pub fn is_demoted(&self, now: Instant) -> bool {
+        matches!(self.demoted_until, Some(t) if t > now)
+    }
+}
+
+/// 多行情源, 按优先级依次请求, 失败切换下一个源
+///
+/// 错误率超过阈值的源降级一段时间, 降级期间仅在其他源均失败时使用;
+/// 配置 `cross_check` 时实时行情同时请求次优源比对现价
+pub struct FailoverFetcher {
+    opts: FailoverOpts,
+    sources: Vec<Box<dyn Fetcher>>,
+    health: Mutex<Vec<SourceHealth>>,
+}
+
+impl FailoverFetcher {
+    pub fn new(opts: FailoverOpts) -> Self {
+        Self {
+            opts,
+            sources: Vec::new(),
+            health: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// 添加行情源, 先添加的优先级高
+    pub fn with_source(mut self, name: &str, fetcher: Box<dyn Fetcher>) -> Self {
+        self.sources.push(fetcher);
+        self.health.get_mut().unwrap().push(SourceHealth {
+            name: name.to_string(),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn health(&self) -> Vec<SourceHealth> {
+        self.health.lock().unwrap().clone()
+    }
+
+    /// 请求顺序: 未降级的源在前, 同组内按优先级
+    fn order(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let health = self.health.lock().unwrap();
+        let (mut active, demoted): (Vec<usize>, Vec<usize>) =
+            (0..health.len()).partition(|i| !health[*i].is_demoted(now));
+        active.extend(demoted);
+        active
+    }
+
+    fn record(&self, index: usize, ok: bool, elapsed: Duration) {
+        let alpha = self.opts.alpha;
+        let mut health = self.health.lock().unwrap();
+        let h = &mut health[index];
+        if ok {
+            h.success += 1;
+        } else {
+            h.failure += 1;
+        }
+        let err = if ok { 0.0 } else { 1.0 };
+        h.error_rate = h.error_rate * (1.0 - alpha) + err * alpha;
+        h.latency = if h.success + h.failure == 1 {
+            elapsed
+        } else {
+            h.latency.mul_f64(1.0 - alpha) + elapsed.mul_f64(alpha)
+        };
+
+        let now = Instant::now();
+        if h.error_rate >= self.opts.error_rate {
+            if !h.is_demoted(now) {
+                warn!(
+                    "fetcher {} demoted, error rate: {:.2}",
+                    &h.name, h.error_rate
+                );
+            }
+            h.demoted_until = Some(now + self.opts.demote);
+        } else if ok && h.demoted_until.take().is_some() {
+            info!("fetcher {} recovered", &h.name);
+        }
+    }
+
+    async fn call<'a, T, F, Fut>(&'a self, index: usize, f: &F) -> Result<T>
+    where
+        F: Fn(&'a dyn Fetcher) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let start = Instant::now();
+        let rs = f(self.sources[index].as_ref()).await;
+        self.record(index, rs.is_ok(), start.elapsed());
+        rs
+    }
+
+    async fn failover<'a, T, F, Fut>(&'a self, order: &[usize], f: F) -> Result<(usize, T)>
+    where
+        F: Fn(&'a dyn Fetcher) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if order.is_empty() {
+            bail!("no fetcher source");
+        }
+        let mut last_err = None;
+        for index in order.iter() {
+            match self.call(*index, &f).await {
+                Ok(rs) => return Ok((*index, rs)),
+                Err(e) => {
+                    let name = self.health.lock().unwrap()[*index].name.clone();
+                    warn!("fetcher {} failed: {}", name, e);
+                    last_err = Some(e);
+                }
+            }
+        }
+        Err(last_err.unwrap())
+    }
+
+    /// 比对两源现价, 价差超出比例的计入两源不一致次数
+    fn cross_check(&self, tolerance: f64, a: (usize, &RtQuot), b: (usize, &RtQuot)) {
+        let mut mismatch = vec![];
+        for (code, qa) in a.1.iter() {
+            if let Some(qb) = b.1.get(code) {
+                if qb.now.is_zero() {
+                    continue;
+                }
+                let diff = ((qa.now - qb.now) / qb.now).abs();
+                if diff > tolerance {
+                    mismatch.push(code.clone());
+                }
+            }
+        }
+        if !mismatch.is_empty() {
+            let mut health = self.health.lock().unwrap();
+            warn!(
+                "fetcher {} and {} quot mismatch: {:?}",
+                &health[a.0].name, &health[b.0].name, &mismatch
+            );
+            health[a.0].mismatch += 1;
+            health[b.0].mismatch += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl Fetcher for FailoverFetcher {
+    async fn fetch_stock_minute(&self, code: &str, min: u32) -> Result<StockBarList> {
+        let order = self.order();
+        let (_, rs) = self
+            .failover(&order, |f| f.fetch_stock_minute(code, min))
+            .await?;
+        Ok(rs)
+    }
+
+    async fn fetch_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        adjust: AdjustMode,
+    ) -> Result<StockBarList> {
+        let order = self.order();
+        let (_, rs) = self
+            .failover(&order, |f| f.fetch_daily(code, start, end, adjust))
+            .await?;
+        Ok(rs)
+    }
+
+    async fn fetch_index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        let order = self.order();
+        let (_, rs) = self
+            .failover(&order, |f| f.fetch_index_daily(code, start, end))
+            .await?;
+        Ok(rs)
+    }
+
+    async fn fetch_stock_list(&self) -> Result<Vec<SecurityInfo>> {
+        let order = self.order();
+        let (_, rs) = self.failover(&order, |f| f.fetch_stock_list()).await?;
+        Ok(rs)
+    }
+
+    async fn fetch_index_list(&self) -> Result<Vec<SecurityInfo>> {
+        let order = self.order();
+        let (_, rs) = self.failover(&order, |f| f.fetch_index_list()).await?;
+        Ok(rs)
+    }
+
+    async fn fetch_fq_factor(&self, code: &str) -> Result<Vec<FqFactor>> {
+        let order = self.order();
+        let (_, rs) = self.failover(&order, |f| f.fetch_fq_factor(code)).await?;
+        Ok(rs)
+    }
+
+    async fn fetch_stock_indicator(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<Vec<StockIndicator>> {
+        let order = self.order();
+        let (_, rs) = self
+            .failover(&order, |f| f.fetch_stock_indicator(code, start, end))
+            .await?;
+        Ok(rs)
+    }
+
+    async fn fetch_rt_quot(&self, codes: &Vec<String>) -> Result<RtQuot> {
+        let order = self.order();
+        let (index, rs) = self.failover(&order, |f| f.fetch_rt_quot(codes)).await?;
+        if let Some(tolerance) = self.opts.cross_check {
+            let now = Instant::now();
+            let other = order.iter().skip_while(|i| **i != index).nth(1).copied();
+            let other = other.filter(|i| !self.health.lock().unwrap()[*i].is_demoted(now));
+            if let Some(other) = other {
+                if let Ok(check) = self
+                    .call(other, &|f: &dyn Fetcher| f.fetch_rt_quot(codes))
+                    .await
+                {
+                    self.cross_check(tolerance, (index, &rs), (other, &check));
+                }
+            }
+        }
+        Ok(rs)
+    }
+
+    /// 按请求顺序转发, 全部代码失败计为该源失败, 失败的代码由下一个源补充
+    async fn fetch_rt_quot_batch(&self, codes: &Vec<String>) -> RtQuotBatch {
+        let mut batch = RtQuotBatch::default();
+        for code in codes {
+            batch
+                .errors
+                .insert(code.clone(), "no fetcher source".to_string());
+        }
+        for index in self.order() {
+            if batch.errors.is_empty() {
+                break;
+            }
+            let pending: Vec<String> = codes
+                .iter()
+                .filter(|code| batch.errors.contains_key(*code))
+                .cloned()
+                .collect();
+            let start = Instant::now();
+            let rs = self.sources[index].fetch_rt_quot_batch(&pending).await;
+            let ok = !rs.quot.is_empty();
+            self.record(index, ok, start.elapsed());
+            if !ok {
+                let name = self.health.lock().unwrap()[index].name.clone();
+                warn!("fetcher {} failed: {:?}", name, &rs.errors);
+            }
+            batch.errors.clear();
+            batch.merge_batch(&pending, Ok(rs));
+        }
+        batch
+    }
+}
+
+#[cfg(test)]
+mod test_failover {
+    use super::{FailoverFetcher, FailoverOpts};
+    use crate::fetch::{AdjustMode, Fetcher, Quot, RtQuot, StockBarList};
+    use crate::Price;
+    use anyhow::{bail, Result};
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    struct MockFetcher {
+        fail: Arc<AtomicBool>,
+        calls: Arc<AtomicU32>,
+        now: f64,
+    }
+
+    #[async_trait]
+    impl Fetcher for MockFetcher {
+        async fn fetch_stock_minute(&self, _code: &str, _min: u32) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_daily(
+            &self,
+            _code: &str,
+            _start: Option<NaiveDate>,
+            _end: Option<NaiveDate>,
+            _adjust: AdjustMode,
+        ) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_index_daily(
+            &self,
+            _code: &str,
+            _start: Option<NaiveDate>,
+            _end: Option<NaiveDate>,
+        ) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_rt_quot(&self, codes: &Vec<String>) -> Result<RtQuot> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail.load(Ordering::SeqCst) {
+                bail!("mock error");
+            }
+            Ok(codes
+                .iter()
+                .map(|code| {
+                    let quot = Quot {
+                        code: code.clone(),
+                        now: Price::from(self.now),
+                        ..Default::default()
+                    };
+                    (code.clone(), quot)
+                })
+                .collect())
+        }
+    }
+
+    fn mock(now: f64) -> (MockFetcher, Arc<AtomicBool>, Arc<AtomicU32>) {
+        let fail = Arc::new(AtomicBool::new(false));
+        let calls = Arc::new(AtomicU32::new(0));
+        let fetcher = MockFetcher {
+            fail: fail.clone(),
+            calls: calls.clone(),
+            now,
+        };
+        (fetcher, fail, calls)
+    }
+
+    #[test]
+    fn test_failover() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let (a, a_fail, a_calls) = mock(10.0);
+            let (b, b_fail, _) = mock(10.5);
+            let fetcher = FailoverFetcher::new(FailoverOpts::default())
+                .with_source("a", Box::new(a))
+                .with_source("b", Box::new(b));
+            let codes = vec!["sh600063".to_string()];
+
+            let rs = fetcher.fetch_rt_quot(&codes).await.unwrap();
+            assert_eq!(rs["sh600063"].now, Price::from(10.0));
+
+            // a 连续失败后降级, 不再优先请求
+            a_fail.store(true, Ordering::SeqCst);
+            for _ in 0..2 {
+                let rs = fetcher.fetch_rt_quot(&codes).await.unwrap();
+                assert_eq!(rs["sh600063"].now, Price::from(10.5));
+            }
+            let health = fetcher.health();
+            assert_eq!(health[0].failure, 2);
+            assert!(health[0].demoted_until.is_some());
+            assert_eq!(a_calls.load(Ordering::SeqCst), 3);
+            fetcher.fetch_rt_quot(&codes).await.unwrap();
+            assert_eq!(a_calls.load(Ordering::SeqCst), 3);
+
+            // 其他源均失败时仍使用降级的源
+            a_fail.store(false, Ordering::SeqCst);
+            b_fail.store(true, Ordering::SeqCst);
+            let rs = fetcher.fetch_rt_quot(&codes).await.unwrap();
+            assert_eq!(rs["sh600063"].now, Price::from(10.0));
+
+            a_fail.store(true, Ordering::SeqCst);
+            assert!(fetcher.fetch_rt_quot(&codes).await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_failover_batch() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let (a, a_fail, a_calls) = mock(10.0);
+            let (b, b_fail, _) = mock(10.5);
+            let fetcher = FailoverFetcher::new(FailoverOpts::default())
+                .with_source("a", Box::new(a))
+                .with_source("b", Box::new(b));
+            let codes = vec!["sh600063".to_string(), "sz000001".to_string()];
+
+            let batch = fetcher.fetch_rt_quot_batch(&codes).await;
+            assert_eq!(batch.quot.len(), 2);
+            assert!(batch.errors.is_empty());
+            assert_eq!(batch.quot["sz000001"].now, Price::from(10.0));
+
+            // a 全部失败计入错误率, 降级后不再优先请求
+            a_fail.store(true, Ordering::SeqCst);
+            for _ in 0..2 {
+                let batch = fetcher.fetch_rt_quot_batch(&codes).await;
+                assert_eq!(batch.quot["sh600063"].now, Price::from(10.5));
+                assert!(batch.errors.is_empty());
+            }
+            let health = fetcher.health();
+            assert_eq!(health[0].failure, 2);
+            assert!(health[0].demoted_until.is_some());
+            assert_eq!(health[1].success, 2);
+            fetcher.fetch_rt_quot_batch(&codes).await;
+            assert_eq!(a_calls.load(Ordering::SeqCst), 3);
+
+            // 所有源均失败时返回每个代码的错误
+            b_fail.store(true, Ordering::SeqCst);
+            let batch = fetcher.fetch_rt_quot_batch(&codes).await;
+            assert!(batch.quot.is_empty());
+            assert_eq!(batch.errors.len(), 2);
+            assert!(batch.errors["sh600063"].contains("mock error"));
+        });
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let (a, _, _) = mock(10.0);
+            let (b, _, b_calls) = mock(10.5);
+            let opts = FailoverOpts {
+                cross_check: Some(0.01),
+                ..Default::default()
+            };
+            let fetcher = FailoverFetcher::new(opts)
+                .with_source("a", Box::new(a))
+                .with_source("b", Box::new(b));
+            let codes = vec!["sh600063".to_string()];
+
+            let rs = fetcher.fetch_rt_quot(&codes).await.unwrap();
+            assert_eq!(rs["sh600063"].now, Price::from(10.0));
+            assert_eq!(b_calls.load(Ordering::SeqCst), 1);
+            let health = fetcher.health();
+            assert_eq!(health[0].mismatch, 1);
+            assert_eq!(health[1].mismatch, 1);
+        });
+    }
+}
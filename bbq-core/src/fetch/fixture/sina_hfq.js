@@ -0,0 +1 @@
+var hfq_data={total:2,data:[{d:"2022-03-01",f:"2.2000000000000000"},{d:"1990-12-19",f:"2.0000000000000000"}]}
@@ -0,0 +1 @@
+var qfq_data={total:2,data:[{d:"2022-03-01",f:"1.0000000000000000"},{d:"1990-12-19",f:"1.1000000000000000"}]}
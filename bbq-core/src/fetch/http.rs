@@ -0,0 +1,193 @@
+use anyhow::{Context, Result};
+use reqwest::{header::HeaderMap, Client};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 行情请求模式
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpMode {
+    #[default]
+    Live,
+    Record,
+    Replay,
+}
+
+impl Display for HttpMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            HttpMode::Live => "实时",
+            HttpMode::Record => "录制",
+            HttpMode::Replay => "回放",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct HttpOpts {
+    pub mode: HttpMode,
+    // 录制/回放文件目录
+    pub path: String,
+}
+
+/// http 响应, 录制时原样保存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HttpResponse {
+    pub url: String,
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// 行情 http 客户端
+///
+/// - 实时: 直接请求
+/// - 录制: 请求并将成功的响应保存到 `path`
+/// - 回放: 不发起请求, 从 `path` 读取录制的响应
+///
+/// 录制文件按 url 的路径及参数(不含域名)命名, 更换服务地址后仍可回放
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    client: Client,
+    opts: HttpOpts,
+}
+
+impl HttpClient {
+    pub fn new(headers: HeaderMap, opts: HttpOpts) -> Self {
+        let client = Client::builder().default_headers(headers).build().unwrap();
+        Self { client, opts }
+    }
+
+    pub fn mode(&self) -> &HttpMode {
+        &self.opts.mode
+    }
+
+    pub async fn get(&self, url: &str) -> Result<HttpResponse> {
+        if let HttpMode::Replay = self.opts.mode {
+            return self.load(url);
+        }
+        let resp = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .with_context(|| format!("Send http request error: {}", url))?;
+        let status = resp.status().as_u16();
+        let body = resp
+            .text()
+            .await
+            .with_context(|| format!("Read http response error: {}", url))?;
+        let resp = HttpResponse {
+            url: url.to_string(),
+            status,
+            body,
+        };
+        if let HttpMode::Record = self.opts.mode {
+            if resp.is_success() {
+                self.save(&resp)?;
+            }
+        }
+        Ok(resp)
+    }
+
+    fn file(&self, url: &str) -> PathBuf {
+        Path::new(&self.opts.path).join(format!("{:016x}.json", fnv1a(record_key(url))))
+    }
+
+    fn save(&self, resp: &HttpResponse) -> Result<()> {
+        fs::create_dir_all(&self.opts.path)
+            .with_context(|| format!("failed to create record path: {}", &self.opts.path))?;
+        let file = self.file(&resp.url);
+        let data =
+            serde_json::to_string_pretty(resp).with_context(|| "failed to serialize record")?;
+        fs::write(&file, data).with_context(|| format!("failed to write record: {:?}", &file))?;
+        Ok(())
+    }
+
+    fn load(&self, url: &str) -> Result<HttpResponse> {
+        let file = self.file(url);
+        let data = fs::read_to_string(&file)
+            .with_context(|| format!("no record for url: {}, file: {:?}", url, &file))?;
+        let resp = serde_json::from_str(&data)
+            .with_context(|| format!("failed to deserialize record: {:?}", &file))?;
+        Ok(resp)
+    }
+}
+
+/// 去除协议及域名, 如 `http://hq.sinajs.cn/?list=sh600063` -> `/?list=sh600063`
+fn record_key(url: &str) -> &str {
+    let rest = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+    match rest.find('/') {
+        Some(pos) => &rest[pos..],
+        None => "/",
+    }
+}
+
+/// FNV-1a, 录制文件名需跨版本稳定, 不使用标准库 hasher
+fn fnv1a(s: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for b in s.bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod test_http {
+    use super::{record_key, HttpClient, HttpMode, HttpOpts};
+    use crate::fetch::stub;
+    use reqwest::header::HeaderMap;
+
+    #[test]
+    fn test_record_replay() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            assert_eq!(record_key("http://hq.sinajs.cn/?list=a"), "/?list=a");
+            assert_eq!(record_key("http://hq.sinajs.cn"), "/");
+
+            let path = std::env::temp_dir().join(format!("bbq-record-{}", std::process::id()));
+            let path = path.to_str().unwrap().to_string();
+            let url = stub::serve(vec![("/ok", "recorded")]).await;
+
+            let record = HttpClient::new(
+                HeaderMap::new(),
+                HttpOpts {
+                    mode: HttpMode::Record,
+                    path: path.clone(),
+                },
+            );
+            let resp = record.get(&format!("{}/ok?a=1", &url)).await.unwrap();
+            assert_eq!(resp.body, "recorded");
+            let resp = record.get(&format!("{}/none", &url)).await.unwrap();
+            assert_eq!(resp.status, 404);
+
+            // 回放不依赖服务地址
+            let replay = HttpClient::new(
+                HeaderMap::new(),
+                HttpOpts {
+                    mode: HttpMode::Replay,
+                    path: path.clone(),
+                },
+            );
+            let resp = replay.get("http://other.host/ok?a=1").await.unwrap();
+            assert_eq!((resp.status, resp.body.as_str()), (200, "recorded"));
+            assert!(replay.get("http://other.host/none").await.is_err());
+
+            let _ = std::fs::remove_dir_all(&path);
+        });
+    }
+}
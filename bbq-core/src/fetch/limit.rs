@@ -0,0 +1,78 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// 令牌桶限速, 每秒补充 `rate` 个令牌, 最多累积 `burst` 个
+#[derive(Debug)]
+pub struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    // (剩余令牌, 上次补充时间), 令牌为负表示已预支
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    /// `rate` 不大于 0 时不限速
+    pub fn new(rate: f64, burst: u32) -> Self {
+        let burst = burst.max(1) as f64;
+        Self {
+            rate,
+            burst,
+            state: Mutex::new((burst, Instant::now())),
+        }
+    }
+
+    /// 获取一个令牌, 不足时等待补充
+    pub async fn acquire(&self) {
+        if self.rate <= 0.0 {
+            return;
+        }
+        let wait = {
+            let mut state = self.state.lock().unwrap();
+            let now = Instant::now();
+            let elapsed = now.duration_since(state.1).as_secs_f64();
+            let tokens = (state.0 + elapsed * self.rate).min(self.burst) - 1.0;
+            *state = (tokens, now);
+            if tokens >= 0.0 {
+                Duration::ZERO
+            } else {
+                Duration::from_secs_f64(-tokens / self.rate)
+            }
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_limit {
+    use super::RateLimiter;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_rate_limiter() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let limiter = RateLimiter::new(20.0, 2);
+            let start = Instant::now();
+            for _ in 0..2 {
+                limiter.acquire().await;
+            }
+            assert!(start.elapsed() < Duration::from_millis(40));
+            for _ in 0..2 {
+                limiter.acquire().await;
+            }
+            assert!(start.elapsed() >= Duration::from_millis(95));
+
+            let limiter = RateLimiter::new(0.0, 1);
+            let start = Instant::now();
+            for _ in 0..100 {
+                limiter.acquire().await;
+            }
+            assert!(start.elapsed() < Duration::from_millis(40));
+        });
+    }
+}
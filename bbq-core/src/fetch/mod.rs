@@ -1,34 +1,180 @@
-use anyhow::Result;
+use crate::data::{FqFactor, StockIndicator};
+use crate::{Board, Money, Price};
+use anyhow::{bail, Result};
 use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{de::Error, Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
+use std::fmt::Display;
 
-mod trade_date;
-pub use trade_date::is_trade_date;
+mod http;
+pub use http::{HttpClient, HttpMode, HttpOpts, HttpResponse};
+
+mod limit;
+pub use limit::RateLimiter;
 
 mod sina;
-pub use sina::Sina;
+pub use sina::{Sina, SinaOpts};
+
+mod tencent;
+pub use tencent::{Tencent, TencentOpts};
+
+mod failover;
+pub use failover::{FailoverFetcher, FailoverOpts, SourceHealth};
+
+#[cfg(test)]
+mod stub;
 
 pub type RtQuot = HashMap<String, Quot>;
 
+/// 批量实时行情, 部分代码失败时保留已获取的行情及失败代码的错误
+#[derive(Debug, Clone, Default)]
+pub struct RtQuotBatch {
+    pub quot: RtQuot,
+    // 代码 -> 错误信息
+    pub errors: HashMap<String, String>,
+}
+
+impl RtQuotBatch {
+    /// 合并一批代码的请求结果, 响应中缺失的代码记为错误
+    pub fn merge(&mut self, codes: &[String], rs: Result<RtQuot>) {
+        self.merge_batch(
+            codes,
+            rs.map(|quot| RtQuotBatch {
+                quot,
+                errors: HashMap::new(),
+            }),
+        );
+    }
+
+    /// 合并一批代码的请求结果, 保留解析失败代码的错误, 响应中缺失的代码记为错误
+    pub fn merge_batch(&mut self, codes: &[String], rs: Result<RtQuotBatch>) {
+        match rs {
+            Ok(mut batch) => {
+                for code in codes {
+                    if let Some(q) = batch.quot.remove(code) {
+                        self.quot.insert(code.clone(), q);
+                    } else {
+                        let e = batch
+                            .errors
+                            .remove(code)
+                            .unwrap_or_else(|| "no quot data".to_string());
+                        self.errors.insert(code.clone(), e);
+                    }
+                }
+            }
+            Err(e) => {
+                let e = format!("{:#}", e);
+                for code in codes {
+                    self.errors.insert(code.clone(), e.clone());
+                }
+            }
+        }
+    }
+
+    /// 全部失败时返回错误, 否则返回已获取的行情
+    pub fn into_result(self) -> Result<RtQuot> {
+        if self.quot.is_empty() {
+            if let Some((code, e)) = self.errors.iter().next() {
+                bail!("fetch rt quot failed, {}: {}", code, e);
+            }
+        }
+        Ok(self.quot)
+    }
+}
+
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct Quot {
     pub code: String,
     pub name: String,
-    pub open: f64,
-    pub pre_close: f64,
-    pub now: f64,
-    pub high: f64,
-    pub low: f64,
-    pub buy: f64,
-    pub sell: f64,
+    pub open: Price,
+    pub pre_close: Price,
+    pub now: Price,
+    pub high: Price,
+    pub low: Price,
+    pub buy: Price,
+    pub sell: Price,
     pub vol: u64,
-    pub amount: f64,
-    pub bid: ((u32, f64), (u32, f64), (u32, f64), (u32, f64), (u32, f64)),
-    pub ask: ((u32, f64), (u32, f64), (u32, f64), (u32, f64), (u32, f64)),
-    pub date: String,
-    pub time: String,
+    pub amount: Money,
+    pub bid: ((u32, Price), (u32, Price), (u32, Price), (u32, Price), (u32, Price)),
+    pub ask: ((u32, Price), (u32, Price), (u32, Price), (u32, Price), (u32, Price)),
+    #[serde(with = "crate::time_fmt::date")]
+    pub date: NaiveDate,
+    #[serde(with = "crate::time_fmt::datetime")]
+    pub time: NaiveDateTime,
+    #[serde(default)]
+    pub status: TradeStatus,
+}
+
+/// 交易状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeStatus {
+    // 正常交易
+    #[default]
+    Trading,
+    // 盘中临时停牌
+    Halted,
+    // 停牌
+    Suspended,
+}
+
+impl TradeStatus {
+    pub fn is_trading(&self) -> bool {
+        matches!(self, TradeStatus::Trading)
+    }
+}
+
+impl Display for TradeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            TradeStatus::Trading => "交易",
+            TradeStatus::Halted => "临时停牌",
+            TradeStatus::Suspended => "停牌",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 复权方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdjustMode {
+    // 不复权
+    #[default]
+    None,
+    // 前复权
+    Qfq,
+    // 后复权
+    Hfq,
+}
+
+impl Display for AdjustMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            AdjustMode::None => "不复权",
+            AdjustMode::Qfq => "前复权",
+            AdjustMode::Hfq => "后复权",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 证券基本信息
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct SecurityInfo {
+    // 代码, 如 sh600063
+    pub code: String,
+    pub name: String,
+    // 上市日期, 行情源不提供时为空
+    pub listing_date: Option<NaiveDate>,
+    pub board: Board,
+    // 停牌
+    pub suspended: bool,
+    // ST/*ST
+    pub st: bool,
 }
 
 pub type StockBarList = Vec<StockBar>;
@@ -36,40 +182,96 @@ pub type StockBarList = Vec<StockBar>;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct StockBar {
-    #[serde(rename = "day")]
-    pub time: String,
-    #[serde(deserialize_with = "from_str2f64")]
-    pub open: f64,
-    #[serde(deserialize_with = "from_str2f64")]
-    pub high: f64,
-    #[serde(deserialize_with = "from_str2f64")]
-    pub low: f64,
-    #[serde(deserialize_with = "from_str2f64")]
-    pub close: f64,
+    #[serde(rename = "day", with = "crate::time_fmt::datetime")]
+    pub time: NaiveDateTime,
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
     #[serde(rename = "volume", deserialize_with = "from_str2u64")]
     pub vol: u64,
 }
 
-fn from_str2f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let s: &str = Deserialize::deserialize(deserializer)?;
-    s.parse().map_err(D::Error::custom)
+/// 保留日期在 `[start, end]` 内的k线, 不指定则不限
+fn retain_date_range(bars: &mut StockBarList, start: Option<NaiveDate>, end: Option<NaiveDate>) {
+    bars.retain(|bar| {
+        let date = bar.time.date();
+        start.is_none_or(|start| date >= start) && end.is_none_or(|end| date <= end)
+    });
+}
+
+/// 开盘价及成交量为 0 视为停牌
+fn infer_status(q: &Quot) -> TradeStatus {
+    if q.open.is_zero() && q.vol == 0 {
+        TradeStatus::Suspended
+    } else {
+        TradeStatus::Trading
+    }
 }
 
+/// 行情源成交量为字符串, 本地存储为数字
 fn from_str2u64<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s: &str = Deserialize::deserialize(deserializer)?;
-    s.parse().map_err(D::Error::custom)
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Volume {
+        Str(String),
+        Num(u64),
+    }
+    match Deserialize::deserialize(deserializer)? {
+        Volume::Str(s) => s.parse().map_err(D::Error::custom),
+        Volume::Num(n) => Ok(n),
+    }
 }
 
 #[async_trait]
 pub trait Fetcher: Send + Sync {
     async fn fetch_stock_minute(&self, code: &str, min: u32) -> Result<StockBarList>;
     async fn fetch_rt_quot(&self, codes: &Vec<String>) -> Result<RtQuot>;
+    /// 股票日k线, 日期区间为闭区间, 不指定则不限
+    async fn fetch_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        adjust: AdjustMode,
+    ) -> Result<StockBarList>;
+    /// 指数日k线, 日期区间为闭区间, 不指定则不限
+    async fn fetch_index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList>;
+    /// 沪深京A股列表
+    async fn fetch_stock_list(&self) -> Result<Vec<SecurityInfo>> {
+        bail!("fetch stock list not supported")
+    }
+    /// 沪深指数列表
+    async fn fetch_index_list(&self) -> Result<Vec<SecurityInfo>> {
+        bail!("fetch index list not supported")
+    }
+    /// 股票全部复权因子, 按日期升序
+    async fn fetch_fq_factor(&self, _code: &str) -> Result<Vec<FqFactor>> {
+        bail!("fetch fq factor not supported")
+    }
+    /// 股票每日指标, 日期区间为闭区间, 不指定则不限
+    async fn fetch_stock_indicator(
+        &self,
+        _code: &str,
+        _start: Option<NaiveDate>,
+        _end: Option<NaiveDate>,
+    ) -> Result<Vec<StockIndicator>> {
+        bail!("fetch stock indicator not supported")
+    }
+    /// 批量实时行情, 部分代码失败不影响其他代码
+    async fn fetch_rt_quot_batch(&self, codes: &Vec<String>) -> RtQuotBatch {
+        let mut batch = RtQuotBatch::default();
+        batch.merge(codes, self.fetch_rt_quot(codes).await);
+        batch
+    }
 }
 
 pub fn is_index(code: &str) -> bool {
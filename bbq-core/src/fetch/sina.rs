@@ -1,56 +1,176 @@
-use crate::fetch::{Fetcher, Quot, RtQuot, StockBarList};
-use anyhow::{bail, Context, Ok, Result};
+use crate::fetch::{
+    infer_status, retain_date_range, AdjustMode, Fetcher, HttpClient, HttpMode, HttpOpts, Quot, RateLimiter,
+    RtQuot, RtQuotBatch, SecurityInfo, StockBarList, TradeStatus,
+};
+use crate::data::FqFactor;
+use crate::{Instrument, Price};
+use anyhow::{anyhow, bail, Context, Result};
 use async_trait::async_trait;
-use chrono::{NaiveDate, NaiveTime};
+use backoff::{backoff::Backoff, ExponentialBackoff};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use futures::{stream, StreamExt};
+use log::{debug, warn};
 use regex::Regex;
-use reqwest::{header::HeaderMap, Client};
+use reqwest::{header::HeaderMap, StatusCode};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+const QUOT_URL: &str = "http://hq.sinajs.cn";
+const KLINE_URL: &str = "http://quotes.sina.cn";
+const FACTOR_URL: &str = "http://finance.sina.com.cn";
+const LIST_URL: &str = "http://vip.stock.finance.sina.com.cn";
+// 证券列表每页数量
+const LIST_PAGE_SIZE: usize = 80;
+// 日k线周期(分钟)
+const DAY_SCALE: u32 = 240;
+// 沪深京实时行情最少字段数, 不含状态
+const A_QUOT_FIELDS: usize = 32;
+// 基金净值最少字段数
+const FUND_QUOT_FIELDS: usize = 5;
+// 港股实时行情最少字段数
+const HK_QUOT_FIELDS: usize = 19;
+// 美股实时行情最少字段数
+const US_QUOT_FIELDS: usize = 11;
+
+/// 新浪行情参数
+#[derive(Debug, Clone)]
+pub struct SinaOpts {
+    pub quot_url: String,
+    pub kline_url: String,
+    // 复权因子
+    pub factor_url: String,
+    // 证券列表
+    pub list_url: String,
+    // 每次请求的代码数量
+    pub chunk_size: usize,
+    // 最大并发请求数
+    pub concurrency: usize,
+    // 每秒请求数, 0 为不限速
+    pub rate: f64,
+    // 突发请求数
+    pub burst: u32,
+    // 首次重试间隔
+    pub retry_interval: Duration,
+    // 重试总时长
+    pub retry_elapsed: Duration,
+    // 实时/录制/回放
+    pub http: HttpOpts,
+}
+
+impl Default for SinaOpts {
+    fn default() -> Self {
+        Self {
+            quot_url: QUOT_URL.to_string(),
+            kline_url: KLINE_URL.to_string(),
+            factor_url: FACTOR_URL.to_string(),
+            list_url: LIST_URL.to_string(),
+            chunk_size: 100,
+            concurrency: 4,
+            rate: 5.0,
+            burst: 5,
+            retry_interval: Duration::from_millis(500),
+            retry_elapsed: Duration::from_secs(10),
+            http: Default::default(),
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Sina {
-    regex: Regex,
-    client: Client,
+    http: HttpClient,
+    opts: SinaOpts,
+    limiter: Arc<RateLimiter>,
+}
+
+impl Default for Sina {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Sina {
     pub fn new() -> Self {
-        let mut re_str = String::from(r"(\w+)=([^\s][^,]+?)");
-        for _ in 0..29 {
-            re_str.push_str(r",([\.\d]+)")
-        }
-        for _ in 0..2 {
-            re_str.push_str(r",([-\.\d:]+)");
-        }
+        Self::with_opts(SinaOpts::default())
+    }
 
+    pub fn with_opts(opts: SinaOpts) -> Self {
         let mut h = HeaderMap::new();
         h.insert("user-agent",
                  "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_12_6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/73.0.3683.86 Safari/537.36".parse().unwrap());
         h.insert("Referer", "http://finance.sina.com.cn/".parse().unwrap());
 
-        let builder = reqwest::Client::builder().default_headers(h);
-
         Self {
-            regex: Regex::new(re_str.as_str()).unwrap(),
-            client: builder.build().unwrap(),
+            http: HttpClient::new(h, opts.http.clone()),
+            limiter: Arc::new(RateLimiter::new(opts.rate, opts.burst)),
+            opts,
         }
     }
 
-   
-}
+    /// 回放时不限速
+    async fn throttle(&self) {
+        if self.http.mode() != &HttpMode::Replay {
+            self.limiter.acquire().await;
+        }
+    }
 
-#[async_trait]
-impl Fetcher for Sina {
-    async fn fetch_stock_minute(&self, code: &str, min: u32) -> Result<StockBarList> {
-        let url = format!("http://quotes.sina.cn/cn/api/jsonp_v2.php/=/CN_MarketDataService.getKLineData?symbol={}&scale={}&datalen=20000", code, min);
+    /// 请求一批代码, 限速并对超时/连接失败/403/429/5xx 按指数退避重试
+    async fn fetch_chunk(&self, codes: &[String]) -> Result<RtQuotBatch> {
+        let url = format!(
+            "{}/?format=text&list={}",
+            &self.opts.quot_url,
+            codes.join(",")
+        );
+        let mut backoff = ExponentialBackoff {
+            current_interval: self.opts.retry_interval,
+            initial_interval: self.opts.retry_interval,
+            max_elapsed_time: Some(self.opts.retry_elapsed),
+            ..Default::default()
+        };
+        loop {
+            self.throttle().await;
+            let (e, transient) = match self.http.get(&url).await {
+                Ok(resp) if resp.is_success() => return Self::parse_rt_quot(&resp.body),
+                Ok(resp) => (
+                    anyhow!("Sina http status error: {}!", resp.status),
+                    is_transient_status(resp.status),
+                ),
+                Err(e) => {
+                    let transient = is_transient(&e);
+                    (e, transient)
+                }
+            };
+            match backoff.next_backoff() {
+                Some(d) if transient => {
+                    debug!("sina request error: {}, retry in {:?}", e, d);
+                    tokio::time::sleep(d).await;
+                }
+                _ => return Err(e),
+            }
+        }
+    }
+
+    async fn get(&self, url: &str) -> Result<String> {
+        self.throttle().await;
 
-        let data = self
-            .client
+        let resp = self
+            .http
             .get(url)
-            .send()
-            .await
-            .with_context(|| "Send sina http request error!")?
-            .text()
             .await
-            .with_context(|| "Parse sina http response error!")?;
+            .with_context(|| "Send sina http request error!")?;
+        if !resp.is_success() {
+            bail!("Sina http status error: {}!", resp.status);
+        }
+        Ok(resp.body)
+    }
+
+    /// k线, `scale` 为周期(分钟), 日k线时间为日期
+    async fn fetch_kline(&self, code: &str, scale: u32) -> Result<StockBarList> {
+        let url = format!("{}/cn/api/jsonp_v2.php/=/CN_MarketDataService.getKLineData?symbol={}&scale={}&datalen=20000", &self.opts.kline_url, code, scale);
+        let data = self.get(&url).await?;
 
         let js: Vec<&str> = data.split("=(").collect();
         if js.len() < 2 {
@@ -64,162 +184,712 @@ impl Fetcher for Sina {
         Ok(serde_json::from_str(js.unwrap())?)
     }
 
-    async fn fetch_rt_quot(&self, code: &Vec<String>) -> Result<RtQuot> {
-        let url = format!("http://hq.sinajs.cn/?format=text&list={}", code.join(","));
+    /// 不复权日k线, 时间为收盘时间 15:00:00
+    async fn fetch_day_kline(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        let mut bars = self.fetch_kline(code, DAY_SCALE).await?;
+        retain_date_range(&mut bars, start, end);
+        for bar in bars.iter_mut() {
+            bar.time = bar
+                .time
+                .date()
+                .and_time(NaiveTime::from_hms_opt(15, 0, 0).unwrap());
+        }
+        Ok(bars)
+    }
 
-        let data = self
-            .client
-            .get(url)
-            .send()
-            .await
-            .with_context(|| "Send sina http request error!")?
-            .text()
-            .await
-            .with_context(|| "Parse sina http response error!")?;
+    /// 复权因子, 格式: `var qfq_data={total:2,data:[{d:"2022-06-16",f:"1.0000"},...]}`
+    ///
+    /// 返回生效日期 -> 因子
+    async fn fetch_factor(
+        &self,
+        code: &str,
+        adjust: AdjustMode,
+    ) -> Result<BTreeMap<NaiveDate, f64>> {
+        let name = match adjust {
+            AdjustMode::Qfq => "qfq",
+            AdjustMode::Hfq => "hfq",
+            AdjustMode::None => bail!("sina factor not support adjust: {}", adjust),
+        };
+        let url = format!(
+            "{}/realstock/company/{}/{}.js",
+            &self.opts.factor_url, code, name
+        );
+        let data = self.get(&url).await?;
+        Self::parse_factor(&data)
+    }
 
-        if !self.regex.is_match(data.as_str()) {
-            bail!("Sina response data error!");
+    fn parse_factor(data: &str) -> Result<BTreeMap<NaiveDate, f64>> {
+        let re =
+            Regex::new(r#""?d"?\s*:\s*"([\d-]+)"\s*,\s*"?f"?\s*:\s*"([\d.]+)""#).unwrap();
+        let mut factors = BTreeMap::new();
+        for cap in re.captures_iter(data) {
+            let date = NaiveDate::parse_from_str(&cap[1], "%Y-%m-%d")
+                .with_context(|| format!("Parse factor date error: {}!", &cap[1]))?;
+            let factor: f64 = cap[2]
+                .parse()
+                .with_context(|| format!("Parse factor error: {}!", &cap[2]))?;
+            factors.insert(date, factor);
         }
+        if factors.is_empty() {
+            bail!("Sina factor data error!");
+        }
+        Ok(factors)
+    }
 
-        let mut rq = RtQuot::new();
-        for cap in self.regex.captures_iter(data.as_str()) {
-            let date: NaiveDate = cap[32]
-                .parse()
-                .with_context(|| format!("Parse naive_date error: {}!", &cap[32]))?;
-            let time: NaiveTime = cap[33]
-                .parse()
-                .with_context(|| format!("Parse naive_time error: {}!", &cap[33]))?;
-
-            let date = date.format("%Y-%m-%d").to_string();
-            let time = format!("{} {}", date, time.format("%H:%M:%S").to_string());
-
-            let q = Quot {
-                code: String::from(&cap[1]),
-                name: String::from(&cap[2]),
-                open: cap[3]
-                    .parse()
-                    .with_context(|| format!("Parse open error: {}!", &cap[3]))?,
-                pre_close: cap[4]
-                    .parse()
-                    .with_context(|| format!("Parse pre_close error: {}!", &cap[4]))?,
-                now: cap[5]
-                    .parse()
-                    .with_context(|| format!("Parse now error: {}!", &cap[5]))?,
-                high: cap[6]
-                    .parse()
-                    .with_context(|| format!("Parse high error: {}!", &cap[6]))?,
-                low: cap[7]
-                    .parse()
-                    .with_context(|| format!("Parse low error: {}!", &cap[7]))?,
-                buy: cap[8]
-                    .parse()
-                    .with_context(|| format!("Parse buy error: {}!", &cap[8]))?,
-                sell: cap[9]
-                    .parse()
-                    .with_context(|| format!("Parse sell error: {}!", &cap[9]))?,
-                vol: cap[10]
-                    .parse()
-                    .with_context(|| format!("Parse vol error: {}!", &cap[10]))?,
-                amount: cap[11]
-                    .parse()
-                    .with_context(|| format!("Parse amount error: {}!", &cap[11]))?,
-                bid: (
-                    (
-                        cap[12]
-                            .parse()
-                            .with_context(|| format!("Parse bid[1] error: {}!", &cap[12]))?,
-                        cap[13]
-                            .parse()
-                            .with_context(|| format!("Parse bid[1] error: {}!", &cap[13]))?,
-                    ),
-                    (
-                        cap[14]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[14]))?,
-                        cap[15]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[14]))?,
-                    ),
-                    (
-                        cap[16]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[16]))?,
-                        cap[17]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[17]))?,
-                    ),
-                    (
-                        cap[18]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[18]))?,
-                        cap[19]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[19]))?,
-                    ),
-                    (
-                        cap[20]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[20]))?,
-                        cap[21]
-                            .parse()
-                            .with_context(|| format!("Parse bid error: {}!", &cap[21]))?,
-                    ),
-                ),
-                ask: (
-                    (
-                        cap[22]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[22]))?,
-                        cap[23]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[23]))?,
-                    ),
-                    (
-                        cap[24]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[24]))?,
-                        cap[25]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[25]))?,
-                    ),
-                    (
-                        cap[26]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[26]))?,
-                        cap[27]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[27]))?,
-                    ),
-                    (
-                        cap[28]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[28]))?,
-                        cap[29]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[29]))?,
-                    ),
-                    (
-                        cap[30]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[30]))?,
-                        cap[31]
-                            .parse()
-                            .with_context(|| format!("Parse ask error: {}!", &cap[31]))?,
-                    ),
-                ),
-                date,
-                time,
+    /// 前复权价格 = 价格 / 前复权因子, 后复权价格 = 价格 * 后复权因子
+    ///
+    /// 因子按生效日期向后沿用
+    fn adjust(bars: &mut StockBarList, factors: &BTreeMap<NaiveDate, f64>, adjust: AdjustMode) {
+        for bar in bars.iter_mut() {
+            let factor = factors
+                .range(..=bar.time.date())
+                .next_back()
+                .map(|(_, factor)| *factor);
+            let ratio = match (factor, adjust) {
+                (Some(f), AdjustMode::Qfq) if f != 0.0 => 1.0 / f,
+                (Some(f), AdjustMode::Hfq) => f,
+                _ => continue,
             };
-            rq.insert(q.code.clone(), q);
+            bar.open = bar.open * ratio;
+            bar.high = bar.high * ratio;
+            bar.low = bar.low * ratio;
+            bar.close = bar.close * ratio;
+        }
+    }
+
+    /// 按行情中心节点分页获取证券列表, `hs_a` 为A股, `hs_s` 为沪深指数
+    async fn fetch_list(&self, node: &str) -> Result<Vec<SecurityInfo>> {
+        let mut list = vec![];
+        for page in 1.. {
+            let url = format!(
+                "{}/quotes_service/api/json_v2.php/Market_Center.getHQNodeData?page={}&num={}&sort=symbol&asc=1&node={}",
+                &self.opts.list_url, page, LIST_PAGE_SIZE, node
+            );
+            let data = self.get(&url).await?;
+            let items = Self::parse_list(&data)?;
+            let is_end = items.len() < LIST_PAGE_SIZE;
+            list.extend(items);
+            if is_end {
+                break;
+            }
+        }
+        Ok(list)
+    }
+
+    /// 解析证券列表, 格式: `[{"symbol":"sh600063","name":"皖维高新","open":"5.610","volume":23578900,...}]`
+    ///
+    /// 超出页数时返回 `null` 或 `[]`; 开盘价及成交量为 0 视为停牌, 名称含 ST 视为 ST
+    fn parse_list(data: &str) -> Result<Vec<SecurityInfo>> {
+        let js: Value =
+            serde_json::from_str(data.trim()).with_context(|| "Parse sina list error!")?;
+        if js.is_null() {
+            return Ok(vec![]);
+        }
+        let rows = js.as_array().with_context(|| "Sina list data error!")?;
+        let mut list = vec![];
+        for row in rows {
+            let code = row["symbol"]
+                .as_str()
+                .with_context(|| format!("Sina list row error: {}", row))?;
+            let instrument = Instrument::parse(code)?;
+            let name = row["name"].as_str().unwrap_or_default().to_string();
+            let number = |key: &str| -> f64 {
+                let v = &row[key];
+                v.as_f64()
+                    .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+                    .unwrap_or_default()
+            };
+            list.push(SecurityInfo {
+                code: instrument.code,
+                st: name.to_uppercase().contains("ST"),
+                suspended: number("open") == 0.0 && number("volume") == 0.0,
+                name,
+                listing_date: None,
+                board: instrument.board,
+            });
+        }
+        Ok(list)
+    }
+
+    /// 解析实时行情, 每行格式: `sh600063=皖维高新,5.610,...`, 按代码前缀选择解析方式
+    ///
+    /// 无数据的代码返回 `sh000000=`, 忽略; 解析失败的代码记为错误
+    fn parse_rt_quot(data: &str) -> Result<RtQuotBatch> {
+        let mut batch = RtQuotBatch::default();
+        for line in data.lines() {
+            let line = line.trim().trim_end_matches(';');
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .with_context(|| format!("Sina response data error: {}!", line))?;
+            let code = key.trim().trim_start_matches("var hq_str_");
+            let value = value.trim_matches('"').trim_end_matches(',');
+            if value.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = value.split(',').collect();
+            match Self::parse_quot(code, &fields) {
+                Ok(q) => {
+                    batch.quot.insert(code.to_string(), q);
+                }
+                Err(e) => {
+                    batch.errors.insert(code.to_string(), format!("{:#}", e));
+                }
+            }
+        }
+        Ok(batch)
+    }
+
+    fn parse_quot(code: &str, fields: &[&str]) -> Result<Quot> {
+        let mut q = if code.starts_with("f_") {
+            Self::parse_fund_quot(code, fields)?
+        } else if code.starts_with("hk") {
+            Self::parse_hk_quot(code, fields)?
+        } else if code.starts_with("gb_") {
+            Self::parse_us_quot(code, fields)?
+        } else {
+            Self::parse_a_quot(code, fields)?
+        };
+        // 停牌时现价为 0, 以昨收计
+        if !q.status.is_trading() && q.now.is_zero() {
+            q.now = q.pre_close;
+        }
+        Ok(q)
+    }
+
+    /// 沪深京, 字段: 名称,今开,昨收,现价,最高,最低,买一,卖一,成交量,成交额,
+    /// 买一至买五(量,价),卖一至卖五(量,价),日期,时间,状态
+    ///
+    /// 状态: `00` 正常, `01`/`04`/`05` 盘中停牌, 其他停牌; 无状态时开盘价及成交量为 0 视为停牌
+    fn parse_a_quot(code: &str, fields: &[&str]) -> Result<Quot> {
+        if fields.len() < A_QUOT_FIELDS {
+            bail!("Sina quot fields error: {}", fields.len());
+        }
+        let level = |index: usize| -> Result<(u32, Price)> {
+            Ok((
+                parse(fields[index], "volume level")?,
+                parse(fields[index + 1], "price level")?,
+            ))
+        };
+        let date = parse_date(fields[30], "%Y-%m-%d")?;
+        let time = date.and_time(parse_time(fields[31])?);
+
+        let mut q = Quot {
+            code: code.to_string(),
+            name: fields[0].to_string(),
+            open: parse(fields[1], "open")?,
+            pre_close: parse(fields[2], "pre_close")?,
+            now: parse(fields[3], "now")?,
+            high: parse(fields[4], "high")?,
+            low: parse(fields[5], "low")?,
+            buy: parse(fields[6], "buy")?,
+            sell: parse(fields[7], "sell")?,
+            vol: parse(fields[8], "vol")?,
+            amount: parse(fields[9], "amount")?,
+            bid: (level(10)?, level(12)?, level(14)?, level(16)?, level(18)?),
+            ask: (level(20)?, level(22)?, level(24)?, level(26)?, level(28)?),
+            date,
+            time,
+            status: TradeStatus::Trading,
+        };
+        q.status = match fields.get(32).map(|s| s.trim()) {
+            Some("00") => TradeStatus::Trading,
+            Some("01") | Some("04") | Some("05") => TradeStatus::Halted,
+            Some(s) if !s.is_empty() => TradeStatus::Suspended,
+            _ => infer_status(&q),
+        };
+        Ok(q)
+    }
+
+    /// 基金净值, 字段: 名称,单位净值,累计净值,前单位净值,净值日期,...
+    ///
+    /// 时间为净值日期 15:00:00, 价格均为单位净值
+    fn parse_fund_quot(code: &str, fields: &[&str]) -> Result<Quot> {
+        if fields.len() < FUND_QUOT_FIELDS {
+            bail!("Sina fund quot fields error: {}", fields.len());
+        }
+        let now: Price = parse(fields[1], "nav")?;
+        let date = parse_date(fields[4], "%Y-%m-%d")?;
+        Ok(Quot {
+            code: code.to_string(),
+            name: fields[0].to_string(),
+            open: now,
+            pre_close: parse(fields[3], "pre_nav")?,
+            now,
+            high: now,
+            low: now,
+            buy: now,
+            sell: now,
+            date,
+            time: date.and_time(NaiveTime::from_hms_opt(15, 0, 0).unwrap()),
+            ..Default::default()
+        })
+    }
+
+    /// 港股, 字段: 英文名,名称,今开,昨收,最高,最低,现价,涨跌,涨跌幅,买一,卖一,
+    /// 成交额,成交量,市盈率,周息率,52周最高,52周最低,日期(`%Y/%m/%d`),时间
+    ///
+    /// 仅有买一卖一价, 无挂单量
+    fn parse_hk_quot(code: &str, fields: &[&str]) -> Result<Quot> {
+        if fields.len() < HK_QUOT_FIELDS {
+            bail!("Sina hk quot fields error: {}", fields.len());
         }
-        Ok(rq)
+        let buy: Price = parse(fields[9], "buy")?;
+        let sell: Price = parse(fields[10], "sell")?;
+        let date = parse_date(fields[17], "%Y/%m/%d")?;
+        let mut q = Quot {
+            code: code.to_string(),
+            name: fields[1].to_string(),
+            open: parse(fields[2], "open")?,
+            pre_close: parse(fields[3], "pre_close")?,
+            now: parse(fields[6], "now")?,
+            high: parse(fields[4], "high")?,
+            low: parse(fields[5], "low")?,
+            buy,
+            sell,
+            vol: parse(fields[12], "vol")?,
+            amount: parse(fields[11], "amount")?,
+            date,
+            time: date.and_time(parse_time(fields[18])?),
+            ..Default::default()
+        };
+        q.bid.0 .1 = buy;
+        q.ask.0 .1 = sell;
+        q.status = infer_status(&q);
+        Ok(q)
+    }
+
+    /// 美股, 字段: 名称,现价,涨跌幅,时间(北京时间),涨跌,今开,最高,最低,52周最高,52周最低,成交量,...
+    ///
+    /// 昨收为现价减涨跌, 无买卖盘
+    fn parse_us_quot(code: &str, fields: &[&str]) -> Result<Quot> {
+        if fields.len() < US_QUOT_FIELDS {
+            bail!("Sina us quot fields error: {}", fields.len());
+        }
+        let now: Price = parse(fields[1], "now")?;
+        let change: Price = parse(fields[4], "change")?;
+        let time = NaiveDateTime::parse_from_str(fields[3], "%Y-%m-%d %H:%M:%S")
+            .with_context(|| format!("Parse naive_date_time error: {}!", fields[3]))?;
+        let mut q = Quot {
+            code: code.to_string(),
+            name: fields[0].to_string(),
+            open: parse(fields[5], "open")?,
+            pre_close: now - change,
+            now,
+            high: parse(fields[6], "high")?,
+            low: parse(fields[7], "low")?,
+            buy: now,
+            sell: now,
+            vol: parse(fields[10], "vol")?,
+            date: time.date(),
+            time,
+            ..Default::default()
+        };
+        q.status = infer_status(&q);
+        Ok(q)
+    }
+}
+
+fn parse<T>(s: &str, name: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.trim()
+        .parse()
+        .map_err(|e| anyhow!("Parse {} error: {}, {}!", name, s, e))
+}
+
+fn parse_date(s: &str, fmt: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s.trim(), fmt)
+        .with_context(|| format!("Parse naive_date error: {}!", s))
+}
+
+/// 时间为 `%H:%M:%S` 或 `%H:%M`
+fn parse_time(s: &str) -> Result<NaiveTime> {
+    let s = s.trim();
+    NaiveTime::parse_from_str(s, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+        .with_context(|| format!("Parse naive_time error: {}!", s))
+}
+
+fn is_transient_status(status: u16) -> bool {
+    match StatusCode::from_u16(status) {
+        Ok(status) => {
+            status == StatusCode::FORBIDDEN
+                || status == StatusCode::TOO_MANY_REQUESTS
+                || status.is_server_error()
+        }
+        Err(_) => false,
+    }
+}
+
+/// 超时/连接失败可重试, 回放缺失等其他错误不重试
+fn is_transient(e: &anyhow::Error) -> bool {
+    match e.downcast_ref::<reqwest::Error>() {
+        Some(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+        None => false,
+    }
+}
+
+#[async_trait]
+impl Fetcher for Sina {
+    async fn fetch_stock_minute(&self, code: &str, min: u32) -> Result<StockBarList> {
+        self.fetch_kline(code, min).await
+    }
+
+    /// 复权因子取自 `finance.sina.com.cn`
+    async fn fetch_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        adjust: AdjustMode,
+    ) -> Result<StockBarList> {
+        let mut bars = self.fetch_day_kline(code, start, end).await?;
+        if adjust != AdjustMode::None {
+            let factors = self.fetch_factor(code, adjust).await?;
+            Self::adjust(&mut bars, &factors, adjust);
+        }
+        Ok(bars)
+    }
+
+    async fn fetch_index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        self.fetch_day_kline(code, start, end).await
+    }
+
+    /// 不含上市日期
+    async fn fetch_stock_list(&self) -> Result<Vec<SecurityInfo>> {
+        self.fetch_list("hs_a").await
+    }
+
+    async fn fetch_index_list(&self) -> Result<Vec<SecurityInfo>> {
+        self.fetch_list("hs_s").await
+    }
+
+    /// 合并前复权及后复权因子, 因子按生效日期向后沿用, 之前为 1
+    async fn fetch_fq_factor(&self, code: &str) -> Result<Vec<FqFactor>> {
+        let qfq = self.fetch_factor(code, AdjustMode::Qfq).await?;
+        let hfq = self.fetch_factor(code, AdjustMode::Hfq).await?;
+        let at = |factors: &BTreeMap<NaiveDate, f64>, date: &NaiveDate| {
+            factors
+                .range(..=*date)
+                .next_back()
+                .map_or(1.0, |(_, factor)| *factor)
+        };
+        let dates: BTreeSet<&NaiveDate> = qfq.keys().chain(hfq.keys()).collect();
+        Ok(dates
+            .into_iter()
+            .map(|date| FqFactor {
+                code: code.to_string(),
+                date: *date,
+                hfq_factor: at(&hfq, date),
+                qfq_factor: at(&qfq, date),
+            })
+            .collect())
+    }
+
+    async fn fetch_rt_quot(&self, codes: &Vec<String>) -> Result<RtQuot> {
+        let batch = self.fetch_rt_quot_batch(codes).await;
+        for (code, e) in batch.errors.iter() {
+            warn!("sina fetch {} failed: {}", code, e);
+        }
+        batch.into_result()
+    }
+
+    /// 按 `chunk_size` 分批并发请求
+    async fn fetch_rt_quot_batch(&self, codes: &Vec<String>) -> RtQuotBatch {
+        let mut tasks = vec![];
+        for chunk in codes.chunks(self.opts.chunk_size.max(1)) {
+            tasks.push(async move { (chunk, self.fetch_chunk(chunk).await) });
+        }
+        let results: Vec<(&[String], Result<RtQuotBatch>)> = stream::iter(tasks)
+            .buffer_unordered(self.opts.concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut batch = RtQuotBatch::default();
+        for (chunk, rs) in results {
+            batch.merge_batch(chunk, rs);
+        }
+        batch
     }
 }
 
 #[cfg(test)]
 mod test_sina {
-    use super::Sina;
-    use crate::fetch::Fetcher;
+    use super::{Sina, SinaOpts};
+    use crate::fetch::{stub, AdjustMode, Fetcher, HttpMode, HttpOpts, TradeStatus};
+    use crate::{Board, Price};
+    use chrono::NaiveDate;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn quot_line(code: &str) -> String {
+        let mut line = format!(
+            "{}=名称,5.61,5.60,5.63,5.70,5.55,5.62,5.63,23578900,132456789.000",
+            code
+        );
+        for _ in 0..10 {
+            line.push_str(",100,5.62");
+        }
+        line.push_str(",2022-03-01,15:00:03,00\n");
+        line
+    }
+
+    #[test]
+    fn test_sina_batch() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let requests = Arc::new(AtomicU32::new(0));
+            let counter = requests.clone();
+            // 首次请求返回 403, sz999999 始终返回 500, sh000000 无数据
+            let url = stub::serve_with(move |path| {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let list = path.split("list=").nth(1).unwrap_or_default();
+                if n == 0 {
+                    return (403, String::new());
+                }
+                if list.contains("sz999999") {
+                    return (500, String::new());
+                }
+                let body = list
+                    .split(',')
+                    .filter(|code| *code != "sh000000")
+                    .map(quot_line)
+                    .collect();
+                (200, body)
+            })
+            .await;
+
+            let sina = Sina::with_opts(SinaOpts {
+                quot_url: url,
+                chunk_size: 2,
+                concurrency: 1,
+                rate: 100.0,
+                retry_interval: Duration::from_millis(10),
+                retry_elapsed: Duration::from_millis(100),
+                ..Default::default()
+            });
+            let codes: Vec<String> = "sh600063,sh601456,sh000000,sz000001,sz999999"
+                .split(',')
+                .map(String::from)
+                .collect();
+            let batch = sina.fetch_rt_quot_batch(&codes).await;
+            assert_eq!(batch.quot.len(), 3);
+            assert_eq!(batch.quot["sz000001"].now, Price::from(5.63));
+            assert_eq!(batch.quot["sz000001"].ask.4, (100, Price::from(5.62)));
+            assert_eq!(batch.errors.len(), 2);
+            assert_eq!(batch.errors["sh000000"], "no quot data");
+            assert!(batch.errors["sz999999"].contains("500"));
+            assert!(requests.load(Ordering::SeqCst) > 4);
+
+            let rs = sina.fetch_rt_quot(&codes[..2].to_vec()).await.unwrap();
+            assert_eq!(rs.len(), 2);
+            assert!(sina.fetch_rt_quot(&codes[4..].to_vec()).await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_sina_markets() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let url = stub::serve(vec![("/?format=text", include_str!("fixture/sina_quot.txt"))])
+                .await;
+            let sina = Sina::with_opts(SinaOpts {
+                quot_url: url,
+                rate: 0.0,
+                ..Default::default()
+            });
+            let codes: Vec<String> =
+                "sh600063,sz000029,sh600000,sz000002,f_510050,hk00700,gb_aapl,sh000000"
+                    .split(',')
+                    .map(String::from)
+                    .collect();
+            let batch = sina.fetch_rt_quot_batch(&codes).await;
+            assert_eq!(batch.quot.len(), 6);
+
+            let q = &batch.quot["sh600063"];
+            assert_eq!(q.status, TradeStatus::Trading);
+            assert_eq!(q.bid.0, (150300, Price::from(5.62)));
+
+            // 停牌现价以昨收计
+            let q = &batch.quot["sz000029"];
+            assert_eq!(q.status, TradeStatus::Suspended);
+            assert_eq!(q.now, Price::from(10.02));
+            assert_eq!(batch.quot["sh600000"].status, TradeStatus::Halted);
+
+            let q = &batch.quot["f_510050"];
+            assert_eq!((q.now, q.pre_close), (Price::from(2.834), Price::from(2.84)));
+            assert_eq!(q.time.to_string(), "2022-03-01 15:00:00");
+
+            let q = &batch.quot["hk00700"];
+            assert_eq!(q.name, "腾讯控股");
+            assert_eq!((q.now, q.vol), (Price::from(370.0), 15994566));
+            assert_eq!(q.time.to_string(), "2022-03-01 16:08:00");
+
+            let q = &batch.quot["gb_aapl"];
+            assert_eq!(q.pre_close, Price::from(165.53));
+            assert_eq!(q.date, NaiveDate::from_ymd_opt(2022, 3, 2).unwrap());
+
+            // 解析失败及无数据的代码记为错误
+            assert_eq!(batch.errors.len(), 2);
+            assert!(batch.errors["sz000002"].contains("now"));
+            assert_eq!(batch.errors["sh000000"], "no quot data");
+        });
+    }
+
+    #[test]
+    fn test_sina_daily() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let url = stub::serve(vec![
+                ("/cn/api/jsonp_v2.php", include_str!("fixture/sina_day.txt")),
+                (
+                    "/realstock/company/sh600063/qfq.js",
+                    include_str!("fixture/sina_qfq.js"),
+                ),
+                (
+                    "/realstock/company/sh600063/hfq.js",
+                    include_str!("fixture/sina_hfq.js"),
+                ),
+            ])
+            .await;
+            let sina = Sina::with_opts(SinaOpts {
+                kline_url: url.clone(),
+                factor_url: url,
+                rate: 0.0,
+                ..Default::default()
+            });
+            let start = NaiveDate::from_ymd_opt(2022, 3, 1);
+
+            let bars = sina
+                .fetch_daily("sh600063", start, None, AdjustMode::None)
+                .await
+                .unwrap();
+            assert_eq!(bars.len(), 2);
+            assert_eq!(bars[0].time.to_string(), "2022-03-01 15:00:00");
+            assert_eq!(bars[0].close, Price::from(5.63));
+            assert_eq!(bars[0].vol, 23578900);
+
+            // 除权日前的k线按前复权因子调整
+            let bars = sina
+                .fetch_daily("sh600063", None, start, AdjustMode::Qfq)
+                .await
+                .unwrap();
+            assert_eq!(bars.len(), 2);
+            assert_eq!(bars[0].close, Price::from(5.0));
+            assert_eq!(bars[1].close, Price::from(5.63));
+
+            let bars = sina
+                .fetch_daily("sh600063", None, None, AdjustMode::Hfq)
+                .await
+                .unwrap();
+            assert_eq!(bars[0].close, Price::from(11.0));
+            assert_eq!(bars[2].close, Price::from(12.276));
+
+            let bars = sina
+                .fetch_index_daily("sh000001", start, start)
+                .await
+                .unwrap();
+            assert_eq!(bars.len(), 1);
+
+            let factors = sina.fetch_fq_factor("sh600063").await.unwrap();
+            assert_eq!(factors.len(), 2);
+            assert_eq!((factors[0].qfq_factor, factors[0].hfq_factor), (1.1, 2.0));
+            assert_eq!(factors[1].date, NaiveDate::from_ymd_opt(2022, 3, 1).unwrap());
+            assert_eq!((factors[1].qfq_factor, factors[1].hfq_factor), (1.0, 2.2));
+
+            assert!(sina
+                .fetch_daily("sh600000", None, None, AdjustMode::Qfq)
+                .await
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn test_sina_list() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            // 第 1 页 80 条, 第 2 页 2 条, 指数 1 页
+            let url = stub::serve_with(|path| {
+                let row = |symbol: String, name: &str, open: &str, volume: u64| {
+                    format!(
+                        r#"{{"symbol":"{}","code":"{}","name":"{}","trade":"5.630","open":"{}","volume":{}}}"#,
+                        &symbol,
+                        &symbol[2..],
+                        name,
+                        open,
+                        volume
+                    )
+                };
+                let rows: Vec<String> = if path.contains("node=hs_s") {
+                    vec![
+                        row("sh000001".to_string(), "上证指数", "3300.1", 100),
+                        row("sz399001".to_string(), "深证成指", "12000.5", 100),
+                    ]
+                } else if path.contains("page=1&") {
+                    (0..80)
+                        .map(|i| row(format!("sh600{:03}", i), "名称", "5.610", 100))
+                        .collect()
+                } else if path.contains("page=2&") {
+                    vec![
+                        row("sz000001".to_string(), "*ST平安", "5.610", 100),
+                        row("bj430047".to_string(), "诺思兰德", "0.000", 0),
+                    ]
+                } else {
+                    return (200, "null".to_string());
+                };
+                (200, format!("[{}]", rows.join(",")))
+            })
+            .await;
+            let sina = Sina::with_opts(SinaOpts {
+                list_url: url,
+                rate: 0.0,
+                ..Default::default()
+            });
+
+            let list = sina.fetch_stock_list().await.unwrap();
+            assert_eq!(list.len(), 82);
+            assert_eq!(list[0].code, "sh600000");
+            assert_eq!(list[0].board, Board::Main);
+            assert!(!list[0].st && !list[0].suspended);
+            assert!(list[80].st);
+            assert_eq!(list[81].board, Board::Bj);
+            assert!(list[81].suspended);
+
+            let list = sina.fetch_index_list().await.unwrap();
+            assert_eq!(list.len(), 2);
+            assert_eq!(list[1].name, "深证成指");
+            assert_eq!(list[1].board, Board::Index);
+        });
+    }
+
     #[test]
     fn test_sina_rt() {
         let rt = tokio::runtime::Builder::new_multi_thread()
@@ -227,17 +897,33 @@ mod test_sina {
             .build()
             .unwrap();
         rt.block_on(async move {
-            let sina = Sina::new();
+            // 回放录制的响应, 不访问网络
+            let sina = Sina::with_opts(SinaOpts {
+                http: HttpOpts {
+                    mode: HttpMode::Replay,
+                    path: concat!(env!("CARGO_MANIFEST_DIR"), "/src/fetch/fixture/replay")
+                        .to_string(),
+                },
+                ..Default::default()
+            });
 
             let codes: Vec<String> = "sh600063,sh601456"
                 .split(",")
-                .map(|x| String::from(x))
+                .map(String::from)
                 .collect();
             let rs = sina.fetch_rt_quot(&codes).await.unwrap();
-            println!("rt quot: {:?}", rs);
+            let q = rs.get("sh601456").unwrap();
+            assert_eq!(q.name, "国联证券");
+            assert_eq!(q.now, Price::from(12.58));
+            assert_eq!(q.amount.to_string(), "230456789");
+            assert_eq!(q.time.to_string(), "2022-03-01 15:00:03");
+
+            let r = sina.fetch_stock_minute("sh600063", 5).await.unwrap();
+            assert_eq!(r.len(), 2);
+            assert_eq!(r[1].close, Price::from(5.63));
+            assert_eq!(r[1].vol, 2345600);
 
-            // let r = sina.fetch_stock_minute("sh000001", 1).await.unwrap();
-            // println!("{:?}", r.get(0));
+            assert!(sina.fetch_stock_minute("sh600063", 1).await.is_err());
         });
     }
 }
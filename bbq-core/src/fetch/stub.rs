@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// 本地 http 桩, 按请求路径前缀返回录制的响应, 返回服务地址 `http://127.0.0.1:port`
+///
+/// 未匹配的路径返回 404
+pub async fn serve(routes: Vec<(&'static str, &'static str)>) -> String {
+    serve_with(
+        move |path| match routes.iter().find(|(prefix, _)| path.starts_with(prefix)) {
+            Some((_, body)) => (200, body.to_string()),
+            None => (404, String::new()),
+        },
+    )
+    .await
+}
+
+/// 本地 http 桩, 由 `handler` 按请求路径返回状态码及响应
+pub async fn serve_with<F>(handler: F) -> String
+where
+    F: Fn(&str) -> (u16, String) + Send + Sync + 'static,
+{
+    let handler = Arc::new(handler);
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        loop {
+            let (mut conn, _) = match listener.accept().await {
+                Ok(conn) => conn,
+                Err(_) => break,
+            };
+            let handler = handler.clone();
+            tokio::spawn(async move {
+                let mut buf = vec![0u8; 8192];
+                let mut len = 0;
+                while len < buf.len() {
+                    match conn.read(&mut buf[len..]).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => len += n,
+                    }
+                    if buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+                        break;
+                    }
+                }
+                let req = String::from_utf8_lossy(&buf[..len]);
+                let path = req.split_whitespace().nth(1).unwrap_or("/");
+                let (status, body) = handler(path);
+                let resp = format!(
+                    "HTTP/1.1 {} STUB\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = conn.write_all(resp.as_bytes()).await;
+                let _ = conn.shutdown().await;
+            });
+        }
+    });
+    format!("http://{}", addr)
+}
@@ -0,0 +1,417 @@
+use crate::fetch::{
+    infer_status, is_index, retain_date_range, AdjustMode, Fetcher, HttpClient, HttpOpts, Quot,
+    RtQuot, RtQuotBatch, StockBar, StockBarList,
+};
+use crate::{Money, Price};
+use anyhow::{anyhow, bail, Context, Result};
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use log::warn;
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const QUOT_URL: &str = "http://qt.gtimg.cn";
+const KLINE_URL: &str = "http://ifzq.gtimg.cn";
+// 分钟k线最大条数
+const MINUTE_COUNT: u32 = 800;
+// 日k线最大条数
+const DAY_COUNT: u32 = 2000;
+// 实时行情最少字段数
+const QUOT_FIELDS: usize = 36;
+// 成交量单位: 手
+const VOLUME_UNIT: u32 = 100;
+
+/// 腾讯行情参数
+#[derive(Debug, Clone)]
+pub struct TencentOpts {
+    pub quot_url: String,
+    pub kline_url: String,
+    // 实时/录制/回放
+    pub http: HttpOpts,
+}
+
+impl Default for TencentOpts {
+    fn default() -> Self {
+        Self {
+            quot_url: QUOT_URL.to_string(),
+            kline_url: KLINE_URL.to_string(),
+            http: Default::default(),
+        }
+    }
+}
+
+/// 腾讯行情, 实时行情取自 `qt.gtimg.cn`, k线取自 `ifzq.gtimg.cn`
+#[derive(Debug, Clone)]
+pub struct Tencent {
+    quot_url: String,
+    kline_url: String,
+    http: HttpClient,
+}
+
+impl Default for Tencent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tencent {
+    pub fn new() -> Self {
+        Self::with_opts(TencentOpts::default())
+    }
+
+    pub fn with_opts(opts: TencentOpts) -> Self {
+        let mut h = HeaderMap::new();
+        h.insert("user-agent",
+                 "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_12_6) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/73.0.3683.86 Safari/537.36".parse().unwrap());
+        h.insert("Referer", "http://gu.qq.com/".parse().unwrap());
+
+        Self {
+            quot_url: opts.quot_url.trim_end_matches('/').to_string(),
+            kline_url: opts.kline_url.trim_end_matches('/').to_string(),
+            http: HttpClient::new(h, opts.http),
+        }
+    }
+
+    async fn get(&self, url: String) -> Result<String> {
+        let resp = self
+            .http
+            .get(&url)
+            .await
+            .with_context(|| "Send tencent http request error!")?;
+        if !resp.is_success() {
+            bail!("Tencent http status error: {}!", resp.status);
+        }
+        Ok(resp.body)
+    }
+
+    /// 解析实时行情, 格式: `v_sh600063="1~名称~600063~现价~昨收~今开~成交量(手)~...";`
+    ///
+    /// 无效代码返回 `v_pv_none_match="1";`, 忽略; 字段不足或解析失败的代码记为错误
+    pub fn parse_rt_quot(data: &str) -> Result<RtQuotBatch> {
+        let mut batch = RtQuotBatch::default();
+        for item in data.split(';') {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            let (key, value) = item
+                .split_once('=')
+                .with_context(|| format!("Tencent response data error: {}!", item))?;
+            let code = key.trim().trim_start_matches("v_");
+            if code == "pv_none_match" {
+                continue;
+            }
+            let fields: Vec<&str> = value.trim_matches('"').split('~').collect();
+            if fields.len() < QUOT_FIELDS {
+                batch.errors.insert(
+                    code.to_string(),
+                    format!("Tencent quot fields too short: {}!", fields.len()),
+                );
+                continue;
+            }
+            match Self::parse_quot(code, &fields) {
+                Ok(q) => {
+                    batch.quot.insert(code.to_string(), q);
+                }
+                Err(e) => {
+                    batch.errors.insert(code.to_string(), format!("{:#}", e));
+                }
+            }
+        }
+        Ok(batch)
+    }
+
+    fn parse_quot(code: &str, fields: &[&str]) -> Result<Quot> {
+        let time = NaiveDateTime::parse_from_str(fields[30], "%Y%m%d%H%M%S")
+            .with_context(|| format!("Parse naive_date_time error: {}!", fields[30]))?;
+        let amount = fields[35]
+            .split('/')
+            .nth(2)
+            .with_context(|| format!("Parse amount error: {}!", fields[35]))?;
+
+        let level = |index: usize| -> Result<(u32, Price)> {
+            let price = parse(fields[index], "price level")?;
+            let volume: u32 = parse(fields[index + 1], "volume level")?;
+            Ok((volume * VOLUME_UNIT, price))
+        };
+
+        let mut q = Quot {
+            code: code.to_string(),
+            name: fields[1].to_string(),
+            open: parse(fields[5], "open")?,
+            pre_close: parse(fields[4], "pre_close")?,
+            now: parse(fields[3], "now")?,
+            high: parse(fields[33], "high")?,
+            low: parse(fields[34], "low")?,
+            buy: parse(fields[9], "buy")?,
+            sell: parse(fields[19], "sell")?,
+            vol: parse::<u64>(fields[6], "vol")? * VOLUME_UNIT as u64,
+            amount: parse::<Money>(amount, "amount")?,
+            bid: (level(9)?, level(11)?, level(13)?, level(15)?, level(17)?),
+            ask: (level(19)?, level(21)?, level(23)?, level(25)?, level(27)?),
+            date: time.date(),
+            time,
+            ..Default::default()
+        };
+        q.status = infer_status(&q);
+        Ok(q)
+    }
+
+    /// 解析k线, 格式: `{"code":0,"data":{"sh600063":{"m5":[["时间","开","收","高","低","成交量(手)"],...]}}}`
+    ///
+    /// 日k线时间为收盘时间 15:00:00, 复权k线的 `key` 为 `qfqday`/`hfqday`,
+    /// 指数无复权数据, 返回 `day`; 股票缺少复权数据时返回错误, 避免将不复权数据当作复权数据
+    pub fn parse_kline(code: &str, key: &str, data: &str) -> Result<StockBarList> {
+        let js: Value = serde_json::from_str(data).with_context(|| "Parse tencent kline error!")?;
+        if js["code"].as_i64() != Some(0) {
+            bail!("tencent kline error: {}", &js["msg"]);
+        }
+        let kline = &js["data"][code];
+        let rows = kline[key]
+            .as_array()
+            .or_else(|| {
+                (key.ends_with("day") && is_index(code))
+                    .then(|| kline["day"].as_array())
+                    .flatten()
+            })
+            .with_context(|| format!("tencent kline data error, code={}, key={}", code, key))?;
+
+        let mut bars = StockBarList::new();
+        for row in rows {
+            let col = |index: usize| -> Result<&str> {
+                row[index]
+                    .as_str()
+                    .with_context(|| format!("tencent kline row error: {}", row))
+            };
+            let time = col(0)?;
+            let time = if time.len() == 12 {
+                NaiveDateTime::parse_from_str(time, "%Y%m%d%H%M")
+                    .with_context(|| format!("Parse naive_date_time error: {}!", time))?
+            } else {
+                NaiveDate::parse_from_str(time, "%Y-%m-%d")
+                    .with_context(|| format!("Parse naive_date error: {}!", time))?
+                    .and_time(close_time())
+            };
+            let vol: f64 = parse(col(5)?, "volume")?;
+            bars.push(StockBar {
+                time,
+                open: parse(col(1)?, "open")?,
+                close: parse(col(2)?, "close")?,
+                high: parse(col(3)?, "high")?,
+                low: parse(col(4)?, "low")?,
+                vol: (vol * VOLUME_UNIT as f64).round() as u64,
+            });
+        }
+        Ok(bars)
+    }
+}
+
+fn parse<T>(s: &str, name: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.parse()
+        .map_err(|e| anyhow!("Parse {} error: {}, {}!", name, s, e))
+}
+
+fn close_time() -> NaiveTime {
+    NaiveTime::from_hms_opt(15, 0, 0).unwrap()
+}
+
+#[async_trait]
+impl Fetcher for Tencent {
+    /// `min` 支持 1/5/15/30/60 分钟, 不小于 240 时取日k线(不复权)
+    async fn fetch_stock_minute(&self, code: &str, min: u32) -> Result<StockBarList> {
+        let url = match min {
+            1 | 5 | 15 | 30 | 60 => format!(
+                "{}/appstock/app/kline/mkline?param={},m{},,{}",
+                &self.kline_url, code, min, MINUTE_COUNT
+            ),
+            m if m >= 240 => return self.fetch_daily(code, None, None, AdjustMode::None).await,
+            _ => bail!("tencent kline not support minute: {}", min),
+        };
+        let data = self.get(url).await?;
+        Self::parse_kline(code, &format!("m{}", min), &data)
+    }
+
+    /// 最多取最近 2000 条
+    async fn fetch_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+        adjust: AdjustMode,
+    ) -> Result<StockBarList> {
+        let fq = match adjust {
+            AdjustMode::None => "",
+            AdjustMode::Qfq => "qfq",
+            AdjustMode::Hfq => "hfq",
+        };
+        let date = |d: Option<NaiveDate>| d.map(|d| d.format("%Y-%m-%d").to_string());
+        let url = format!(
+            "{}/appstock/app/fqkline/get?param={},day,{},{},{},{}",
+            &self.kline_url,
+            code,
+            date(start).unwrap_or_default(),
+            date(end).unwrap_or_default(),
+            DAY_COUNT,
+            fq
+        );
+        let data = self.get(url).await?;
+        let mut bars = Self::parse_kline(code, &format!("{}day", fq), &data)?;
+        retain_date_range(&mut bars, start, end);
+        Ok(bars)
+    }
+
+    async fn fetch_index_daily(
+        &self,
+        code: &str,
+        start: Option<NaiveDate>,
+        end: Option<NaiveDate>,
+    ) -> Result<StockBarList> {
+        self.fetch_daily(code, start, end, AdjustMode::None).await
+    }
+
+    async fn fetch_rt_quot(&self, codes: &Vec<String>) -> Result<RtQuot> {
+        let batch = self.fetch_rt_quot_batch(codes).await;
+        for (code, e) in batch.errors.iter() {
+            warn!("tencent fetch {} failed: {}", code, e);
+        }
+        batch.into_result()
+    }
+
+    async fn fetch_rt_quot_batch(&self, codes: &Vec<String>) -> RtQuotBatch {
+        let url = format!("{}/q={}", &self.quot_url, codes.join(","));
+        let rs = self
+            .get(url)
+            .await
+            .and_then(|data| Self::parse_rt_quot(&data));
+        let mut batch = RtQuotBatch::default();
+        batch.merge_batch(codes, rs);
+        batch
+    }
+}
+
+#[cfg(test)]
+mod test_tencent {
+    use super::{Tencent, TencentOpts};
+    use crate::fetch::{stub, AdjustMode, Fetcher};
+    use crate::{Money, Price};
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_parse_rt_quot() {
+        // 字段不足及解析失败的代码记为错误, 不影响其他代码
+        let data = include_str!("fixture/tencent_quot.txt").to_string()
+            + "v_sh600000=\"1~浦发银行~600000\";\n"
+            + &include_str!("fixture/tencent_quot.txt")
+                .lines()
+                .next()
+                .unwrap()
+                .replace("v_sh600063", "v_sh600001")
+                .replace("20220301150003", "bad_time");
+        let batch = Tencent::parse_rt_quot(&data).unwrap();
+        assert_eq!(batch.quot.len(), 2);
+        assert!(batch.quot.contains_key("sh600063"));
+        assert!(batch.errors.get("sh600000").unwrap().contains("too short"));
+        assert!(batch.errors.contains_key("sh600001"));
+        assert!(!batch.errors.contains_key("pv_none_match"));
+    }
+
+    #[test]
+    fn test_parse_kline() {
+        // 指数无复权数据时返回 day
+        let data = include_str!("fixture/tencent_day.json").replace("sh600063", "sh000001");
+        let bars = Tencent::parse_kline("sh000001", "qfqday", &data).unwrap();
+        assert_eq!(bars.len(), 2);
+
+        let data = include_str!("fixture/tencent_day.json");
+        assert!(Tencent::parse_kline("sh600063", "qfqday", data).is_err());
+        assert_eq!(
+            Tencent::parse_kline("sh600063", "day", data).unwrap().len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_tencent() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let url = stub::serve(vec![
+                ("/q=", include_str!("fixture/tencent_quot.txt")),
+                (
+                    "/appstock/app/kline/mkline",
+                    include_str!("fixture/tencent_minute.json"),
+                ),
+                (
+                    "/appstock/app/fqkline/get?param=sh600063,day,2022-03-01,,2000,qfq",
+                    include_str!("fixture/tencent_qfq.json"),
+                ),
+                (
+                    "/appstock/app/fqkline/get",
+                    include_str!("fixture/tencent_day.json"),
+                ),
+            ])
+            .await;
+            let tencent = Tencent::with_opts(TencentOpts {
+                quot_url: url.clone(),
+                kline_url: url,
+                ..Default::default()
+            });
+
+            let codes = vec!["sh600063".to_string(), "sz000001".to_string()];
+            let rs = tencent.fetch_rt_quot(&codes).await.unwrap();
+            assert_eq!(rs.len(), 2);
+            let q = rs.get("sh600063").unwrap();
+            assert_eq!(q.name, "皖维高新");
+            assert_eq!(q.now, Price::from(5.63));
+            assert_eq!(q.pre_close, Price::from(5.6));
+            assert_eq!(q.high, Price::from(5.7));
+            assert_eq!(q.vol, 23578900);
+            assert_eq!(q.amount, Money::from(132456789.0));
+            assert_eq!(q.bid.0, (150300, Price::from(5.62)));
+            assert_eq!(q.ask.4, (90000, Price::from(5.67)));
+            assert_eq!(q.time.to_string(), "2022-03-01 15:00:03");
+
+            let bars = tencent.fetch_stock_minute("sh600063", 5).await.unwrap();
+            assert_eq!(bars.len(), 2);
+            assert_eq!(bars[0].time.to_string(), "2022-03-01 10:00:00");
+            assert_eq!(bars[0].close, Price::from(5.61));
+            assert_eq!(bars[0].vol, 123400);
+
+            let bars = tencent.fetch_stock_minute("sh600063", 1440).await.unwrap();
+            assert_eq!(bars[1].time.to_string(), "2022-03-02 15:00:00");
+            assert_eq!(bars[1].low, Price::from(5.52));
+
+            // 复权k线, 过滤区间外的数据
+            let start = NaiveDate::from_ymd_opt(2022, 3, 1);
+            let bars = tencent
+                .fetch_daily("sh600063", start, None, AdjustMode::Qfq)
+                .await
+                .unwrap();
+            assert_eq!(bars.len(), 2);
+            assert_eq!(bars[0].time.to_string(), "2022-03-01 15:00:00");
+            assert_eq!(bars[1].close, Price::from(5.58));
+
+            // 股票无复权数据时返回错误
+            assert!(tencent
+                .fetch_daily("sh600063", None, start, AdjustMode::Hfq)
+                .await
+                .is_err());
+            let bars = tencent
+                .fetch_index_daily("sh600063", None, None)
+                .await
+                .unwrap();
+            assert_eq!(bars.len(), 2);
+
+            assert!(tencent.fetch_stock_minute("sh600063", 3).await.is_err());
+            assert!(tencent.fetch_stock_minute("sh600000", 5).await.is_err());
+        });
+    }
+}
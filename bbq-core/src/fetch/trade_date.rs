@@ -1,81 +0,0 @@
-use chrono::{Local, NaiveDate, Datelike};
-use lazy_static::lazy_static;
-use std::collections::BTreeSet;
-use std::sync::RwLock;
-
-lazy_static! {
-    static ref TRADE_DAYS: RwLock<BTreeSet<i32>> = {
-        let mut set = BTreeSet::new();
-        set.insert(19901219);set.insert(19901220);set.insert(19901221);set.insert(19901224);set.insert(19901225);set.insert(19901226);set.insert(19901227);set.insert(19901228);set.insert(19901231);set.insert(19910102);set.insert(19910103);set.insert(19910104);set.insert(19910107);set.insert(19910108);set.insert(19910109);set.insert(19910110);set.insert(19910111);set.insert(19910114);set.insert(19910115);set.insert(19910116);set.insert(19910117);set.insert(19910118);set.insert(19910121);set.insert(19910122);set.insert(19910123);set.insert(19910124);set.insert(19910125);set.insert(19910128);set.insert(19910129);set.insert(19910130);set.insert(19910131);set.insert(19910201);set.insert(19910204);set.insert(19910205);set.insert(19910206);set.insert(19910207);set.insert(19910208);set.insert(19910211);set.insert(19910212);set.insert(19910213);set.insert(19910214);set.insert(19910219);set.insert(19910220);set.insert(19910221);set.insert(19910222);set.insert(19910225);set.insert(19910226);set.insert(19910227);set.insert(19910228);set.insert(19910301);set.insert(19910304);set.insert(19910305);set.insert(19910306);set.insert(19910307);set.insert(19910308);set.insert(19910311);set.insert(19910312);set.insert(19910313);set.insert(19910314);set.insert(19910315);set.insert(19910318);set.insert(19910319);set.insert(19910320);set.insert(19910321);set.insert(19910322);set.insert(19910325);set.insert(19910326);set.insert(19910327);set.insert(19910328);set.insert(19910329);set.insert(19910401);set.insert(19910402);set.insert(19910403);set.insert(19910404);set.insert(19910405);set.insert(19910408);set.insert(19910409);set.insert(19910410);set.insert(19910411);set.insert(19910412);set.insert(19910415);set.insert(19910416);set.insert(19910417);set.insert(19910418);set.insert(19910419);set.insert(19910422);set.insert(19910423);set.insert(19910424);set.insert(19910425);set.insert(19910426);set.insert(19910429);set.insert(19910430);set.insert(19910502);set.insert(19910503);set.insert(19910506);set.insert(19910507);set.insert(19910508);set.insert(19910509);set.insert(19910510);set.insert(19910513);set.insert(19910514);set.insert(19910515);set.insert(19910516);set.insert(19910517);set.insert(19910520);set.insert(19910521);set.insert(19910522);set.insert(19910523);set.insert(19910524);set.insert(19910527);set.insert(19910528);set.insert(19910529);set.insert(19910530);set.insert(19910531);set.insert(19910603);set.insert(19910604);set.insert(19910605);set.insert(19910606);set.insert(19910607);set.insert(19910610);set.insert(19910611);set.insert(19910612);set.insert(19910613);set.insert(19910614);set.insert(19910617);set.insert(19910618);set.insert(19910619);set.insert(19910620);set.insert(19910621);set.insert(19910624);set.insert(19910625);set.insert(19910626);set.insert(19910627);set.insert(19910628);set.insert(19910701);set.insert(19910702);set.insert(19910703);set.insert(19910704);set.insert(19910705);set.insert(19910708);set.insert(19910709);set.insert(19910710);set.insert(19910711);set.insert(19910712);set.insert(19910715);set.insert(19910716);set.insert(19910717);set.insert(19910718);set.insert(19910719);set.insert(19910722);set.insert(19910723);set.insert(19910724);set.insert(19910725);set.insert(19910726);set.insert(19910729);set.insert(19910730);set.insert(19910731);set.insert(19910801);set.insert(19910802);set.insert(19910805);set.insert(19910806);set.insert(19910807);set.insert(19910808);set.insert(19910809);set.insert(19910812);set.insert(19910813);set.insert(19910814);set.insert(19910815);set.insert(19910816);set.insert(19910819);set.insert(19910820);set.insert(19910821);set.insert(19910822);set.insert(19910823);set.insert(19910826);set.insert(19910827);set.insert(19910828);set.insert(19910829);set.insert(19910830);set.insert(19910902);set.insert(19910903);set.insert(19910904);set.insert(19910905);set.insert(19910906);set.insert(19910909);set.insert(19910910);set.insert(19910911);set.insert(19910912);set.insert(19910913);set.insert(19910916);set.insert(19910917);set.insert(19910918);set.insert(19910919);set.insert(19910920);set.insert(19910923);set.insert(19910924);set.insert(19910925);set.insert(19910926);set.insert(19910927);set.insert(19910930);set.insert(19911003);set.insert(19911004);set.insert(19911007);set.insert(19911008);set.insert(19911009);set.insert(19911010);set.insert(19911011);set.insert(19911014);set.insert(19911015);set.insert(19911016);set.insert(19911017);set.insert(19911018);set.insert(19911021);set.insert(19911022);set.insert(19911023);set.insert(19911024);set.insert(19911025);set.insert(19911028);set.insert(19911029);set.insert(19911030);set.insert(19911031);set.insert(19911101);set.insert(19911104);set.insert(19911105);set.insert(19911106);set.insert(19911107);set.insert(19911108);set.insert(19911111);set.insert(19911112);set.insert(19911113);set.insert(19911114);set.insert(19911115);set.insert(19911118);set.insert(19911119);set.insert(19911120);set.insert(19911121);set.insert(19911122);set.insert(19911125);set.insert(19911126);set.insert(19911127);set.insert(19911128);set.insert(19911129);set.insert(19911202);set.insert(19911203);set.insert(19911204);set.insert(19911205);set.insert(19911206);set.insert(19911209);set.insert(19911210);set.insert(19911211);set.insert(19911212);set.insert(19911213);set.insert(19911216);set.insert(19911217);set.insert(19911218);set.insert(19911219);set.insert(19911220);set.insert(19911223);set.insert(19911224);set.insert(19911225);set.insert(19911226);set.insert(19911227);set.insert(19911230);set.insert(19911231);set.insert(19920102);set.insert(19920103);set.insert(19920106);set.insert(19920107);set.insert(19920108);set.insert(19920109);set.insert(19920110);set.insert(19920113);set.insert(19920114);set.insert(19920115);set.insert(19920116);set.insert(19920117);set.insert(19920120);set.insert(19920121);set.insert(19920122);set.insert(19920123);set.insert(19920124);set.insert(19920127);set.insert(19920128);set.insert(19920129);set.insert(19920130);set.insert(19920131);set.insert(19920203);set.insert(19920207);set.insert(19920210);set.insert(19920211);set.insert(19920212);set.insert(19920213);set.insert(19920214);set.insert(19920217);set.insert(19920218);set.insert(19920219);set.insert(19920220);set.insert(19920221);set.insert(19920224);set.insert(19920225);set.insert(19920226);set.insert(19920227);set.insert(19920228);set.insert(19920302);set.insert(19920303);set.insert(19920304);set.insert(19920305);set.insert(19920306);set.insert(19920309);set.insert(19920310);set.insert(19920311);set.insert(19920312);set.insert(19920313);set.insert(19920316);set.insert(19920317);set.insert(19920318);set.insert(19920319);set.insert(19920320);set.insert(19920323);set.insert(19920324);set.insert(19920325);set.insert(19920326);set.insert(19920327);set.insert(19920330);set.insert(19920331);set.insert(19920401);set.insert(19920402);set.insert(19920403);set.insert(19920406);set.insert(19920407);set.insert(19920408);set.insert(19920409);set.insert(19920410);set.insert(19920413);set.insert(19920414);set.insert(19920415);set.insert(19920416);set.insert(19920417);set.insert(19920420);set.insert(19920421);set.insert(19920422);set.insert(19920423);set.insert(19920424);set.insert(19920427);set.insert(19920428);set.insert(19920429);set.insert(19920430);set.insert(19920504);set.insert(19920505);set.insert(19920506);set.insert(19920507);set.insert(19920508);set.insert(19920511);set.insert(19920512);set.insert(19920513);set.insert(19920514);set.insert(19920515);set.insert(19920518);set.insert(19920519);set.insert(19920520);set.insert(19920521);set.insert(19920522);set.insert(19920525);set.insert(19920526);set.insert(19920527);set.insert(19920528);set.insert(19920529);set.insert(19920601);set.insert(19920602);set.insert(19920603);set.insert(19920604);set.insert(19920605);set.insert(19920608);set.insert(19920609);set.insert(19920610);set.insert(19920611);set.insert(19920612);set.insert(19920615);set.insert(19920616);set.insert(19920617);set.insert(19920618);set.insert(19920619);set.insert(19920622);set.insert(19920623);set.insert(19920624);set.insert(19920625);set.insert(19920626);set.insert(19920629);set.insert(19920630);set.insert(19920701);set.insert(19920702);set.insert(19920703);set.insert(19920706);set.insert(19920707);set.insert(19920708);set.insert(19920709);set.insert(19920710);set.insert(19920713);set.insert(19920714);set.insert(19920715);set.insert(19920716);set.insert(19920717);set.insert(19920720);set.insert(19920721);set.insert(19920722);set.insert(19920723);set.insert(19920724);set.insert(19920727);set.insert(19920728);set.insert(19920729);set.insert(19920730);set.insert(19920731);set.insert(19920803);set.insert(19920804);set.insert(19920805);set.insert(19920806);set.insert(19920807);set.insert(19920810);set.insert(19920811);set.insert(19920812);set.insert(19920813);set.insert(19920814);set.insert(19920817);set.insert(19920818);set.insert(19920819);set.insert(19920820);set.insert(19920821);set.insert(19920824);set.insert(19920825);set.insert(19920826);set.insert(19920827);set.insert(19920828);set.insert(19920831);set.insert(19920901);set.insert(19920902);set.insert(19920903);set.insert(19920904);set.insert(19920907);set.insert(19920908);set.insert(19920909);set.insert(19920910);set.insert(19920911);set.insert(19920914);set.insert(19920915);set.insert(19920916);set.insert(19920917);set.insert(19920918);set.insert(19920921);set.insert(19920922);set.insert(19920923);set.insert(19920924);set.insert(19920925);set.insert(19920928);set.insert(19920929);set.insert(19920930);set.insert(19921005);set.insert(19921006);set.insert(19921007);set.insert(19921008);set.insert(19921009);set.insert(19921012);set.insert(19921013);set.insert(19921014);set.insert(19921015);set.insert(19921016);set.insert(19921019);set.insert(19921020);set.insert(19921021);set.insert(19921022);set.insert(19921023);set.insert(19921026);set.insert(19921027);set.insert(19921028);set.insert(19921029);set.insert(19921030);set.insert(19921102);set.insert(19921103);set.insert(19921104);set.insert(19921105);set.insert(19921106);set.insert(19921109);set.insert(19921110);set.insert(19921111);set.insert(19921112);set.insert(19921113);set.insert(19921116);set.insert(19921117);set.insert(19921118);set.insert(19921119);set.insert(19921120);set.insert(19921123);set.insert(19921124);set.insert(19921125);set.insert(19921126);set.insert(19921127);set.insert(19921130);set.insert(19921201);set.insert(19921202);set.insert(19921203);set.insert(19921204);set.insert(19921207);set.insert(19921208);set.insert(19921209);set.insert(19921210);set.insert(19921211);set.insert(19921214);set.insert(19921215);set.insert(19921216);set.insert(19921217);set.insert(19921218);set.insert(19921221);set.insert(19921222);set.insert(19921223);set.insert(19921224);set.insert(19921225);set.insert(19921228);set.insert(19921229);set.insert(19921230);set.insert(19921231);set.insert(19930104);set.insert(19930105);set.insert(19930106);set.insert(19930107);set.insert(19930108);set.insert(19930111);set.insert(19930112);set.insert(19930113);set.insert(19930114);set.insert(19930115);set.insert(19930118);set.insert(19930119);set.insert(19930120);set.insert(19930121);set.insert(19930122);set.insert(19930127);set.insert(19930128);set.insert(19930129);set.insert(19930201);set.insert(19930202);set.insert(19930203);set.insert(19930204);set.insert(19930205);set.insert(19930208);set.insert(19930209);set.insert(19930210);set.insert(19930211);set.insert(19930212);set.insert(19930215);set.insert(19930216);set.insert(19930217);set.insert(19930218);set.insert(19930219);set.insert(19930222);set.insert(19930223);set.insert(19930224);set.insert(19930225);set.insert(19930226);set.insert(19930301);set.insert(19930302);set.insert(19930303);set.insert(19930304);set.insert(19930305);set.insert(19930308);set.insert(19930309);set.insert(19930310);set.insert(19930311);set.insert(19930312);set.insert(19930315);set.insert(19930316);set.insert(19930317);set.insert(19930318);set.insert(19930319);set.insert(19930322);set.insert(19930323);set.insert(19930324);set.insert(19930325);set.insert(19930326);set.insert(19930329);set.insert(19930330);set.insert(19930331);set.insert(19930401);set.insert(19930402);set.insert(19930405);set.insert(19930406);set.insert(19930407);set.insert(19930408);set.insert(19930409);set.insert(19930412);set.insert(19930413);set.insert(19930414);set.insert(19930415);set.insert(19930416);set.insert(19930419);set.insert(19930420);set.insert(19930421);set.insert(19930422);set.insert(19930423);set.insert(19930426);set.insert(19930427);set.insert(19930428);set.insert(19930429);set.insert(19930430);set.insert(19930503);set.insert(19930504);set.insert(19930505);set.insert(19930506);set.insert(19930507);set.insert(19930510);set.insert(19930511);set.insert(19930512);set.insert(19930513);set.insert(19930514);set.insert(19930517);set.insert(19930518);set.insert(19930519);set.insert(19930520);set.insert(19930521);set.insert(19930524);set.insert(19930525);set.insert(19930526);set.insert(19930527);set.insert(19930528);set.insert(19930531);set.insert(19930601);set.insert(19930602);set.insert(19930603);set.insert(19930604);set.insert(19930607);set.insert(19930608);set.insert(19930609);set.insert(19930610);set.insert(19930611);set.insert(19930614);set.insert(19930615);set.insert(19930616);set.insert(19930617);set.insert(19930618);set.insert(19930621);set.insert(19930622);set.insert(19930623);set.insert(19930624);set.insert(19930625);set.insert(19930628);set.insert(19930629);set.insert(19930630);set.insert(19930701);set.insert(19930702);set.insert(19930705);set.insert(19930706);set.insert(19930707);set.insert(19930708);set.insert(19930709);set.insert(19930712);set.insert(19930713);set.insert(19930714);set.insert(19930715);set.insert(19930716);set.insert(19930719);set.insert(19930720);set.insert(19930721);set.insert(19930722);set.insert(19930723);set.insert(19930726);set.insert(19930727);set.insert(19930728);set.insert(19930729);set.insert(19930730);set.insert(19930802);set.insert(19930803);set.insert(19930804);set.insert(19930805);set.insert(19930806);set.insert(19930809);set.insert(19930810);set.insert(19930811);set.insert(19930812);set.insert(19930813);set.insert(19930816);set.insert(19930817);set.insert(19930818);set.insert(19930819);set.insert(19930820);set.insert(19930823);set.insert(19930824);set.insert(19930825);set.insert(19930826);set.insert(19930827);set.insert(19930830);set.insert(19930831);set.insert(19930901);set.insert(19930902);set.insert(19930903);set.insert(19930906);set.insert(19930907);set.insert(19930908);set.insert(19930909);set.insert(19930910);set.insert(19930913);set.insert(19930914);set.insert(19930915);set.insert(19930916);set.insert(19930917);set.insert(19930920);set.insert(19930921);set.insert(19930922);set.insert(19930923);set.insert(19930924);set.insert(19930927);set.insert(19930928);set.insert(19930929);set.insert(19930930);set.insert(19931004);set.insert(19931005);set.insert(19931006);set.insert(19931007);set.insert(19931008);set.insert(19931011);set.insert(19931012);set.insert(19931013);set.insert(19931014);set.insert(19931015);set.insert(19931018);set.insert(19931019);set.insert(19931020);set.insert(19931021);set.insert(19931022);set.insert(19931025);set.insert(19931026);set.insert(19931027);set.insert(19931028);set.insert(19931029);set.insert(19931101);set.insert(19931102);set.insert(19931103);set.insert(19931104);set.insert(19931105);set.insert(19931108);set.insert(19931109);set.insert(19931110);set.insert(19931111);set.insert(19931112);set.insert(19931115);set.insert(19931116);set.insert(19931117);set.insert(19931118);set.insert(19931119);set.insert(19931122);set.insert(19931123);set.insert(19931124);set.insert(19931125);set.insert(19931126);set.insert(19931129);set.insert(19931130);set.insert(19931201);set.insert(19931202);set.insert(19931203);set.insert(19931206);set.insert(19931207);set.insert(19931208);set.insert(19931209);set.insert(19931210);set.insert(19931213);set.insert(19931214);set.insert(19931215);set.insert(19931216);set.insert(19931217);set.insert(19931220);set.insert(19931221);set.insert(19931222);set.insert(19931223);set.insert(19931224);set.insert(19931227);set.insert(19931228);set.insert(19931229);set.insert(19931230);set.insert(19931231);set.insert(19940103);set.insert(19940104);set.insert(19940105);set.insert(19940106);set.insert(19940107);set.insert(19940110);set.insert(19940111);set.insert(19940112);set.insert(19940113);set.insert(19940114);set.insert(19940117);set.insert(19940118);set.insert(19940119);set.insert(19940120);set.insert(19940121);set.insert(19940124);set.insert(19940125);set.insert(19940126);set.insert(19940127);set.insert(19940128);set.insert(19940131);set.insert(19940201);set.insert(19940202);set.insert(19940203);set.insert(19940204);set.insert(19940214);set.insert(19940215);set.insert(19940216);set.insert(19940217);set.insert(19940218);set.insert(19940221);set.insert(19940222);set.insert(19940223);set.insert(19940224);set.insert(19940225);set.insert(19940228);set.insert(19940301);set.insert(19940302);set.insert(19940303);set.insert(19940304);set.insert(19940307);set.insert(19940308);set.insert(19940309);set.insert(19940310);set.insert(19940311);set.insert(19940314);set.insert(19940315);set.insert(19940316);set.insert(19940317);set.insert(19940318);set.insert(19940321);set.insert(19940322);set.insert(19940323);set.insert(19940324);set.insert(19940325);set.insert(19940328);set.insert(19940329);set.insert(19940330);set.insert(19940331);set.insert(19940401);set.insert(19940404);set.insert(19940405);set.insert(19940406);set.insert(19940407);set.insert(19940408);set.insert(19940411);set.insert(19940412);set.insert(19940413);set.insert(19940414);set.insert(19940415);set.insert(19940418);set.insert(19940419);set.insert(19940420);set.insert(19940421);set.insert(19940422);set.insert(19940425);set.insert(19940426);set.insert(19940427);set.insert(19940428);set.insert(19940429);set.insert(19940503);set.insert(19940504);set.insert(19940505);set.insert(19940506);set.insert(19940509);set.insert(19940510);set.insert(19940511);set.insert(19940512);set.insert(19940513);set.insert(19940516);set.insert(19940517);set.insert(19940518);set.insert(19940519);set.insert(19940520);set.insert(19940523);set.insert(19940524);set.insert(19940525);set.insert(19940526);set.insert(19940527);set.insert(19940530);set.insert(19940531);set.insert(19940601);set.insert(19940602);set.insert(19940603);set.insert(19940606);set.insert(19940607);set.insert(19940608);set.insert(19940609);set.insert(19940610);set.insert(19940613);set.insert(19940614);set.insert(19940615);set.insert(19940616);set.insert(19940617);set.insert(19940620);set.insert(19940621);set.insert(19940622);set.insert(19940623);set.insert(19940624);set.insert(19940627);set.insert(19940628);set.insert(19940629);set.insert(19940630);set.insert(19940701);set.insert(19940704);set.insert(19940705);set.insert(19940706);set.insert(19940707);set.insert(19940708);set.insert(19940711);set.insert(19940712);set.insert(19940713);set.insert(19940714);set.insert(19940715);set.insert(19940718);set.insert(19940719);set.insert(19940720);set.insert(19940721);set.insert(19940722);set.insert(19940725);set.insert(19940726);set.insert(19940727);set.insert(19940728);set.insert(19940729);set.insert(19940801);set.insert(19940802);set.insert(19940803);set.insert(19940804);set.insert(19940805);set.insert(19940808);set.insert(19940809);set.insert(19940810);set.insert(19940811);set.insert(19940812);set.insert(19940815);set.insert(19940816);set.insert(19940817);set.insert(19940818);set.insert(19940819);set.insert(19940822);set.insert(19940823);set.insert(19940824);set.insert(19940825);set.insert(19940826);set.insert(19940829);set.insert(19940830);set.insert(19940831);set.insert(19940901);set.insert(19940902);set.insert(19940905);set.insert(19940906);set.insert(19940907);set.insert(19940908);set.insert(19940909);set.insert(19940912);set.insert(19940913);set.insert(19940914);set.insert(19940915);set.insert(19940916);set.insert(19940919);set.insert(19940920);set.insert(19940921);set.insert(19940922);set.insert(19940923);set.insert(19940926);set.insert(19940927);set.insert(19940928);set.insert(19940929);set.insert(19940930);set.insert(19941005);set.insert(19941006);set.insert(19941007);set.insert(19941010);set.insert(19941011);set.insert(19941012);set.insert(19941013);set.insert(19941014);set.insert(19941017);set.insert(19941018);set.insert(19941019);set.insert(19941020);set.insert(19941021);set.insert(19941024);set.insert(19941025);set.insert(19941026);set.insert(19941027);set.insert(19941028);set.insert(19941031);set.insert(19941101);set.insert(19941102);set.insert(19941103);set.insert(19941104);set.insert(19941107);set.insert(19941108);set.insert(19941109);set.insert(19941110);set.insert(19941111);set.insert(19941114);set.insert(19941115);set.insert(19941116);set.insert(19941117);set.insert(19941118);set.insert(19941121);set.insert(19941122);set.insert(19941123);set.insert(19941124);set.insert(19941125);set.insert(19941128);set.insert(19941129);set.insert(19941130);set.insert(19941201);set.insert(19941202);set.insert(19941205);set.insert(19941206);set.insert(19941207);set.insert(19941208);set.insert(19941209);set.insert(19941212);set.insert(19941213);set.insert(19941214);set.insert(19941215);set.insert(19941216);set.insert(19941219);set.insert(19941220);set.insert(19941221);set.insert(19941222);set.insert(19941223);set.insert(19941226);set.insert(19941227);set.insert(19941228);set.insert(19941229);set.insert(19941230);set.insert(19950103);set.insert(19950104);set.insert(19950105);set.insert(19950106);set.insert(19950109);set.insert(19950110);set.insert(19950111);set.insert(19950112);set.insert(19950113);set.insert(19950116);set.insert(19950117);set.insert(19950118);set.insert(19950119);set.insert(19950120);set.insert(19950123);set.insert(19950124);set.insert(19950125);set.insert(19950126);set.insert(19950127);set.insert(19950206);set.insert(19950207);set.insert(19950208);set.insert(19950209);set.insert(19950210);set.insert(19950213);set.insert(19950214);set.insert(19950215);set.insert(19950216);set.insert(19950217);set.insert(19950220);set.insert(19950221);set.insert(19950222);set.insert(19950223);set.insert(19950224);set.insert(19950227);set.insert(19950228);set.insert(19950301);set.insert(19950302);set.insert(19950303);set.insert(19950306);set.insert(19950307);set.insert(19950308);set.insert(19950309);set.insert(19950310);set.insert(19950313);set.insert(19950314);set.insert(19950315);set.insert(19950316);set.insert(19950317);set.insert(19950320);set.insert(19950321);set.insert(19950322);set.insert(19950323);set.insert(19950324);set.insert(19950327);set.insert(19950328);set.insert(19950329);set.insert(19950330);set.insert(19950331);set.insert(19950403);set.insert(19950404);set.insert(19950405);set.insert(19950406);set.insert(19950407);set.insert(19950410);set.insert(19950411);set.insert(19950412);set.insert(19950413);set.insert(19950414);set.insert(19950417);set.insert(19950418);set.insert(19950419);set.insert(19950420);set.insert(19950421);set.insert(19950424);set.insert(19950425);set.insert(19950426);set.insert(19950427);set.insert(19950428);set.insert(19950502);set.insert(19950503);set.insert(19950504);set.insert(19950505);set.insert(19950508);set.insert(19950509);set.insert(19950510);set.insert(19950511);set.insert(19950512);set.insert(19950515);set.insert(19950516);set.insert(19950517);set.insert(19950518);set.insert(19950519);set.insert(19950522);set.insert(19950523);set.insert(19950524);set.insert(19950525);set.insert(19950526);set.insert(19950529);set.insert(19950530);set.insert(19950531);set.insert(19950601);set.insert(19950602);set.insert(19950605);set.insert(19950606);set.insert(19950607);set.insert(19950608);set.insert(19950609);set.insert(19950612);set.insert(19950613);set.insert(19950614);set.insert(19950615);set.insert(19950616);set.insert(19950619);set.insert(19950620);set.insert(19950621);set.insert(19950622);set.insert(19950623);set.insert(19950626);set.insert(19950627);set.insert(19950628);set.insert(19950629);set.insert(19950630);set.insert(19950703);set.insert(19950704);set.insert(19950705);set.insert(19950706);set.insert(19950707);set.insert(19950710);set.insert(19950711);set.insert(19950712);set.insert(19950713);set.insert(19950714);set.insert(19950717);set.insert(19950718);set.insert(19950719);set.insert(19950720);set.insert(19950721);set.insert(19950724);set.insert(19950725);set.insert(19950726);set.insert(19950727);set.insert(19950728);set.insert(19950731);set.insert(19950801);set.insert(19950802);set.insert(19950803);set.insert(19950804);set.insert(19950807);set.insert(19950808);set.insert(19950809);set.insert(19950810);set.insert(19950811);set.insert(19950814);set.insert(19950815);set.insert(19950816);set.insert(19950817);set.insert(19950818);set.insert(19950821);set.insert(19950822);set.insert(19950823);set.insert(19950824);set.insert(19950825);set.insert(19950828);set.insert(19950829);set.insert(19950830);set.insert(19950831);set.insert(19950901);set.insert(19950904);set.insert(19950905);set.insert(19950906);set.insert(19950907);set.insert(19950908);set.insert(19950911);set.insert(19950912);set.insert(19950913);set.insert(19950914);set.insert(19950915);set.insert(19950918);set.insert(19950919);set.insert(19950920);set.insert(19950921);set.insert(19950922);set.insert(19950925);set.insert(19950926);set.insert(19950927);set.insert(19950928);set.insert(19950929);set.insert(19951004);set.insert(19951005);set.insert(19951006);set.insert(19951009);set.insert(19951010);set.insert(19951011);set.insert(19951012);set.insert(19951013);set.insert(19951016);set.insert(19951017);set.insert(19951018);set.insert(19951019);set.insert(19951020);set.insert(19951023);set.insert(19951024);set.insert(19951025);set.insert(19951026);set.insert(19951027);set.insert(19951030);set.insert(19951031);set.insert(19951101);set.insert(19951102);set.insert(19951103);set.insert(19951106);set.insert(19951107);set.insert(19951108);set.insert(19951109);set.insert(19951110);set.insert(19951113);set.insert(19951114);set.insert(19951115);set.insert(19951116);set.insert(19951117);set.insert(19951120);set.insert(19951121);set.insert(19951122);set.insert(19951123);set.insert(19951124);set.insert(19951127);set.insert(19951128);set.insert(19951129);set.insert(19951130);set.insert(19951201);set.insert(19951204);set.insert(19951205);set.insert(19951206);set.insert(19951207);set.insert(19951208);set.insert(19951211);set.insert(19951212);set.insert(19951213);set.insert(19951214);set.insert(19951215);set.insert(19951218);set.insert(19951219);set.insert(19951220);set.insert(19951221);set.insert(19951222);set.insert(19951225);set.insert(19951226);set.insert(19951227);set.insert(19951228);set.insert(19951229);set.insert(19960102);set.insert(19960103);set.insert(19960104);set.insert(19960105);set.insert(19960108);set.insert(19960109);set.insert(19960110);set.insert(19960111);set.insert(19960112);set.insert(19960115);set.insert(19960116);set.insert(19960117);set.insert(19960118);set.insert(19960119);set.insert(19960122);set.insert(19960123);set.insert(19960124);set.insert(19960125);set.insert(19960126);set.insert(19960129);set.insert(19960130);set.insert(19960131);set.insert(19960201);set.insert(19960202);set.insert(19960205);set.insert(19960206);set.insert(19960207);set.insert(19960208);set.insert(19960209);set.insert(19960212);set.insert(19960213);set.insert(19960214);set.insert(19960215);set.insert(19960216);set.insert(19960304);set.insert(19960305);set.insert(19960306);set.insert(19960307);set.insert(19960308);set.insert(19960311);set.insert(19960312);set.insert(19960313);set.insert(19960314);set.insert(19960315);set.insert(19960318);set.insert(19960319);set.insert(19960320);set.insert(19960321);set.insert(19960322);set.insert(19960325);set.insert(19960326);set.insert(19960327);set.insert(19960328);set.insert(19960329);set.insert(19960401);set.insert(19960402);set.insert(19960403);set.insert(19960404);set.insert(19960405);set.insert(19960408);set.insert(19960409);set.insert(19960410);set.insert(19960411);set.insert(19960412);set.insert(19960415);set.insert(19960416);set.insert(19960417);set.insert(19960418);set.insert(19960419);set.insert(19960422);set.insert(19960423);set.insert(19960424);set.insert(19960425);set.insert(19960426);set.insert(19960429);set.insert(19960430);set.insert(19960502);set.insert(19960503);set.insert(19960506);set.insert(19960507);set.insert(19960508);set.insert(19960509);set.insert(19960510);set.insert(19960513);set.insert(19960514);set.insert(19960515);set.insert(19960516);set.insert(19960517);set.insert(19960520);set.insert(19960521);set.insert(19960522);set.insert(19960523);set.insert(19960524);set.insert(19960527);set.insert(19960528);set.insert(19960529);set.insert(19960530);set.insert(19960531);set.insert(19960603);set.insert(19960604);set.insert(19960605);set.insert(19960606);set.insert(19960607);set.insert(19960610);set.insert(19960611);set.insert(19960612);set.insert(19960613);set.insert(19960614);set.insert(19960617);set.insert(19960618);set.insert(19960619);set.insert(19960620);set.insert(19960621);set.insert(19960624);set.insert(19960625);set.insert(19960626);set.insert(19960627);set.insert(19960628);set.insert(19960701);set.insert(19960702);set.insert(19960703);set.insert(19960704);set.insert(19960705);set.insert(19960708);set.insert(19960709);set.insert(19960710);set.insert(19960711);set.insert(19960712);set.insert(19960715);set.insert(19960716);set.insert(19960717);set.insert(19960718);set.insert(19960719);set.insert(19960722);set.insert(19960723);set.insert(19960724);set.insert(19960725);set.insert(19960726);set.insert(19960729);set.insert(19960730);set.insert(19960731);set.insert(19960801);set.insert(19960802);set.insert(19960805);set.insert(19960806);set.insert(19960807);set.insert(19960808);set.insert(19960809);set.insert(19960812);set.insert(19960813);set.insert(19960814);set.insert(19960815);set.insert(19960816);set.insert(19960819);set.insert(19960820);set.insert(19960821);set.insert(19960822);set.insert(19960823);set.insert(19960826);set.insert(19960827);set.insert(19960828);set.insert(19960829);set.insert(19960830);set.insert(19960902);set.insert(19960903);set.insert(19960904);set.insert(19960905);set.insert(19960906);set.insert(19960909);set.insert(19960910);set.insert(19960911);set.insert(19960912);set.insert(19960913);set.insert(19960916);set.insert(19960917);set.insert(19960918);set.insert(19960919);set.insert(19960920);set.insert(19960923);set.insert(19960924);set.insert(19960925);set.insert(19960926);set.insert(19960927);set.insert(19961003);set.insert(19961004);set.insert(19961007);set.insert(19961008);set.insert(19961009);set.insert(19961010);set.insert(19961011);set.insert(19961014);set.insert(19961015);set.insert(19961016);set.insert(19961017);set.insert(19961018);set.insert(19961021);set.insert(19961022);set.insert(19961023);set.insert(19961024);set.insert(19961025);set.insert(19961028);set.insert(19961029);set.insert(19961030);set.insert(19961031);set.insert(19961101);set.insert(19961104);set.insert(19961105);set.insert(19961106);set.insert(19961107);set.insert(19961108);set.insert(19961111);set.insert(19961112);set.insert(19961113);set.insert(19961114);set.insert(19961115);set.insert(19961118);set.insert(19961119);set.insert(19961120);set.insert(19961121);set.insert(19961122);set.insert(19961125);set.insert(19961126);set.insert(19961127);set.insert(19961128);set.insert(19961129);set.insert(19961202);set.insert(19961203);set.insert(19961204);set.insert(19961205);set.insert(19961206);set.insert(19961209);set.insert(19961210);set.insert(19961211);set.insert(19961212);set.insert(19961213);set.insert(19961216);set.insert(19961217);set.insert(19961218);set.insert(19961219);set.insert(19961220);set.insert(19961223);set.insert(19961224);set.insert(19961225);set.insert(19961226);set.insert(19961227);set.insert(19961230);set.insert(19961231);set.insert(19970102);set.insert(19970103);set.insert(19970106);set.insert(19970107);set.insert(19970108);set.insert(19970109);set.insert(19970110);set.insert(19970113);set.insert(19970114);set.insert(19970115);set.insert(19970116);set.insert(19970117);set.insert(19970120);set.insert(19970121);set.insert(19970122);set.insert(19970123);set.insert(19970124);set.insert(19970127);set.insert(19970128);set.insert(19970129);set.insert(19970130);set.insert(19970131);set.insert(19970217);set.insert(19970218);set.insert(19970219);set.insert(19970220);set.insert(19970221);set.insert(19970224);set.insert(19970225);set.insert(19970226);set.insert(19970227);set.insert(19970228);set.insert(19970303);set.insert(19970304);set.insert(19970305);set.insert(19970306);set.insert(19970307);set.insert(19970310);set.insert(19970311);set.insert(19970312);set.insert(19970313);set.insert(19970314);set.insert(19970317);set.insert(19970318);set.insert(19970319);set.insert(19970320);set.insert(19970321);set.insert(19970324);set.insert(19970325);set.insert(19970326);set.insert(19970327);set.insert(19970328);set.insert(19970331);set.insert(19970401);set.insert(19970402);set.insert(19970403);set.insert(19970404);set.insert(19970407);set.insert(19970408);set.insert(19970409);set.insert(19970410);set.insert(19970411);set.insert(19970414);set.insert(19970415);set.insert(19970416);set.insert(19970417);set.insert(19970418);set.insert(19970421);set.insert(19970422);set.insert(19970423);set.insert(19970424);set.insert(19970425);set.insert(19970428);set.insert(19970429);set.insert(19970430);set.insert(19970505);set.insert(19970506);set.insert(19970507);set.insert(19970508);set.insert(19970509);set.insert(19970512);set.insert(19970513);set.insert(19970514);set.insert(19970515);set.insert(19970516);set.insert(19970519);set.insert(19970520);set.insert(19970521);set.insert(19970522);set.insert(19970523);set.insert(19970526);set.insert(19970527);set.insert(19970528);set.insert(19970529);set.insert(19970530);set.insert(19970602);set.insert(19970603);set.insert(19970604);set.insert(19970605);set.insert(19970606);set.insert(19970609);set.insert(19970610);set.insert(19970611);set.insert(19970612);set.insert(19970613);set.insert(19970616);set.insert(19970617);set.insert(19970618);set.insert(19970619);set.insert(19970620);set.insert(19970623);set.insert(19970624);set.insert(19970625);set.insert(19970626);set.insert(19970627);set.insert(19970702);set.insert(19970703);set.insert(19970704);set.insert(19970707);set.insert(19970708);set.insert(19970709);set.insert(19970710);set.insert(19970711);set.insert(19970714);set.insert(19970715);set.insert(19970716);set.insert(19970717);set.insert(19970718);set.insert(19970721);set.insert(19970722);set.insert(19970723);set.insert(19970724);set.insert(19970725);set.insert(19970728);set.insert(19970729);set.insert(19970730);set.insert(19970731);set.insert(19970801);set.insert(19970804);set.insert(19970805);set.insert(19970806);set.insert(19970807);set.insert(19970808);set.insert(19970811);set.insert(19970812);set.insert(19970813);set.insert(19970814);set.insert(19970815);set.insert(19970818);set.insert(19970819);set.insert(19970820);set.insert(19970821);set.insert(19970822);set.insert(19970825);set.insert(19970826);set.insert(19970827);set.insert(19970828);set.insert(19970829);set.insert(19970901);set.insert(19970902);set.insert(19970903);set.insert(19970904);set.insert(19970905);set.insert(19970908);set.insert(19970909);set.insert(19970910);set.insert(19970911);set.insert(19970912);set.insert(19970915);set.insert(19970916);set.insert(19970917);set.insert(19970918);set.insert(19970919);set.insert(19970922);set.insert(19970923);set.insert(19970924);set.insert(19970925);set.insert(19970926);set.insert(19970929);set.insert(19970930);set.insert(19971006);set.insert(19971007);set.insert(19971008);set.insert(19971009);set.insert(19971010);set.insert(19971013);set.insert(19971014);set.insert(19971015);set.insert(19971016);set.insert(19971017);set.insert(19971020);set.insert(19971021);set.insert(19971022);set.insert(19971023);set.insert(19971024);set.insert(19971027);set.insert(19971028);set.insert(19971029);set.insert(19971030);set.insert(19971031);set.insert(19971103);set.insert(19971104);set.insert(19971105);set.insert(19971106);set.insert(19971107);set.insert(19971110);set.insert(19971111);set.insert(19971112);set.insert(19971113);set.insert(19971114);set.insert(19971117);set.insert(19971118);set.insert(19971119);set.insert(19971120);set.insert(19971121);set.insert(19971124);set.insert(19971125);set.insert(19971126);set.insert(19971127);set.insert(19971128);set.insert(19971201);set.insert(19971202);set.insert(19971203);set.insert(19971204);set.insert(19971205);set.insert(19971208);set.insert(19971209);set.insert(19971210);set.insert(19971211);set.insert(19971212);set.insert(19971215);set.insert(19971216);set.insert(19971217);set.insert(19971218);set.insert(19971219);set.insert(19971222);set.insert(19971223);set.insert(19971224);set.insert(19971225);set.insert(19971226);set.insert(19971229);set.insert(19971230);set.insert(19971231);set.insert(19980105);set.insert(19980106);set.insert(19980107);set.insert(19980108);set.insert(19980109);set.insert(19980112);set.insert(19980113);set.insert(19980114);set.insert(19980115);set.insert(19980116);set.insert(19980119);set.insert(19980120);set.insert(19980121);set.insert(19980122);set.insert(19980123);set.insert(19980209);set.insert(19980210);set.insert(19980211);set.insert(19980212);set.insert(19980213);set.insert(19980216);set.insert(19980217);set.insert(19980218);set.insert(19980219);set.insert(19980220);set.insert(19980223);set.insert(19980224);set.insert(19980225);set.insert(19980226);set.insert(19980227);set.insert(19980302);set.insert(19980303);set.insert(19980304);set.insert(19980305);set.insert(19980306);set.insert(19980309);set.insert(19980310);set.insert(19980311);set.insert(19980312);set.insert(19980313);set.insert(19980316);set.insert(19980317);set.insert(19980318);set.insert(19980319);set.insert(19980320);set.insert(19980323);set.insert(19980324);set.insert(19980325);set.insert(19980326);set.insert(19980327);set.insert(19980330);set.insert(19980331);set.insert(19980401);set.insert(19980402);set.insert(19980403);set.insert(19980406);set.insert(19980407);set.insert(19980408);set.insert(19980409);set.insert(19980410);set.insert(19980413);set.insert(19980414);set.insert(19980415);set.insert(19980416);set.insert(19980417);set.insert(19980420);set.insert(19980421);set.insert(19980422);set.insert(19980423);set.insert(19980424);set.insert(19980427);set.insert(19980428);set.insert(19980429);set.insert(19980430);set.insert(19980504);set.insert(19980505);set.insert(19980506);set.insert(19980507);set.insert(19980508);set.insert(19980511);set.insert(19980512);set.insert(19980513);set.insert(19980514);set.insert(19980515);set.insert(19980518);set.insert(19980519);set.insert(19980520);set.insert(19980521);set.insert(19980522);set.insert(19980525);set.insert(19980526);set.insert(19980527);set.insert(19980528);set.insert(19980529);set.insert(19980601);set.insert(19980602);set.insert(19980603);set.insert(19980604);set.insert(19980605);set.insert(19980608);set.insert(19980609);set.insert(19980610);set.insert(19980611);set.insert(19980612);set.insert(19980615);set.insert(19980616);set.insert(19980617);set.insert(19980618);set.insert(19980619);set.insert(19980622);set.insert(19980623);set.insert(19980624);set.insert(19980625);set.insert(19980626);set.insert(19980629);set.insert(19980630);set.insert(19980701);set.insert(19980702);set.insert(19980703);set.insert(19980706);set.insert(19980707);set.insert(19980708);set.insert(19980709);set.insert(19980710);set.insert(19980713);set.insert(19980714);set.insert(19980715);set.insert(19980716);set.insert(19980717);set.insert(19980720);set.insert(19980721);set.insert(19980722);set.insert(19980723);set.insert(19980724);set.insert(19980727);set.insert(19980728);set.insert(19980729);set.insert(19980730);set.insert(19980731);set.insert(19980803);set.insert(19980804);set.insert(19980805);set.insert(19980806);set.insert(19980807);set.insert(19980810);set.insert(19980811);set.insert(19980812);set.insert(19980813);set.insert(19980814);set.insert(19980817);set.insert(19980818);set.insert(19980819);set.insert(19980820);set.insert(19980821);set.insert(19980824);set.insert(19980825);set.insert(19980826);set.insert(19980827);set.insert(19980828);set.insert(19980831);set.insert(19980901);set.insert(19980902);set.insert(19980903);set.insert(19980904);set.insert(19980907);set.insert(19980908);set.insert(19980909);set.insert(19980910);set.insert(19980911);set.insert(19980914);set.insert(19980915);set.insert(19980916);set.insert(19980917);set.insert(19980918);set.insert(19980921);set.insert(19980922);set.insert(19980923);set.insert(19980924);set.insert(19980925);set.insert(19980928);set.insert(19980929);set.insert(19980930);set.insert(19981005);set.insert(19981006);set.insert(19981007);set.insert(19981008);set.insert(19981009);set.insert(19981012);set.insert(19981013);set.insert(19981014);set.insert(19981015);set.insert(19981016);set.insert(19981019);set.insert(19981020);set.insert(19981021);set.insert(19981022);set.insert(19981023);set.insert(19981026);set.insert(19981027);set.insert(19981028);set.insert(19981029);set.insert(19981030);set.insert(19981102);set.insert(19981103);set.insert(19981104);set.insert(19981105);set.insert(19981106);set.insert(19981109);set.insert(19981110);set.insert(19981111);set.insert(19981112);set.insert(19981113);set.insert(19981116);set.insert(19981117);set.insert(19981118);set.insert(19981119);set.insert(19981120);set.insert(19981123);set.insert(19981124);set.insert(19981125);set.insert(19981126);set.insert(19981127);set.insert(19981130);set.insert(19981201);set.insert(19981202);set.insert(19981203);set.insert(19981204);set.insert(19981207);set.insert(19981208);set.insert(19981209);set.insert(19981210);set.insert(19981211);set.insert(19981214);set.insert(19981215);set.insert(19981216);set.insert(19981217);set.insert(19981218);set.insert(19981221);set.insert(19981222);set.insert(19981223);set.insert(19981224);set.insert(19981225);set.insert(19981228);set.insert(19981229);set.insert(19981230);set.insert(19981231);set.insert(19990104);set.insert(19990105);set.insert(19990106);set.insert(19990107);set.insert(19990108);set.insert(19990111);set.insert(19990112);set.insert(19990113);set.insert(19990114);set.insert(19990115);set.insert(19990118);set.insert(19990119);set.insert(19990120);set.insert(19990121);set.insert(19990122);set.insert(19990125);set.insert(19990126);set.insert(19990127);set.insert(19990128);set.insert(19990129);set.insert(19990201);set.insert(19990202);set.insert(19990203);set.insert(19990204);set.insert(19990205);set.insert(19990208);set.insert(19990209);set.insert(19990301);set.insert(19990302);set.insert(19990303);set.insert(19990304);set.insert(19990305);set.insert(19990308);set.insert(19990309);set.insert(19990310);set.insert(19990311);set.insert(19990312);set.insert(19990315);set.insert(19990316);set.insert(19990317);set.insert(19990318);set.insert(19990319);set.insert(19990322);set.insert(19990323);set.insert(19990324);set.insert(19990325);set.insert(19990326);set.insert(19990329);set.insert(19990330);set.insert(19990331);set.insert(19990401);set.insert(19990402);set.insert(19990405);set.insert(19990406);set.insert(19990407);set.insert(19990408);set.insert(19990409);set.insert(19990412);set.insert(19990413);set.insert(19990414);set.insert(19990415);set.insert(19990416);set.insert(19990419);set.insert(19990420);set.insert(19990421);set.insert(19990422);set.insert(19990423);set.insert(19990426);set.insert(19990427);set.insert(19990428);set.insert(19990429);set.insert(19990430);set.insert(19990504);set.insert(19990505);set.insert(19990506);set.insert(19990507);set.insert(19990510);set.insert(19990511);set.insert(19990512);set.insert(19990513);set.insert(19990514);set.insert(19990517);set.insert(19990518);set.insert(19990519);set.insert(19990520);set.insert(19990521);set.insert(19990524);set.insert(19990525);set.insert(19990526);set.insert(19990527);set.insert(19990528);set.insert(19990531);set.insert(19990601);set.insert(19990602);set.insert(19990603);set.insert(19990604);set.insert(19990607);set.insert(19990608);set.insert(19990609);set.insert(19990610);set.insert(19990611);set.insert(19990614);set.insert(19990615);set.insert(19990616);set.insert(19990617);set.insert(19990618);set.insert(19990621);set.insert(19990622);set.insert(19990623);set.insert(19990624);set.insert(19990625);set.insert(19990628);set.insert(19990629);set.insert(19990630);set.insert(19990701);set.insert(19990702);set.insert(19990705);set.insert(19990706);set.insert(19990707);set.insert(19990708);set.insert(19990709);set.insert(19990712);set.insert(19990713);set.insert(19990714);set.insert(19990715);set.insert(19990716);set.insert(19990719);set.insert(19990720);set.insert(19990721);set.insert(19990722);set.insert(19990723);set.insert(19990726);set.insert(19990727);set.insert(19990728);set.insert(19990729);set.insert(19990730);set.insert(19990802);set.insert(19990803);set.insert(19990804);set.insert(19990805);set.insert(19990806);set.insert(19990809);set.insert(19990810);set.insert(19990811);set.insert(19990812);set.insert(19990813);set.insert(19990816);set.insert(19990817);set.insert(19990818);set.insert(19990819);set.insert(19990820);set.insert(19990823);set.insert(19990824);set.insert(19990825);set.insert(19990826);set.insert(19990827);set.insert(19990830);set.insert(19990831);set.insert(19990901);set.insert(19990902);set.insert(19990903);set.insert(19990906);set.insert(19990907);set.insert(19990908);set.insert(19990909);set.insert(19990910);set.insert(19990913);set.insert(19990914);set.insert(19990915);set.insert(19990916);set.insert(19990917);set.insert(19990920);set.insert(19990921);set.insert(19990922);set.insert(19990923);set.insert(19990924);set.insert(19990927);set.insert(19990928);set.insert(19990929);set.insert(19990930);set.insert(19991008);set.insert(19991011);set.insert(19991012);set.insert(19991013);set.insert(19991014);set.insert(19991015);set.insert(19991018);set.insert(19991019);set.insert(19991020);set.insert(19991021);set.insert(19991022);set.insert(19991025);set.insert(19991026);set.insert(19991027);set.insert(19991028);set.insert(19991029);set.insert(19991101);set.insert(19991102);set.insert(19991103);set.insert(19991104);set.insert(19991105);set.insert(19991108);set.insert(19991109);set.insert(19991110);set.insert(19991111);set.insert(19991112);set.insert(19991115);set.insert(19991116);set.insert(19991117);set.insert(19991118);set.insert(19991119);set.insert(19991122);set.insert(19991123);set.insert(19991124);set.insert(19991125);set.insert(19991126);set.insert(19991129);set.insert(19991130);set.insert(19991201);set.insert(19991202);set.insert(19991203);set.insert(19991206);set.insert(19991207);set.insert(19991208);set.insert(19991209);set.insert(19991210);set.insert(19991213);set.insert(19991214);set.insert(19991215);set.insert(19991216);set.insert(19991217);set.insert(19991221);set.insert(19991222);set.insert(19991223);set.insert(19991224);set.insert(19991227);set.insert(19991228);set.insert(19991229);set.insert(19991230);set.insert(20000104);set.insert(20000105);set.insert(20000106);set.insert(20000107);set.insert(20000110);set.insert(20000111);set.insert(20000112);set.insert(20000113);set.insert(20000114);set.insert(20000117);set.insert(20000118);set.insert(20000119);set.insert(20000120);set.insert(20000121);set.insert(20000124);set.insert(20000125);set.insert(20000126);set.insert(20000127);set.insert(20000128);set.insert(20000214);set.insert(20000215);set.insert(20000216);set.insert(20000217);set.insert(20000218);set.insert(20000221);set.insert(20000222);set.insert(20000223);set.insert(20000224);set.insert(20000225);set.insert(20000228);set.insert(20000229);set.insert(20000301);set.insert(20000302);set.insert(20000303);set.insert(20000306);set.insert(20000307);set.insert(20000308);set.insert(20000309);set.insert(20000310);set.insert(20000313);set.insert(20000314);set.insert(20000315);set.insert(20000316);set.insert(20000317);set.insert(20000320);set.insert(20000321);set.insert(20000322);set.insert(20000323);set.insert(20000324);set.insert(20000327);set.insert(20000328);set.insert(20000329);set.insert(20000330);set.insert(20000331);set.insert(20000403);set.insert(20000404);set.insert(20000405);set.insert(20000406);set.insert(20000407);set.insert(20000410);set.insert(20000411);set.insert(20000412);set.insert(20000413);set.insert(20000414);set.insert(20000417);set.insert(20000418);set.insert(20000419);set.insert(20000420);set.insert(20000421);set.insert(20000424);set.insert(20000425);set.insert(20000426);set.insert(20000427);set.insert(20000428);set.insert(20000508);set.insert(20000509);set.insert(20000510);set.insert(20000511);set.insert(20000512);set.insert(20000515);set.insert(20000516);set.insert(20000517);set.insert(20000518);set.insert(20000519);set.insert(20000522);set.insert(20000523);set.insert(20000524);set.insert(20000525);set.insert(20000526);set.insert(20000529);set.insert(20000530);set.insert(20000531);set.insert(20000601);set.insert(20000602);set.insert(20000605);set.insert(20000606);set.insert(20000607);set.insert(20000608);set.insert(20000609);set.insert(20000612);set.insert(20000613);set.insert(20000614);set.insert(20000615);set.insert(20000616);set.insert(20000619);set.insert(20000620);set.insert(20000621);set.insert(20000622);set.insert(20000623);set.insert(20000626);set.insert(20000627);set.insert(20000628);set.insert(20000629);set.insert(20000630);set.insert(20000703);set.insert(20000704);set.insert(20000705);set.insert(20000706);set.insert(20000707);set.insert(20000710);set.insert(20000711);set.insert(20000712);set.insert(20000713);set.insert(20000714);set.insert(20000717);set.insert(20000718);set.insert(20000719);set.insert(20000720);set.insert(20000721);set.insert(20000724);set.insert(20000725);set.insert(20000726);set.insert(20000727);set.insert(20000728);set.insert(20000731);set.insert(20000801);set.insert(20000802);set.insert(20000803);set.insert(20000804);set.insert(20000807);set.insert(20000808);set.insert(20000809);set.insert(20000810);set.insert(20000811);set.insert(20000814);set.insert(20000815);set.insert(20000816);set.insert(20000817);set.insert(20000818);set.insert(20000821);set.insert(20000822);set.insert(20000823);set.insert(20000824);set.insert(20000825);set.insert(20000828);set.insert(20000829);set.insert(20000830);set.insert(20000831);set.insert(20000901);set.insert(20000904);set.insert(20000905);set.insert(20000906);set.insert(20000907);set.insert(20000908);set.insert(20000911);set.insert(20000912);set.insert(20000913);set.insert(20000914);set.insert(20000915);set.insert(20000918);set.insert(20000919);set.insert(20000920);set.insert(20000921);set.insert(20000922);set.insert(20000925);set.insert(20000926);set.insert(20000927);set.insert(20000928);set.insert(20000929);set.insert(20001009);set.insert(20001010);set.insert(20001011);set.insert(20001012);set.insert(20001013);set.insert(20001016);set.insert(20001017);set.insert(20001018);set.insert(20001019);set.insert(20001020);set.insert(20001023);set.insert(20001024);set.insert(20001025);set.insert(20001026);set.insert(20001027);set.insert(20001030);set.insert(20001031);set.insert(20001101);set.insert(20001102);set.insert(20001103);set.insert(20001106);set.insert(20001107);set.insert(20001108);set.insert(20001109);set.insert(20001110);set.insert(20001113);set.insert(20001114);set.insert(20001115);set.insert(20001116);set.insert(20001117);set.insert(20001120);set.insert(20001121);set.insert(20001122);set.insert(20001123);set.insert(20001124);set.insert(20001127);set.insert(20001128);set.insert(20001129);set.insert(20001130);set.insert(20001201);set.insert(20001204);set.insert(20001205);set.insert(20001206);set.insert(20001207);set.insert(20001208);set.insert(20001211);set.insert(20001212);set.insert(20001213);set.insert(20001214);set.insert(20001215);set.insert(20001218);set.insert(20001219);set.insert(20001220);set.insert(20001221);set.insert(20001222);set.insert(20001225);set.insert(20001226);set.insert(20001227);set.insert(20001228);set.insert(20001229);set.insert(20010102);set.insert(20010103);set.insert(20010104);set.insert(20010105);set.insert(20010108);set.insert(20010109);set.insert(20010110);set.insert(20010111);set.insert(20010112);set.insert(20010115);set.insert(20010116);set.insert(20010117);set.insert(20010118);set.insert(20010119);set.insert(20010205);set.insert(20010206);set.insert(20010207);set.insert(20010208);set.insert(20010209);set.insert(20010212);set.insert(20010213);set.insert(20010214);set.insert(20010215);set.insert(20010216);set.insert(20010219);set.insert(20010220);set.insert(20010221);set.insert(20010222);set.insert(20010223);set.insert(20010226);set.insert(20010227);set.insert(20010228);set.insert(20010301);set.insert(20010302);set.insert(20010305);set.insert(20010306);set.insert(20010307);set.insert(20010308);set.insert(20010309);set.insert(20010312);set.insert(20010313);set.insert(20010314);set.insert(20010315);set.insert(20010316);set.insert(20010319);set.insert(20010320);set.insert(20010321);set.insert(20010322);set.insert(20010323);set.insert(20010326);set.insert(20010327);set.insert(20010328);set.insert(20010329);set.insert(20010330);set.insert(20010402);set.insert(20010403);set.insert(20010404);set.insert(20010405);set.insert(20010406);set.insert(20010409);set.insert(20010410);set.insert(20010411);set.insert(20010412);set.insert(20010413);set.insert(20010416);set.insert(20010417);set.insert(20010418);set.insert(20010419);set.insert(20010420);set.insert(20010423);set.insert(20010424);set.insert(20010425);set.insert(20010426);set.insert(20010427);set.insert(20010430);set.insert(20010508);set.insert(20010509);set.insert(20010510);set.insert(20010511);set.insert(20010514);set.insert(20010515);set.insert(20010516);set.insert(20010517);set.insert(20010518);set.insert(20010521);set.insert(20010522);set.insert(20010523);set.insert(20010524);set.insert(20010525);set.insert(20010528);set.insert(20010529);set.insert(20010530);set.insert(20010531);set.insert(20010601);set.insert(20010604);set.insert(20010605);set.insert(20010606);set.insert(20010607);set.insert(20010608);set.insert(20010611);set.insert(20010612);set.insert(20010613);set.insert(20010614);set.insert(20010615);set.insert(20010618);set.insert(20010619);set.insert(20010620);set.insert(20010621);set.insert(20010622);set.insert(20010625);set.insert(20010626);set.insert(20010627);set.insert(20010628);set.insert(20010629);set.insert(20010702);set.insert(20010703);set.insert(20010704);set.insert(20010705);set.insert(20010706);set.insert(20010709);set.insert(20010710);set.insert(20010711);set.insert(20010712);set.insert(20010713);set.insert(20010716);set.insert(20010717);set.insert(20010718);set.insert(20010719);set.insert(20010720);set.insert(20010723);set.insert(20010724);set.insert(20010725);set.insert(20010726);set.insert(20010727);set.insert(20010730);set.insert(20010731);set.insert(20010801);set.insert(20010802);set.insert(20010803);set.insert(20010806);set.insert(20010807);set.insert(20010808);set.insert(20010809);set.insert(20010810);set.insert(20010813);set.insert(20010814);set.insert(20010815);set.insert(20010816);set.insert(20010817);set.insert(20010820);set.insert(20010821);set.insert(20010822);set.insert(20010823);set.insert(20010824);set.insert(20010827);set.insert(20010828);set.insert(20010829);set.insert(20010830);set.insert(20010831);set.insert(20010903);set.insert(20010904);set.insert(20010905);set.insert(20010906);set.insert(20010907);set.insert(20010910);set.insert(20010911);set.insert(20010912);set.insert(20010913);set.insert(20010914);set.insert(20010917);set.insert(20010918);set.insert(20010919);set.insert(20010920);set.insert(20010921);set.insert(20010924);set.insert(20010925);set.insert(20010926);set.insert(20010927);set.insert(20010928);set.insert(20011008);set.insert(20011009);set.insert(20011010);set.insert(20011011);set.insert(20011012);set.insert(20011015);set.insert(20011016);set.insert(20011017);set.insert(20011018);set.insert(20011019);set.insert(20011022);set.insert(20011023);set.insert(20011024);set.insert(20011025);set.insert(20011026);set.insert(20011029);set.insert(20011030);set.insert(20011031);set.insert(20011101);set.insert(20011102);set.insert(20011105);set.insert(20011106);set.insert(20011107);set.insert(20011108);set.insert(20011109);set.insert(20011112);set.insert(20011113);set.insert(20011114);set.insert(20011115);set.insert(20011116);set.insert(20011119);set.insert(20011120);set.insert(20011121);set.insert(20011122);set.insert(20011123);set.insert(20011126);set.insert(20011127);set.insert(20011128);set.insert(20011129);set.insert(20011130);set.insert(20011203);set.insert(20011204);set.insert(20011205);set.insert(20011206);set.insert(20011207);set.insert(20011210);set.insert(20011211);set.insert(20011212);set.insert(20011213);set.insert(20011214);set.insert(20011217);set.insert(20011218);set.insert(20011219);set.insert(20011220);set.insert(20011221);set.insert(20011224);set.insert(20011225);set.insert(20011226);set.insert(20011227);set.insert(20011228);set.insert(20011231);set.insert(20020104);set.insert(20020107);set.insert(20020108);set.insert(20020109);set.insert(20020110);set.insert(20020111);set.insert(20020114);set.insert(20020115);set.insert(20020116);set.insert(20020117);set.insert(20020118);set.insert(20020121);set.insert(20020122);set.insert(20020123);set.insert(20020124);set.insert(20020125);set.insert(20020128);set.insert(20020129);set.insert(20020130);set.insert(20020131);set.insert(20020201);set.insert(20020204);set.insert(20020205);set.insert(20020206);set.insert(20020207);set.insert(20020208);set.insert(20020225);set.insert(20020226);set.insert(20020227);set.insert(20020228);set.insert(20020301);set.insert(20020304);set.insert(20020305);set.insert(20020306);set.insert(20020307);set.insert(20020308);set.insert(20020311);set.insert(20020312);set.insert(20020313);set.insert(20020314);set.insert(20020315);set.insert(20020318);set.insert(20020319);set.insert(20020320);set.insert(20020321);set.insert(20020322);set.insert(20020325);set.insert(20020326);set.insert(20020327);set.insert(20020328);set.insert(20020329);set.insert(20020401);set.insert(20020402);set.insert(20020403);set.insert(20020404);set.insert(20020405);set.insert(20020408);set.insert(20020409);set.insert(20020410);set.insert(20020411);set.insert(20020412);set.insert(20020415);set.insert(20020416);set.insert(20020417);set.insert(20020418);set.insert(20020419);set.insert(20020422);set.insert(20020423);set.insert(20020424);set.insert(20020425);set.insert(20020426);set.insert(20020429);set.insert(20020430);set.insert(20020508);set.insert(20020509);set.insert(20020510);set.insert(20020513);set.insert(20020514);set.insert(20020515);set.insert(20020516);set.insert(20020517);set.insert(20020520);set.insert(20020521);set.insert(20020522);set.insert(20020523);set.insert(20020524);set.insert(20020527);set.insert(20020528);set.insert(20020529);set.insert(20020530);set.insert(20020531);set.insert(20020603);set.insert(20020604);set.insert(20020605);set.insert(20020606);set.insert(20020607);set.insert(20020610);set.insert(20020611);set.insert(20020612);set.insert(20020613);set.insert(20020614);set.insert(20020617);set.insert(20020618);set.insert(20020619);set.insert(20020620);set.insert(20020621);set.insert(20020624);set.insert(20020625);set.insert(20020626);set.insert(20020627);set.insert(20020628);set.insert(20020701);set.insert(20020702);set.insert(20020703);set.insert(20020704);set.insert(20020705);set.insert(20020708);set.insert(20020709);set.insert(20020710);set.insert(20020711);set.insert(20020712);set.insert(20020715);set.insert(20020716);set.insert(20020717);set.insert(20020718);set.insert(20020719);set.insert(20020722);set.insert(20020723);set.insert(20020724);set.insert(20020725);set.insert(20020726);set.insert(20020729);set.insert(20020730);set.insert(20020731);set.insert(20020801);set.insert(20020802);set.insert(20020805);set.insert(20020806);set.insert(20020807);set.insert(20020808);set.insert(20020809);set.insert(20020812);set.insert(20020813);set.insert(20020814);set.insert(20020815);set.insert(20020816);set.insert(20020819);set.insert(20020820);set.insert(20020821);set.insert(20020822);set.insert(20020823);set.insert(20020826);set.insert(20020827);set.insert(20020828);set.insert(20020829);set.insert(20020830);set.insert(20020902);set.insert(20020903);set.insert(20020904);set.insert(20020905);set.insert(20020906);set.insert(20020909);set.insert(20020910);set.insert(20020911);set.insert(20020912);set.insert(20020913);set.insert(20020916);set.insert(20020917);set.insert(20020918);set.insert(20020919);set.insert(20020920);set.insert(20020923);set.insert(20020924);set.insert(20020925);set.insert(20020926);set.insert(20020927);set.insert(20021008);set.insert(20021009);set.insert(20021010);set.insert(20021011);set.insert(20021014);set.insert(20021015);set.insert(20021016);set.insert(20021017);set.insert(20021018);set.insert(20021021);set.insert(20021022);set.insert(20021023);set.insert(20021024);set.insert(20021025);set.insert(20021028);set.insert(20021029);set.insert(20021030);set.insert(20021031);set.insert(20021101);set.insert(20021104);set.insert(20021105);set.insert(20021106);set.insert(20021107);set.insert(20021108);set.insert(20021111);set.insert(20021112);set.insert(20021113);set.insert(20021114);set.insert(20021115);set.insert(20021118);set.insert(20021119);set.insert(20021120);set.insert(20021121);set.insert(20021122);set.insert(20021125);set.insert(20021126);set.insert(20021127);set.insert(20021128);set.insert(20021129);set.insert(20021202);set.insert(20021203);set.insert(20021204);set.insert(20021205);set.insert(20021206);set.insert(20021209);set.insert(20021210);set.insert(20021211);set.insert(20021212);set.insert(20021213);set.insert(20021216);set.insert(20021217);set.insert(20021218);set.insert(20021219);set.insert(20021220);set.insert(20021223);set.insert(20021224);set.insert(20021225);set.insert(20021226);set.insert(20021227);set.insert(20021230);set.insert(20021231);set.insert(20030102);set.insert(20030103);set.insert(20030106);set.insert(20030107);set.insert(20030108);set.insert(20030109);set.insert(20030110);set.insert(20030113);set.insert(20030114);set.insert(20030115);set.insert(20030116);set.insert(20030117);set.insert(20030120);set.insert(20030121);set.insert(20030122);set.insert(20030123);set.insert(20030124);set.insert(20030127);set.insert(20030128);set.insert(20030129);set.insert(20030210);set.insert(20030211);set.insert(20030212);set.insert(20030213);set.insert(20030214);set.insert(20030217);set.insert(20030218);set.insert(20030219);set.insert(20030220);set.insert(20030221);set.insert(20030224);set.insert(20030225);set.insert(20030226);set.insert(20030227);set.insert(20030228);set.insert(20030303);set.insert(20030304);set.insert(20030305);set.insert(20030306);set.insert(20030307);set.insert(20030310);set.insert(20030311);set.insert(20030312);set.insert(20030313);set.insert(20030314);set.insert(20030317);set.insert(20030318);set.insert(20030319);set.insert(20030320);set.insert(20030321);set.insert(20030324);set.insert(20030325);set.insert(20030326);set.insert(20030327);set.insert(20030328);set.insert(20030331);set.insert(20030401);set.insert(20030402);set.insert(20030403);set.insert(20030404);set.insert(20030407);set.insert(20030408);set.insert(20030409);set.insert(20030410);set.insert(20030411);set.insert(20030414);set.insert(20030415);set.insert(20030416);set.insert(20030417);set.insert(20030418);set.insert(20030421);set.insert(20030422);set.insert(20030423);set.insert(20030424);set.insert(20030425);set.insert(20030428);set.insert(20030429);set.insert(20030430);set.insert(20030512);set.insert(20030513);set.insert(20030514);set.insert(20030515);set.insert(20030516);set.insert(20030519);set.insert(20030520);set.insert(20030521);set.insert(20030522);set.insert(20030523);set.insert(20030526);set.insert(20030527);set.insert(20030528);set.insert(20030529);set.insert(20030530);set.insert(20030602);set.insert(20030603);set.insert(20030604);set.insert(20030605);set.insert(20030606);set.insert(20030609);set.insert(20030610);set.insert(20030611);set.insert(20030612);set.insert(20030613);set.insert(20030616);set.insert(20030617);set.insert(20030618);set.insert(20030619);set.insert(20030620);set.insert(20030623);set.insert(20030624);set.insert(20030625);set.insert(20030626);set.insert(20030627);set.insert(20030630);set.insert(20030701);set.insert(20030702);set.insert(20030703);set.insert(20030704);set.insert(20030707);set.insert(20030708);set.insert(20030709);set.insert(20030710);set.insert(20030711);set.insert(20030714);set.insert(20030715);set.insert(20030716);set.insert(20030717);set.insert(20030718);set.insert(20030721);set.insert(20030722);set.insert(20030723);set.insert(20030724);set.insert(20030725);set.insert(20030728);set.insert(20030729);set.insert(20030730);set.insert(20030731);set.insert(20030801);set.insert(20030804);set.insert(20030805);set.insert(20030806);set.insert(20030807);set.insert(20030808);set.insert(20030811);set.insert(20030812);set.insert(20030813);set.insert(20030814);set.insert(20030815);set.insert(20030818);set.insert(20030819);set.insert(20030820);set.insert(20030821);set.insert(20030822);set.insert(20030825);set.insert(20030826);set.insert(20030827);set.insert(20030828);set.insert(20030829);set.insert(20030901);set.insert(20030902);set.insert(20030903);set.insert(20030904);set.insert(20030905);set.insert(20030908);set.insert(20030909);set.insert(20030910);set.insert(20030911);set.insert(20030912);set.insert(20030915);set.insert(20030916);set.insert(20030917);set.insert(20030918);set.insert(20030919);set.insert(20030922);set.insert(20030923);set.insert(20030924);set.insert(20030925);set.insert(20030926);set.insert(20030929);set.insert(20030930);set.insert(20031008);set.insert(20031009);set.insert(20031010);set.insert(20031013);set.insert(20031014);set.insert(20031015);set.insert(20031016);set.insert(20031017);set.insert(20031020);set.insert(20031021);set.insert(20031022);set.insert(20031023);set.insert(20031024);set.insert(20031027);set.insert(20031028);set.insert(20031029);set.insert(20031030);set.insert(20031031);set.insert(20031103);set.insert(20031104);set.insert(20031105);set.insert(20031106);set.insert(20031107);set.insert(20031110);set.insert(20031111);set.insert(20031112);set.insert(20031113);set.insert(20031114);set.insert(20031117);set.insert(20031118);set.insert(20031119);set.insert(20031120);set.insert(20031121);set.insert(20031124);set.insert(20031125);set.insert(20031126);set.insert(20031127);set.insert(20031128);set.insert(20031201);set.insert(20031202);set.insert(20031203);set.insert(20031204);set.insert(20031205);set.insert(20031208);set.insert(20031209);set.insert(20031210);set.insert(20031211);set.insert(20031212);set.insert(20031215);set.insert(20031216);set.insert(20031217);set.insert(20031218);set.insert(20031219);set.insert(20031222);set.insert(20031223);set.insert(20031224);set.insert(20031225);set.insert(20031226);set.insert(20031229);set.insert(20031230);set.insert(20031231);set.insert(20040102);set.insert(20040105);set.insert(20040106);set.insert(20040107);set.insert(20040108);set.insert(20040109);set.insert(20040112);set.insert(20040113);set.insert(20040114);set.insert(20040115);set.insert(20040116);set.insert(20040129);set.insert(20040130);set.insert(20040202);set.insert(20040203);set.insert(20040204);set.insert(20040205);set.insert(20040206);set.insert(20040209);set.insert(20040210);set.insert(20040211);set.insert(20040212);set.insert(20040213);set.insert(20040216);set.insert(20040217);set.insert(20040218);set.insert(20040219);set.insert(20040220);set.insert(20040223);set.insert(20040224);set.insert(20040225);set.insert(20040226);set.insert(20040227);set.insert(20040301);set.insert(20040302);set.insert(20040303);set.insert(20040304);set.insert(20040305);set.insert(20040308);set.insert(20040309);set.insert(20040310);set.insert(20040311);set.insert(20040312);set.insert(20040315);set.insert(20040316);set.insert(20040317);set.insert(20040318);set.insert(20040319);set.insert(20040322);set.insert(20040323);set.insert(20040324);set.insert(20040325);set.insert(20040326);set.insert(20040329);set.insert(20040330);set.insert(20040331);set.insert(20040401);set.insert(20040402);set.insert(20040405);set.insert(20040406);set.insert(20040407);set.insert(20040408);set.insert(20040409);set.insert(20040412);set.insert(20040413);set.insert(20040414);set.insert(20040415);set.insert(20040416);set.insert(20040419);set.insert(20040420);set.insert(20040421);set.insert(20040422);set.insert(20040423);set.insert(20040426);set.insert(20040427);set.insert(20040428);set.insert(20040429);set.insert(20040430);set.insert(20040510);set.insert(20040511);set.insert(20040512);set.insert(20040513);set.insert(20040514);set.insert(20040517);set.insert(20040518);set.insert(20040519);set.insert(20040520);set.insert(20040521);set.insert(20040524);set.insert(20040525);set.insert(20040526);set.insert(20040527);set.insert(20040528);set.insert(20040531);set.insert(20040601);set.insert(20040602);set.insert(20040603);set.insert(20040604);set.insert(20040607);set.insert(20040608);set.insert(20040609);set.insert(20040610);set.insert(20040611);set.insert(20040614);set.insert(20040615);set.insert(20040616);set.insert(20040617);set.insert(20040618);set.insert(20040621);set.insert(20040622);set.insert(20040623);set.insert(20040624);set.insert(20040625);set.insert(20040628);set.insert(20040629);set.insert(20040630);set.insert(20040701);set.insert(20040702);set.insert(20040705);set.insert(20040706);set.insert(20040707);set.insert(20040708);set.insert(20040709);set.insert(20040712);set.insert(20040713);set.insert(20040714);set.insert(20040715);set.insert(20040716);set.insert(20040719);set.insert(20040720);set.insert(20040721);set.insert(20040722);set.insert(20040723);set.insert(20040726);set.insert(20040727);set.insert(20040728);set.insert(20040729);set.insert(20040730);set.insert(20040802);set.insert(20040803);set.insert(20040804);set.insert(20040805);set.insert(20040806);set.insert(20040809);set.insert(20040810);set.insert(20040811);set.insert(20040812);set.insert(20040813);set.insert(20040816);set.insert(20040817);set.insert(20040818);set.insert(20040819);set.insert(20040820);set.insert(20040823);set.insert(20040824);set.insert(20040825);set.insert(20040826);set.insert(20040827);set.insert(20040830);set.insert(20040831);set.insert(20040901);set.insert(20040902);set.insert(20040903);set.insert(20040906);set.insert(20040907);set.insert(20040908);set.insert(20040909);set.insert(20040910);set.insert(20040913);set.insert(20040914);set.insert(20040915);set.insert(20040916);set.insert(20040917);set.insert(20040920);set.insert(20040921);set.insert(20040922);set.insert(20040923);set.insert(20040924);set.insert(20040927);set.insert(20040928);set.insert(20040929);set.insert(20040930);set.insert(20041008);set.insert(20041011);set.insert(20041012);set.insert(20041013);set.insert(20041014);set.insert(20041015);set.insert(20041018);set.insert(20041019);set.insert(20041020);set.insert(20041021);set.insert(20041022);set.insert(20041025);set.insert(20041026);set.insert(20041027);set.insert(20041028);set.insert(20041029);set.insert(20041101);set.insert(20041102);set.insert(20041103);set.insert(20041104);set.insert(20041105);set.insert(20041108);set.insert(20041109);set.insert(20041110);set.insert(20041111);set.insert(20041112);set.insert(20041115);set.insert(20041116);set.insert(20041117);set.insert(20041118);set.insert(20041119);set.insert(20041122);set.insert(20041123);set.insert(20041124);set.insert(20041125);set.insert(20041126);set.insert(20041129);set.insert(20041130);set.insert(20041201);set.insert(20041202);set.insert(20041203);set.insert(20041206);set.insert(20041207);set.insert(20041208);set.insert(20041209);set.insert(20041210);set.insert(20041213);set.insert(20041214);set.insert(20041215);set.insert(20041216);set.insert(20041217);set.insert(20041220);set.insert(20041221);set.insert(20041222);set.insert(20041223);set.insert(20041224);set.insert(20041227);set.insert(20041228);set.insert(20041229);set.insert(20041230);set.insert(20041231);set.insert(20050104);set.insert(20050105);set.insert(20050106);set.insert(20050107);set.insert(20050110);set.insert(20050111);set.insert(20050112);set.insert(20050113);set.insert(20050114);set.insert(20050117);set.insert(20050118);set.insert(20050119);set.insert(20050120);set.insert(20050121);set.insert(20050124);set.insert(20050125);set.insert(20050126);set.insert(20050127);set.insert(20050128);set.insert(20050131);set.insert(20050201);set.insert(20050202);set.insert(20050203);set.insert(20050204);set.insert(20050216);set.insert(20050217);set.insert(20050218);set.insert(20050221);set.insert(20050222);set.insert(20050223);set.insert(20050224);set.insert(20050225);set.insert(20050228);set.insert(20050301);set.insert(20050302);set.insert(20050303);set.insert(20050304);set.insert(20050307);set.insert(20050308);set.insert(20050309);set.insert(20050310);set.insert(20050311);set.insert(20050314);set.insert(20050315);set.insert(20050316);set.insert(20050317);set.insert(20050318);set.insert(20050321);set.insert(20050322);set.insert(20050323);set.insert(20050324);set.insert(20050325);set.insert(20050328);set.insert(20050329);set.insert(20050330);set.insert(20050331);set.insert(20050401);set.insert(20050404);set.insert(20050405);set.insert(20050406);set.insert(20050407);set.insert(20050408);set.insert(20050411);set.insert(20050412);set.insert(20050413);set.insert(20050414);set.insert(20050415);set.insert(20050418);set.insert(20050419);set.insert(20050420);set.insert(20050421);set.insert(20050422);set.insert(20050425);set.insert(20050426);set.insert(20050427);set.insert(20050428);set.insert(20050429);set.insert(20050509);set.insert(20050510);set.insert(20050511);set.insert(20050512);set.insert(20050513);set.insert(20050516);set.insert(20050517);set.insert(20050518);set.insert(20050519);set.insert(20050520);set.insert(20050523);set.insert(20050524);set.insert(20050525);set.insert(20050526);set.insert(20050527);set.insert(20050530);set.insert(20050531);set.insert(20050601);set.insert(20050602);set.insert(20050603);set.insert(20050606);set.insert(20050607);set.insert(20050608);set.insert(20050609);set.insert(20050610);set.insert(20050613);set.insert(20050614);set.insert(20050615);set.insert(20050616);set.insert(20050617);set.insert(20050620);set.insert(20050621);set.insert(20050622);set.insert(20050623);set.insert(20050624);set.insert(20050627);set.insert(20050628);set.insert(20050629);set.insert(20050630);set.insert(20050701);set.insert(20050704);set.insert(20050705);set.insert(20050706);set.insert(20050707);set.insert(20050708);set.insert(20050711);set.insert(20050712);set.insert(20050713);set.insert(20050714);set.insert(20050715);set.insert(20050718);set.insert(20050719);set.insert(20050720);set.insert(20050721);set.insert(20050722);set.insert(20050725);set.insert(20050726);set.insert(20050727);set.insert(20050728);set.insert(20050729);set.insert(20050801);set.insert(20050802);set.insert(20050803);set.insert(20050804);set.insert(20050805);set.insert(20050808);set.insert(20050809);set.insert(20050810);set.insert(20050811);set.insert(20050812);set.insert(20050815);set.insert(20050816);set.insert(20050817);set.insert(20050818);set.insert(20050819);set.insert(20050822);set.insert(20050823);set.insert(20050824);set.insert(20050825);set.insert(20050826);set.insert(20050829);set.insert(20050830);set.insert(20050831);set.insert(20050901);set.insert(20050902);set.insert(20050905);set.insert(20050906);set.insert(20050907);set.insert(20050908);set.insert(20050909);set.insert(20050912);set.insert(20050913);set.insert(20050914);set.insert(20050915);set.insert(20050916);set.insert(20050919);set.insert(20050920);set.insert(20050921);set.insert(20050922);set.insert(20050923);set.insert(20050926);set.insert(20050927);set.insert(20050928);set.insert(20050929);set.insert(20050930);set.insert(20051010);set.insert(20051011);set.insert(20051012);set.insert(20051013);set.insert(20051014);set.insert(20051017);set.insert(20051018);set.insert(20051019);set.insert(20051020);set.insert(20051021);set.insert(20051024);set.insert(20051025);set.insert(20051026);set.insert(20051027);set.insert(20051028);set.insert(20051031);set.insert(20051101);set.insert(20051102);set.insert(20051103);set.insert(20051104);set.insert(20051107);set.insert(20051108);set.insert(20051109);set.insert(20051110);set.insert(20051111);set.insert(20051114);set.insert(20051115);set.insert(20051116);set.insert(20051117);set.insert(20051118);set.insert(20051121);set.insert(20051122);set.insert(20051123);set.insert(20051124);set.insert(20051125);set.insert(20051128);set.insert(20051129);set.insert(20051130);set.insert(20051201);set.insert(20051202);set.insert(20051205);set.insert(20051206);set.insert(20051207);set.insert(20051208);set.insert(20051209);set.insert(20051212);set.insert(20051213);set.insert(20051214);set.insert(20051215);set.insert(20051216);set.insert(20051219);set.insert(20051220);set.insert(20051221);set.insert(20051222);set.insert(20051223);set.insert(20051226);set.insert(20051227);set.insert(20051228);set.insert(20051229);set.insert(20051230);set.insert(20060104);set.insert(20060105);set.insert(20060106);set.insert(20060109);set.insert(20060110);set.insert(20060111);set.insert(20060112);set.insert(20060113);set.insert(20060116);set.insert(20060117);set.insert(20060118);set.insert(20060119);set.insert(20060120);set.insert(20060123);set.insert(20060124);set.insert(20060125);set.insert(20060206);set.insert(20060207);set.insert(20060208);set.insert(20060209);set.insert(20060210);set.insert(20060213);set.insert(20060214);set.insert(20060215);set.insert(20060216);set.insert(20060217);set.insert(20060220);set.insert(20060221);set.insert(20060222);set.insert(20060223);set.insert(20060224);set.insert(20060227);set.insert(20060228);set.insert(20060301);set.insert(20060302);set.insert(20060303);set.insert(20060306);set.insert(20060307);set.insert(20060308);set.insert(20060309);set.insert(20060310);set.insert(20060313);set.insert(20060314);set.insert(20060315);set.insert(20060316);set.insert(20060317);set.insert(20060320);set.insert(20060321);set.insert(20060322);set.insert(20060323);set.insert(20060324);set.insert(20060327);set.insert(20060328);set.insert(20060329);set.insert(20060330);set.insert(20060331);set.insert(20060403);set.insert(20060404);set.insert(20060405);set.insert(20060406);set.insert(20060407);set.insert(20060410);set.insert(20060411);set.insert(20060412);set.insert(20060413);set.insert(20060414);set.insert(20060417);set.insert(20060418);set.insert(20060419);set.insert(20060420);set.insert(20060421);set.insert(20060424);set.insert(20060425);set.insert(20060426);set.insert(20060427);set.insert(20060428);set.insert(20060508);set.insert(20060509);set.insert(20060510);set.insert(20060511);set.insert(20060512);set.insert(20060515);set.insert(20060516);set.insert(20060517);set.insert(20060518);set.insert(20060519);set.insert(20060522);set.insert(20060523);set.insert(20060524);set.insert(20060525);set.insert(20060526);set.insert(20060529);set.insert(20060530);set.insert(20060531);set.insert(20060601);set.insert(20060602);set.insert(20060605);set.insert(20060606);set.insert(20060607);set.insert(20060608);set.insert(20060609);set.insert(20060612);set.insert(20060613);set.insert(20060614);set.insert(20060615);set.insert(20060616);set.insert(20060619);set.insert(20060620);set.insert(20060621);set.insert(20060622);set.insert(20060623);set.insert(20060626);set.insert(20060627);set.insert(20060628);set.insert(20060629);set.insert(20060630);set.insert(20060703);set.insert(20060704);set.insert(20060705);set.insert(20060706);set.insert(20060707);set.insert(20060710);set.insert(20060711);set.insert(20060712);set.insert(20060713);set.insert(20060714);set.insert(20060717);set.insert(20060718);set.insert(20060719);set.insert(20060720);set.insert(20060721);set.insert(20060724);set.insert(20060725);set.insert(20060726);set.insert(20060727);set.insert(20060728);set.insert(20060731);set.insert(20060801);set.insert(20060802);set.insert(20060803);set.insert(20060804);set.insert(20060807);set.insert(20060808);set.insert(20060809);set.insert(20060810);set.insert(20060811);set.insert(20060814);set.insert(20060815);set.insert(20060816);set.insert(20060817);set.insert(20060818);set.insert(20060821);set.insert(20060822);set.insert(20060823);set.insert(20060824);set.insert(20060825);set.insert(20060828);set.insert(20060829);set.insert(20060830);set.insert(20060831);set.insert(20060901);set.insert(20060904);set.insert(20060905);set.insert(20060906);set.insert(20060907);set.insert(20060908);set.insert(20060911);set.insert(20060912);set.insert(20060913);set.insert(20060914);set.insert(20060915);set.insert(20060918);set.insert(20060919);set.insert(20060920);set.insert(20060921);set.insert(20060922);set.insert(20060925);set.insert(20060926);set.insert(20060927);set.insert(20060928);set.insert(20060929);set.insert(20061009);set.insert(20061010);set.insert(20061011);set.insert(20061012);set.insert(20061013);set.insert(20061016);set.insert(20061017);set.insert(20061018);set.insert(20061019);set.insert(20061020);set.insert(20061023);set.insert(20061024);set.insert(20061025);set.insert(20061026);set.insert(20061027);set.insert(20061030);set.insert(20061031);set.insert(20061101);set.insert(20061102);set.insert(20061103);set.insert(20061106);set.insert(20061107);set.insert(20061108);set.insert(20061109);set.insert(20061110);set.insert(20061113);set.insert(20061114);set.insert(20061115);set.insert(20061116);set.insert(20061117);set.insert(20061120);set.insert(20061121);set.insert(20061122);set.insert(20061123);set.insert(20061124);set.insert(20061127);set.insert(20061128);set.insert(20061129);set.insert(20061130);set.insert(20061201);set.insert(20061204);set.insert(20061205);set.insert(20061206);set.insert(20061207);set.insert(20061208);set.insert(20061211);set.insert(20061212);set.insert(20061213);set.insert(20061214);set.insert(20061215);set.insert(20061218);set.insert(20061219);set.insert(20061220);set.insert(20061221);set.insert(20061222);set.insert(20061225);set.insert(20061226);set.insert(20061227);set.insert(20061228);set.insert(20061229);set.insert(20070104);set.insert(20070105);set.insert(20070108);set.insert(20070109);set.insert(20070110);set.insert(20070111);set.insert(20070112);set.insert(20070115);set.insert(20070116);set.insert(20070117);set.insert(20070118);set.insert(20070119);set.insert(20070122);set.insert(20070123);set.insert(20070124);set.insert(20070125);set.insert(20070126);set.insert(20070129);set.insert(20070130);set.insert(20070131);set.insert(20070201);set.insert(20070202);set.insert(20070205);set.insert(20070206);set.insert(20070207);set.insert(20070208);set.insert(20070209);set.insert(20070212);set.insert(20070213);set.insert(20070214);set.insert(20070215);set.insert(20070216);set.insert(20070226);set.insert(20070227);set.insert(20070228);set.insert(20070301);set.insert(20070302);set.insert(20070305);set.insert(20070306);set.insert(20070307);set.insert(20070308);set.insert(20070309);set.insert(20070312);set.insert(20070313);set.insert(20070314);set.insert(20070315);set.insert(20070316);set.insert(20070319);set.insert(20070320);set.insert(20070321);set.insert(20070322);set.insert(20070323);set.insert(20070326);set.insert(20070327);set.insert(20070328);set.insert(20070329);set.insert(20070330);set.insert(20070402);set.insert(20070403);set.insert(20070404);set.insert(20070405);set.insert(20070406);set.insert(20070409);set.insert(20070410);set.insert(20070411);set.insert(20070412);set.insert(20070413);set.insert(20070416);set.insert(20070417);set.insert(20070418);set.insert(20070419);set.insert(20070420);set.insert(20070423);set.insert(20070424);set.insert(20070425);set.insert(20070426);set.insert(20070427);set.insert(20070430);set.insert(20070508);set.insert(20070509);set.insert(20070510);set.insert(20070511);set.insert(20070514);set.insert(20070515);set.insert(20070516);set.insert(20070517);set.insert(20070518);set.insert(20070521);set.insert(20070522);set.insert(20070523);set.insert(20070524);set.insert(20070525);set.insert(20070528);set.insert(20070529);set.insert(20070530);set.insert(20070531);set.insert(20070601);set.insert(20070604);set.insert(20070605);set.insert(20070606);set.insert(20070607);set.insert(20070608);set.insert(20070611);set.insert(20070612);set.insert(20070613);set.insert(20070614);set.insert(20070615);set.insert(20070618);set.insert(20070619);set.insert(20070620);set.insert(20070621);set.insert(20070622);set.insert(20070625);set.insert(20070626);set.insert(20070627);set.insert(20070628);set.insert(20070629);set.insert(20070702);set.insert(20070703);set.insert(20070704);set.insert(20070705);set.insert(20070706);set.insert(20070709);set.insert(20070710);set.insert(20070711);set.insert(20070712);set.insert(20070713);set.insert(20070716);set.insert(20070717);set.insert(20070718);set.insert(20070719);set.insert(20070720);set.insert(20070723);set.insert(20070724);set.insert(20070725);set.insert(20070726);set.insert(20070727);set.insert(20070730);set.insert(20070731);set.insert(20070801);set.insert(20070802);set.insert(20070803);set.insert(20070806);set.insert(20070807);set.insert(20070808);set.insert(20070809);set.insert(20070810);set.insert(20070813);set.insert(20070814);set.insert(20070815);set.insert(20070816);set.insert(20070817);set.insert(20070820);set.insert(20070821);set.insert(20070822);set.insert(20070823);set.insert(20070824);set.insert(20070827);set.insert(20070828);set.insert(20070829);set.insert(20070830);set.insert(20070831);set.insert(20070903);set.insert(20070904);set.insert(20070905);set.insert(20070906);set.insert(20070907);set.insert(20070910);set.insert(20070911);set.insert(20070912);set.insert(20070913);set.insert(20070914);set.insert(20070917);set.insert(20070918);set.insert(20070919);set.insert(20070920);set.insert(20070921);set.insert(20070924);set.insert(20070925);set.insert(20070926);set.insert(20070927);set.insert(20070928);set.insert(20071008);set.insert(20071009);set.insert(20071010);set.insert(20071011);set.insert(20071012);set.insert(20071015);set.insert(20071016);set.insert(20071017);set.insert(20071018);set.insert(20071019);set.insert(20071022);set.insert(20071023);set.insert(20071024);set.insert(20071025);set.insert(20071026);set.insert(20071029);set.insert(20071030);set.insert(20071031);set.insert(20071101);set.insert(20071102);set.insert(20071105);set.insert(20071106);set.insert(20071107);set.insert(20071108);set.insert(20071109);set.insert(20071112);set.insert(20071113);set.insert(20071114);set.insert(20071115);set.insert(20071116);set.insert(20071119);set.insert(20071120);set.insert(20071121);set.insert(20071122);set.insert(20071123);set.insert(20071126);set.insert(20071127);set.insert(20071128);set.insert(20071129);set.insert(20071130);set.insert(20071203);set.insert(20071204);set.insert(20071205);set.insert(20071206);set.insert(20071207);set.insert(20071210);set.insert(20071211);set.insert(20071212);set.insert(20071213);set.insert(20071214);set.insert(20071217);set.insert(20071218);set.insert(20071219);set.insert(20071220);set.insert(20071221);set.insert(20071224);set.insert(20071225);set.insert(20071226);set.insert(20071227);set.insert(20071228);set.insert(20080102);set.insert(20080103);set.insert(20080104);set.insert(20080107);set.insert(20080108);set.insert(20080109);set.insert(20080110);set.insert(20080111);set.insert(20080114);set.insert(20080115);set.insert(20080116);set.insert(20080117);set.insert(20080118);set.insert(20080121);set.insert(20080122);set.insert(20080123);set.insert(20080124);set.insert(20080125);set.insert(20080128);set.insert(20080129);set.insert(20080130);set.insert(20080131);set.insert(20080201);set.insert(20080204);set.insert(20080205);set.insert(20080213);set.insert(20080214);set.insert(20080215);set.insert(20080218);set.insert(20080219);set.insert(20080220);set.insert(20080221);set.insert(20080222);set.insert(20080225);set.insert(20080226);set.insert(20080227);set.insert(20080228);set.insert(20080229);set.insert(20080303);set.insert(20080304);set.insert(20080305);set.insert(20080306);set.insert(20080307);set.insert(20080310);set.insert(20080311);set.insert(20080312);set.insert(20080313);set.insert(20080314);set.insert(20080317);set.insert(20080318);set.insert(20080319);set.insert(20080320);set.insert(20080321);set.insert(20080324);set.insert(20080325);set.insert(20080326);set.insert(20080327);set.insert(20080328);set.insert(20080331);set.insert(20080401);set.insert(20080402);set.insert(20080403);set.insert(20080407);set.insert(20080408);set.insert(20080409);set.insert(20080410);set.insert(20080411);set.insert(20080414);set.insert(20080415);set.insert(20080416);set.insert(20080417);set.insert(20080418);set.insert(20080421);set.insert(20080422);set.insert(20080423);set.insert(20080424);set.insert(20080425);set.insert(20080428);set.insert(20080429);set.insert(20080430);set.insert(20080505);set.insert(20080506);set.insert(20080507);set.insert(20080508);set.insert(20080509);set.insert(20080512);set.insert(20080513);set.insert(20080514);set.insert(20080515);set.insert(20080516);set.insert(20080519);set.insert(20080520);set.insert(20080521);set.insert(20080522);set.insert(20080523);set.insert(20080526);set.insert(20080527);set.insert(20080528);set.insert(20080529);set.insert(20080530);set.insert(20080602);set.insert(20080603);set.insert(20080604);set.insert(20080605);set.insert(20080606);set.insert(20080610);set.insert(20080611);set.insert(20080612);set.insert(20080613);set.insert(20080616);set.insert(20080617);set.insert(20080618);set.insert(20080619);set.insert(20080620);set.insert(20080623);set.insert(20080624);set.insert(20080625);set.insert(20080626);set.insert(20080627);set.insert(20080630);set.insert(20080701);set.insert(20080702);set.insert(20080703);set.insert(20080704);set.insert(20080707);set.insert(20080708);set.insert(20080709);set.insert(20080710);set.insert(20080711);set.insert(20080714);set.insert(20080715);set.insert(20080716);set.insert(20080717);set.insert(20080718);set.insert(20080721);set.insert(20080722);set.insert(20080723);set.insert(20080724);set.insert(20080725);set.insert(20080728);set.insert(20080729);set.insert(20080730);set.insert(20080731);set.insert(20080801);set.insert(20080804);set.insert(20080805);set.insert(20080806);set.insert(20080807);set.insert(20080808);set.insert(20080811);set.insert(20080812);set.insert(20080813);set.insert(20080814);set.insert(20080815);set.insert(20080818);set.insert(20080819);set.insert(20080820);set.insert(20080821);set.insert(20080822);set.insert(20080825);set.insert(20080826);set.insert(20080827);set.insert(20080828);set.insert(20080829);set.insert(20080901);set.insert(20080902);set.insert(20080903);set.insert(20080904);set.insert(20080905);set.insert(20080908);set.insert(20080909);set.insert(20080910);set.insert(20080911);set.insert(20080912);set.insert(20080916);set.insert(20080917);set.insert(20080918);set.insert(20080919);set.insert(20080922);set.insert(20080923);set.insert(20080924);set.insert(20080925);set.insert(20080926);set.insert(20081006);set.insert(20081007);set.insert(20081008);set.insert(20081009);set.insert(20081010);set.insert(20081013);set.insert(20081014);set.insert(20081015);set.insert(20081016);set.insert(20081017);set.insert(20081020);set.insert(20081021);set.insert(20081022);set.insert(20081023);set.insert(20081024);set.insert(20081027);set.insert(20081028);set.insert(20081029);set.insert(20081030);set.insert(20081031);set.insert(20081103);set.insert(20081104);set.insert(20081105);set.insert(20081106);set.insert(20081107);set.insert(20081110);set.insert(20081111);set.insert(20081112);set.insert(20081113);set.insert(20081114);set.insert(20081117);set.insert(20081118);set.insert(20081119);set.insert(20081120);set.insert(20081121);set.insert(20081124);set.insert(20081125);set.insert(20081126);set.insert(20081127);set.insert(20081128);set.insert(20081201);set.insert(20081202);set.insert(20081203);set.insert(20081204);set.insert(20081205);set.insert(20081208);set.insert(20081209);set.insert(20081210);set.insert(20081211);set.insert(20081212);set.insert(20081215);set.insert(20081216);set.insert(20081217);set.insert(20081218);set.insert(20081219);set.insert(20081222);set.insert(20081223);set.insert(20081224);set.insert(20081225);set.insert(20081226);set.insert(20081229);set.insert(20081230);set.insert(20081231);set.insert(20090105);set.insert(20090106);set.insert(20090107);set.insert(20090108);set.insert(20090109);set.insert(20090112);set.insert(20090113);set.insert(20090114);set.insert(20090115);set.insert(20090116);set.insert(20090119);set.insert(20090120);set.insert(20090121);set.insert(20090122);set.insert(20090123);set.insert(20090202);set.insert(20090203);set.insert(20090204);set.insert(20090205);set.insert(20090206);set.insert(20090209);set.insert(20090210);set.insert(20090211);set.insert(20090212);set.insert(20090213);set.insert(20090216);set.insert(20090217);set.insert(20090218);set.insert(20090219);set.insert(20090220);set.insert(20090223);set.insert(20090224);set.insert(20090225);set.insert(20090226);set.insert(20090227);set.insert(20090302);set.insert(20090303);set.insert(20090304);set.insert(20090305);set.insert(20090306);set.insert(20090309);set.insert(20090310);set.insert(20090311);set.insert(20090312);set.insert(20090313);set.insert(20090316);set.insert(20090317);set.insert(20090318);set.insert(20090319);set.insert(20090320);set.insert(20090323);set.insert(20090324);set.insert(20090325);set.insert(20090326);set.insert(20090327);set.insert(20090330);set.insert(20090331);set.insert(20090401);set.insert(20090402);set.insert(20090403);set.insert(20090407);set.insert(20090408);set.insert(20090409);set.insert(20090410);set.insert(20090413);set.insert(20090414);set.insert(20090415);set.insert(20090416);set.insert(20090417);set.insert(20090420);set.insert(20090421);set.insert(20090422);set.insert(20090423);set.insert(20090424);set.insert(20090427);set.insert(20090428);set.insert(20090429);set.insert(20090430);set.insert(20090504);set.insert(20090505);set.insert(20090506);set.insert(20090507);set.insert(20090508);set.insert(20090511);set.insert(20090512);set.insert(20090513);set.insert(20090514);set.insert(20090515);set.insert(20090518);set.insert(20090519);set.insert(20090520);set.insert(20090521);set.insert(20090522);set.insert(20090525);set.insert(20090526);set.insert(20090527);set.insert(20090601);set.insert(20090602);set.insert(20090603);set.insert(20090604);set.insert(20090605);set.insert(20090608);set.insert(20090609);set.insert(20090610);set.insert(20090611);set.insert(20090612);set.insert(20090615);set.insert(20090616);set.insert(20090617);set.insert(20090618);set.insert(20090619);set.insert(20090622);set.insert(20090623);set.insert(20090624);set.insert(20090625);set.insert(20090626);set.insert(20090629);set.insert(20090630);set.insert(20090701);set.insert(20090702);set.insert(20090703);set.insert(20090706);set.insert(20090707);set.insert(20090708);set.insert(20090709);set.insert(20090710);set.insert(20090713);set.insert(20090714);set.insert(20090715);set.insert(20090716);set.insert(20090717);set.insert(20090720);set.insert(20090721);set.insert(20090722);set.insert(20090723);set.insert(20090724);set.insert(20090727);set.insert(20090728);set.insert(20090729);set.insert(20090730);set.insert(20090731);set.insert(20090803);set.insert(20090804);set.insert(20090805);set.insert(20090806);set.insert(20090807);set.insert(20090810);set.insert(20090811);set.insert(20090812);set.insert(20090813);set.insert(20090814);set.insert(20090817);set.insert(20090818);set.insert(20090819);set.insert(20090820);set.insert(20090821);set.insert(20090824);set.insert(20090825);set.insert(20090826);set.insert(20090827);set.insert(20090828);set.insert(20090831);set.insert(20090901);set.insert(20090902);set.insert(20090903);set.insert(20090904);set.insert(20090907);set.insert(20090908);set.insert(20090909);set.insert(20090910);set.insert(20090911);set.insert(20090914);set.insert(20090915);set.insert(20090916);set.insert(20090917);set.insert(20090918);set.insert(20090921);set.insert(20090922);set.insert(20090923);set.insert(20090924);set.insert(20090925);set.insert(20090928);set.insert(20090929);set.insert(20090930);set.insert(20091009);set.insert(20091012);set.insert(20091013);set.insert(20091014);set.insert(20091015);set.insert(20091016);set.insert(20091019);set.insert(20091020);set.insert(20091021);set.insert(20091022);set.insert(20091023);set.insert(20091026);set.insert(20091027);set.insert(20091028);set.insert(20091029);set.insert(20091030);set.insert(20091102);set.insert(20091103);set.insert(20091104);set.insert(20091105);set.insert(20091106);set.insert(20091109);set.insert(20091110);set.insert(20091111);set.insert(20091112);set.insert(20091113);set.insert(20091116);set.insert(20091117);set.insert(20091118);set.insert(20091119);set.insert(20091120);set.insert(20091123);set.insert(20091124);set.insert(20091125);set.insert(20091126);set.insert(20091127);set.insert(20091130);set.insert(20091201);set.insert(20091202);set.insert(20091203);set.insert(20091204);set.insert(20091207);set.insert(20091208);set.insert(20091209);set.insert(20091210);set.insert(20091211);set.insert(20091214);set.insert(20091215);set.insert(20091216);set.insert(20091217);set.insert(20091218);set.insert(20091221);set.insert(20091222);set.insert(20091223);set.insert(20091224);set.insert(20091225);set.insert(20091228);set.insert(20091229);set.insert(20091230);set.insert(20091231);set.insert(20100104);set.insert(20100105);set.insert(20100106);set.insert(20100107);set.insert(20100108);set.insert(20100111);set.insert(20100112);set.insert(20100113);set.insert(20100114);set.insert(20100115);set.insert(20100118);set.insert(20100119);set.insert(20100120);set.insert(20100121);set.insert(20100122);set.insert(20100125);set.insert(20100126);set.insert(20100127);set.insert(20100128);set.insert(20100129);set.insert(20100201);set.insert(20100202);set.insert(20100203);set.insert(20100204);set.insert(20100205);set.insert(20100208);set.insert(20100209);set.insert(20100210);set.insert(20100211);set.insert(20100212);set.insert(20100222);set.insert(20100223);set.insert(20100224);set.insert(20100225);set.insert(20100226);set.insert(20100301);set.insert(20100302);set.insert(20100303);set.insert(20100304);set.insert(20100305);set.insert(20100308);set.insert(20100309);set.insert(20100310);set.insert(20100311);set.insert(20100312);set.insert(20100315);set.insert(20100316);set.insert(20100317);set.insert(20100318);set.insert(20100319);set.insert(20100322);set.insert(20100323);set.insert(20100324);set.insert(20100325);set.insert(20100326);set.insert(20100329);set.insert(20100330);set.insert(20100331);set.insert(20100401);set.insert(20100402);set.insert(20100406);set.insert(20100407);set.insert(20100408);set.insert(20100409);set.insert(20100412);set.insert(20100413);set.insert(20100414);set.insert(20100415);set.insert(20100416);set.insert(20100419);set.insert(20100420);set.insert(20100421);set.insert(20100422);set.insert(20100423);set.insert(20100426);set.insert(20100427);set.insert(20100428);set.insert(20100429);set.insert(20100430);set.insert(20100504);set.insert(20100505);set.insert(20100506);set.insert(20100507);set.insert(20100510);set.insert(20100511);set.insert(20100512);set.insert(20100513);set.insert(20100514);set.insert(20100517);set.insert(20100518);set.insert(20100519);set.insert(20100520);set.insert(20100521);set.insert(20100524);set.insert(20100525);set.insert(20100526);set.insert(20100527);set.insert(20100528);set.insert(20100531);set.insert(20100601);set.insert(20100602);set.insert(20100603);set.insert(20100604);set.insert(20100607);set.insert(20100608);set.insert(20100609);set.insert(20100610);set.insert(20100611);set.insert(20100617);set.insert(20100618);set.insert(20100621);set.insert(20100622);set.insert(20100623);set.insert(20100624);set.insert(20100625);set.insert(20100628);set.insert(20100629);set.insert(20100630);set.insert(20100701);set.insert(20100702);set.insert(20100705);set.insert(20100706);set.insert(20100707);set.insert(20100708);set.insert(20100709);set.insert(20100712);set.insert(20100713);set.insert(20100714);set.insert(20100715);set.insert(20100716);set.insert(20100719);set.insert(20100720);set.insert(20100721);set.insert(20100722);set.insert(20100723);set.insert(20100726);set.insert(20100727);set.insert(20100728);set.insert(20100729);set.insert(20100730);set.insert(20100802);set.insert(20100803);set.insert(20100804);set.insert(20100805);set.insert(20100806);set.insert(20100809);set.insert(20100810);set.insert(20100811);set.insert(20100812);set.insert(20100813);set.insert(20100816);set.insert(20100817);set.insert(20100818);set.insert(20100819);set.insert(20100820);set.insert(20100823);set.insert(20100824);set.insert(20100825);set.insert(20100826);set.insert(20100827);set.insert(20100830);set.insert(20100831);set.insert(20100901);set.insert(20100902);set.insert(20100903);set.insert(20100906);set.insert(20100907);set.insert(20100908);set.insert(20100909);set.insert(20100910);set.insert(20100913);set.insert(20100914);set.insert(20100915);set.insert(20100916);set.insert(20100917);set.insert(20100920);set.insert(20100921);set.insert(20100927);set.insert(20100928);set.insert(20100929);set.insert(20100930);set.insert(20101008);set.insert(20101011);set.insert(20101012);set.insert(20101013);set.insert(20101014);set.insert(20101015);set.insert(20101018);set.insert(20101019);set.insert(20101020);set.insert(20101021);set.insert(20101022);set.insert(20101025);set.insert(20101026);set.insert(20101027);set.insert(20101028);set.insert(20101029);set.insert(20101101);set.insert(20101102);set.insert(20101103);set.insert(20101104);set.insert(20101105);set.insert(20101108);set.insert(20101109);set.insert(20101110);set.insert(20101111);set.insert(20101112);set.insert(20101115);set.insert(20101116);set.insert(20101117);set.insert(20101118);set.insert(20101119);set.insert(20101122);set.insert(20101123);set.insert(20101124);set.insert(20101125);set.insert(20101126);set.insert(20101129);set.insert(20101130);set.insert(20101201);set.insert(20101202);set.insert(20101203);set.insert(20101206);set.insert(20101207);set.insert(20101208);set.insert(20101209);set.insert(20101210);set.insert(20101213);set.insert(20101214);set.insert(20101215);set.insert(20101216);set.insert(20101217);set.insert(20101220);set.insert(20101221);set.insert(20101222);set.insert(20101223);set.insert(20101224);set.insert(20101227);set.insert(20101228);set.insert(20101229);set.insert(20101230);set.insert(20101231);set.insert(20110104);set.insert(20110105);set.insert(20110106);set.insert(20110107);set.insert(20110110);set.insert(20110111);set.insert(20110112);set.insert(20110113);set.insert(20110114);set.insert(20110117);set.insert(20110118);set.insert(20110119);set.insert(20110120);set.insert(20110121);set.insert(20110124);set.insert(20110125);set.insert(20110126);set.insert(20110127);set.insert(20110128);set.insert(20110131);set.insert(20110201);set.insert(20110209);set.insert(20110210);set.insert(20110211);set.insert(20110214);set.insert(20110215);set.insert(20110216);set.insert(20110217);set.insert(20110218);set.insert(20110221);set.insert(20110222);set.insert(20110223);set.insert(20110224);set.insert(20110225);set.insert(20110228);set.insert(20110301);set.insert(20110302);set.insert(20110303);set.insert(20110304);set.insert(20110307);set.insert(20110308);set.insert(20110309);set.insert(20110310);set.insert(20110311);set.insert(20110314);set.insert(20110315);set.insert(20110316);set.insert(20110317);set.insert(20110318);set.insert(20110321);set.insert(20110322);set.insert(20110323);set.insert(20110324);set.insert(20110325);set.insert(20110328);set.insert(20110329);set.insert(20110330);set.insert(20110331);set.insert(20110401);set.insert(20110406);set.insert(20110407);set.insert(20110408);set.insert(20110411);set.insert(20110412);set.insert(20110413);set.insert(20110414);set.insert(20110415);set.insert(20110418);set.insert(20110419);set.insert(20110420);set.insert(20110421);set.insert(20110422);set.insert(20110425);set.insert(20110426);set.insert(20110427);set.insert(20110428);set.insert(20110429);set.insert(20110503);set.insert(20110504);set.insert(20110505);set.insert(20110506);set.insert(20110509);set.insert(20110510);set.insert(20110511);set.insert(20110512);set.insert(20110513);set.insert(20110516);set.insert(20110517);set.insert(20110518);set.insert(20110519);set.insert(20110520);set.insert(20110523);set.insert(20110524);set.insert(20110525);set.insert(20110526);set.insert(20110527);set.insert(20110530);set.insert(20110531);set.insert(20110601);set.insert(20110602);set.insert(20110603);set.insert(20110607);set.insert(20110608);set.insert(20110609);set.insert(20110610);set.insert(20110613);set.insert(20110614);set.insert(20110615);set.insert(20110616);set.insert(20110617);set.insert(20110620);set.insert(20110621);set.insert(20110622);set.insert(20110623);set.insert(20110624);set.insert(20110627);set.insert(20110628);set.insert(20110629);set.insert(20110630);set.insert(20110701);set.insert(20110704);set.insert(20110705);set.insert(20110706);set.insert(20110707);set.insert(20110708);set.insert(20110711);set.insert(20110712);set.insert(20110713);set.insert(20110714);set.insert(20110715);set.insert(20110718);set.insert(20110719);set.insert(20110720);set.insert(20110721);set.insert(20110722);set.insert(20110725);set.insert(20110726);set.insert(20110727);set.insert(20110728);set.insert(20110729);set.insert(20110801);set.insert(20110802);set.insert(20110803);set.insert(20110804);set.insert(20110805);set.insert(20110808);set.insert(20110809);set.insert(20110810);set.insert(20110811);set.insert(20110812);set.insert(20110815);set.insert(20110816);set.insert(20110817);set.insert(20110818);set.insert(20110819);set.insert(20110822);set.insert(20110823);set.insert(20110824);set.insert(20110825);set.insert(20110826);set.insert(20110829);set.insert(20110830);set.insert(20110831);set.insert(20110901);set.insert(20110902);set.insert(20110905);set.insert(20110906);set.insert(20110907);set.insert(20110908);set.insert(20110909);set.insert(20110913);set.insert(20110914);set.insert(20110915);set.insert(20110916);set.insert(20110919);set.insert(20110920);set.insert(20110921);set.insert(20110922);set.insert(20110923);set.insert(20110926);set.insert(20110927);set.insert(20110928);set.insert(20110929);set.insert(20110930);set.insert(20111010);set.insert(20111011);set.insert(20111012);set.insert(20111013);set.insert(20111014);set.insert(20111017);set.insert(20111018);set.insert(20111019);set.insert(20111020);set.insert(20111021);set.insert(20111024);set.insert(20111025);set.insert(20111026);set.insert(20111027);set.insert(20111028);set.insert(20111031);set.insert(20111101);set.insert(20111102);set.insert(20111103);set.insert(20111104);set.insert(20111107);set.insert(20111108);set.insert(20111109);set.insert(20111110);set.insert(20111111);set.insert(20111114);set.insert(20111115);set.insert(20111116);set.insert(20111117);set.insert(20111118);set.insert(20111121);set.insert(20111122);set.insert(20111123);set.insert(20111124);set.insert(20111125);set.insert(20111128);set.insert(20111129);set.insert(20111130);set.insert(20111201);set.insert(20111202);set.insert(20111205);set.insert(20111206);set.insert(20111207);set.insert(20111208);set.insert(20111209);set.insert(20111212);set.insert(20111213);set.insert(20111214);set.insert(20111215);set.insert(20111216);set.insert(20111219);set.insert(20111220);set.insert(20111221);set.insert(20111222);set.insert(20111223);set.insert(20111226);set.insert(20111227);set.insert(20111228);set.insert(20111229);set.insert(20111230);set.insert(20120104);set.insert(20120105);set.insert(20120106);set.insert(20120109);set.insert(20120110);set.insert(20120111);set.insert(20120112);set.insert(20120113);set.insert(20120116);set.insert(20120117);set.insert(20120118);set.insert(20120119);set.insert(20120120);set.insert(20120130);set.insert(20120131);set.insert(20120201);set.insert(20120202);set.insert(20120203);set.insert(20120206);set.insert(20120207);set.insert(20120208);set.insert(20120209);set.insert(20120210);set.insert(20120213);set.insert(20120214);set.insert(20120215);set.insert(20120216);set.insert(20120217);set.insert(20120220);set.insert(20120221);set.insert(20120222);set.insert(20120223);set.insert(20120224);set.insert(20120227);set.insert(20120228);set.insert(20120229);set.insert(20120301);set.insert(20120302);set.insert(20120305);set.insert(20120306);set.insert(20120307);set.insert(20120308);set.insert(20120309);set.insert(20120312);set.insert(20120313);set.insert(20120314);set.insert(20120315);set.insert(20120316);set.insert(20120319);set.insert(20120320);set.insert(20120321);set.insert(20120322);set.insert(20120323);set.insert(20120326);set.insert(20120327);set.insert(20120328);set.insert(20120329);set.insert(20120330);set.insert(20120405);set.insert(20120406);set.insert(20120409);set.insert(20120410);set.insert(20120411);set.insert(20120412);set.insert(20120413);set.insert(20120416);set.insert(20120417);set.insert(20120418);set.insert(20120419);set.insert(20120420);set.insert(20120423);set.insert(20120424);set.insert(20120425);set.insert(20120426);set.insert(20120427);set.insert(20120502);set.insert(20120503);set.insert(20120504);set.insert(20120507);set.insert(20120508);set.insert(20120509);set.insert(20120510);set.insert(20120511);set.insert(20120514);set.insert(20120515);set.insert(20120516);set.insert(20120517);set.insert(20120518);set.insert(20120521);set.insert(20120522);set.insert(20120523);set.insert(20120524);set.insert(20120525);set.insert(20120528);set.insert(20120529);set.insert(20120530);set.insert(20120531);set.insert(20120601);set.insert(20120604);set.insert(20120605);set.insert(20120606);set.insert(20120607);set.insert(20120608);set.insert(20120611);set.insert(20120612);set.insert(20120613);set.insert(20120614);set.insert(20120615);set.insert(20120618);set.insert(20120619);set.insert(20120620);set.insert(20120621);set.insert(20120625);set.insert(20120626);set.insert(20120627);set.insert(20120628);set.insert(20120629);set.insert(20120702);set.insert(20120703);set.insert(20120704);set.insert(20120705);set.insert(20120706);set.insert(20120709);set.insert(20120710);set.insert(20120711);set.insert(20120712);set.insert(20120713);set.insert(20120716);set.insert(20120717);set.insert(20120718);set.insert(20120719);set.insert(20120720);set.insert(20120723);set.insert(20120724);set.insert(20120725);set.insert(20120726);set.insert(20120727);set.insert(20120730);set.insert(20120731);set.insert(20120801);set.insert(20120802);set.insert(20120803);set.insert(20120806);set.insert(20120807);set.insert(20120808);set.insert(20120809);set.insert(20120810);set.insert(20120813);set.insert(20120814);set.insert(20120815);set.insert(20120816);set.insert(20120817);set.insert(20120820);set.insert(20120821);set.insert(20120822);set.insert(20120823);set.insert(20120824);set.insert(20120827);set.insert(20120828);set.insert(20120829);set.insert(20120830);set.insert(20120831);set.insert(20120903);set.insert(20120904);set.insert(20120905);set.insert(20120906);set.insert(20120907);set.insert(20120910);set.insert(20120911);set.insert(20120912);set.insert(20120913);set.insert(20120914);set.insert(20120917);set.insert(20120918);set.insert(20120919);set.insert(20120920);set.insert(20120921);set.insert(20120924);set.insert(20120925);set.insert(20120926);set.insert(20120927);set.insert(20120928);set.insert(20121008);set.insert(20121009);set.insert(20121010);set.insert(20121011);set.insert(20121012);set.insert(20121015);set.insert(20121016);set.insert(20121017);set.insert(20121018);set.insert(20121019);set.insert(20121022);set.insert(20121023);set.insert(20121024);set.insert(20121025);set.insert(20121026);set.insert(20121029);set.insert(20121030);set.insert(20121031);set.insert(20121101);set.insert(20121102);set.insert(20121105);set.insert(20121106);set.insert(20121107);set.insert(20121108);set.insert(20121109);set.insert(20121112);set.insert(20121113);set.insert(20121114);set.insert(20121115);set.insert(20121116);set.insert(20121119);set.insert(20121120);set.insert(20121121);set.insert(20121122);set.insert(20121123);set.insert(20121126);set.insert(20121127);set.insert(20121128);set.insert(20121129);set.insert(20121130);set.insert(20121203);set.insert(20121204);set.insert(20121205);set.insert(20121206);set.insert(20121207);set.insert(20121210);set.insert(20121211);set.insert(20121212);set.insert(20121213);set.insert(20121214);set.insert(20121217);set.insert(20121218);set.insert(20121219);set.insert(20121220);set.insert(20121221);set.insert(20121224);set.insert(20121225);set.insert(20121226);set.insert(20121227);set.insert(20121228);set.insert(20121231);set.insert(20130104);set.insert(20130107);set.insert(20130108);set.insert(20130109);set.insert(20130110);set.insert(20130111);set.insert(20130114);set.insert(20130115);set.insert(20130116);set.insert(20130117);set.insert(20130118);set.insert(20130121);set.insert(20130122);set.insert(20130123);set.insert(20130124);set.insert(20130125);set.insert(20130128);set.insert(20130129);set.insert(20130130);set.insert(20130131);set.insert(20130201);set.insert(20130204);set.insert(20130205);set.insert(20130206);set.insert(20130207);set.insert(20130208);set.insert(20130218);set.insert(20130219);set.insert(20130220);set.insert(20130221);set.insert(20130222);set.insert(20130225);set.insert(20130226);set.insert(20130227);set.insert(20130228);set.insert(20130301);set.insert(20130304);set.insert(20130305);set.insert(20130306);set.insert(20130307);set.insert(20130308);set.insert(20130311);set.insert(20130312);set.insert(20130313);set.insert(20130314);set.insert(20130315);set.insert(20130318);set.insert(20130319);set.insert(20130320);set.insert(20130321);set.insert(20130322);set.insert(20130325);set.insert(20130326);set.insert(20130327);set.insert(20130328);set.insert(20130329);set.insert(20130401);set.insert(20130402);set.insert(20130403);set.insert(20130408);set.insert(20130409);set.insert(20130410);set.insert(20130411);set.insert(20130412);set.insert(20130415);set.insert(20130416);set.insert(20130417);set.insert(20130418);set.insert(20130419);set.insert(20130422);set.insert(20130423);set.insert(20130424);set.insert(20130425);set.insert(20130426);set.insert(20130502);set.insert(20130503);set.insert(20130506);set.insert(20130507);set.insert(20130508);set.insert(20130509);set.insert(20130510);set.insert(20130513);set.insert(20130514);set.insert(20130515);set.insert(20130516);set.insert(20130517);set.insert(20130520);set.insert(20130521);set.insert(20130522);set.insert(20130523);set.insert(20130524);set.insert(20130527);set.insert(20130528);set.insert(20130529);set.insert(20130530);set.insert(20130531);set.insert(20130603);set.insert(20130604);set.insert(20130605);set.insert(20130606);set.insert(20130607);set.insert(20130613);set.insert(20130614);set.insert(20130617);set.insert(20130618);set.insert(20130619);set.insert(20130620);set.insert(20130621);set.insert(20130624);set.insert(20130625);set.insert(20130626);set.insert(20130627);set.insert(20130628);set.insert(20130701);set.insert(20130702);set.insert(20130703);set.insert(20130704);set.insert(20130705);set.insert(20130708);set.insert(20130709);set.insert(20130710);set.insert(20130711);set.insert(20130712);set.insert(20130715);set.insert(20130716);set.insert(20130717);set.insert(20130718);set.insert(20130719);set.insert(20130722);set.insert(20130723);set.insert(20130724);set.insert(20130725);set.insert(20130726);set.insert(20130729);set.insert(20130730);set.insert(20130731);set.insert(20130801);set.insert(20130802);set.insert(20130805);set.insert(20130806);set.insert(20130807);set.insert(20130808);set.insert(20130809);set.insert(20130812);set.insert(20130813);set.insert(20130814);set.insert(20130815);set.insert(20130816);set.insert(20130819);set.insert(20130820);set.insert(20130821);set.insert(20130822);set.insert(20130823);set.insert(20130826);set.insert(20130827);set.insert(20130828);set.insert(20130829);set.insert(20130830);set.insert(20130902);set.insert(20130903);set.insert(20130904);set.insert(20130905);set.insert(20130906);set.insert(20130909);set.insert(20130910);set.insert(20130911);set.insert(20130912);set.insert(20130913);set.insert(20130916);set.insert(20130917);set.insert(20130918);set.insert(20130923);set.insert(20130924);set.insert(20130925);set.insert(20130926);set.insert(20130927);set.insert(20130930);set.insert(20131008);set.insert(20131009);set.insert(20131010);set.insert(20131011);set.insert(20131014);set.insert(20131015);set.insert(20131016);set.insert(20131017);set.insert(20131018);set.insert(20131021);set.insert(20131022);set.insert(20131023);set.insert(20131024);set.insert(20131025);set.insert(20131028);set.insert(20131029);set.insert(20131030);set.insert(20131031);set.insert(20131101);set.insert(20131104);set.insert(20131105);set.insert(20131106);set.insert(20131107);set.insert(20131108);set.insert(20131111);set.insert(20131112);set.insert(20131113);set.insert(20131114);set.insert(20131115);set.insert(20131118);set.insert(20131119);set.insert(20131120);set.insert(20131121);set.insert(20131122);set.insert(20131125);set.insert(20131126);set.insert(20131127);set.insert(20131128);set.insert(20131129);set.insert(20131202);set.insert(20131203);set.insert(20131204);set.insert(20131205);set.insert(20131206);set.insert(20131209);set.insert(20131210);set.insert(20131211);set.insert(20131212);set.insert(20131213);set.insert(20131216);set.insert(20131217);set.insert(20131218);set.insert(20131219);set.insert(20131220);set.insert(20131223);set.insert(20131224);set.insert(20131225);set.insert(20131226);set.insert(20131227);set.insert(20131230);set.insert(20131231);set.insert(20140102);set.insert(20140103);set.insert(20140106);set.insert(20140107);set.insert(20140108);set.insert(20140109);set.insert(20140110);set.insert(20140113);set.insert(20140114);set.insert(20140115);set.insert(20140116);set.insert(20140117);set.insert(20140120);set.insert(20140121);set.insert(20140122);set.insert(20140123);set.insert(20140124);set.insert(20140127);set.insert(20140128);set.insert(20140129);set.insert(20140130);set.insert(20140207);set.insert(20140210);set.insert(20140211);set.insert(20140212);set.insert(20140213);set.insert(20140214);set.insert(20140217);set.insert(20140218);set.insert(20140219);set.insert(20140220);set.insert(20140221);set.insert(20140224);set.insert(20140225);set.insert(20140226);set.insert(20140227);set.insert(20140228);set.insert(20140303);set.insert(20140304);set.insert(20140305);set.insert(20140306);set.insert(20140307);set.insert(20140310);set.insert(20140311);set.insert(20140312);set.insert(20140313);set.insert(20140314);set.insert(20140317);set.insert(20140318);set.insert(20140319);set.insert(20140320);set.insert(20140321);set.insert(20140324);set.insert(20140325);set.insert(20140326);set.insert(20140327);set.insert(20140328);set.insert(20140331);set.insert(20140401);set.insert(20140402);set.insert(20140403);set.insert(20140404);set.insert(20140408);set.insert(20140409);set.insert(20140410);set.insert(20140411);set.insert(20140414);set.insert(20140415);set.insert(20140416);set.insert(20140417);set.insert(20140418);set.insert(20140421);set.insert(20140422);set.insert(20140423);set.insert(20140424);set.insert(20140425);set.insert(20140428);set.insert(20140429);set.insert(20140430);set.insert(20140505);set.insert(20140506);set.insert(20140507);set.insert(20140508);set.insert(20140509);set.insert(20140512);set.insert(20140513);set.insert(20140514);set.insert(20140515);set.insert(20140516);set.insert(20140519);set.insert(20140520);set.insert(20140521);set.insert(20140522);set.insert(20140523);set.insert(20140526);set.insert(20140527);set.insert(20140528);set.insert(20140529);set.insert(20140530);set.insert(20140603);set.insert(20140604);set.insert(20140605);set.insert(20140606);set.insert(20140609);set.insert(20140610);set.insert(20140611);set.insert(20140612);set.insert(20140613);set.insert(20140616);set.insert(20140617);set.insert(20140618);set.insert(20140619);set.insert(20140620);set.insert(20140623);set.insert(20140624);set.insert(20140625);set.insert(20140626);set.insert(20140627);set.insert(20140630);set.insert(20140701);set.insert(20140702);set.insert(20140703);set.insert(20140704);set.insert(20140707);set.insert(20140708);set.insert(20140709);set.insert(20140710);set.insert(20140711);set.insert(20140714);set.insert(20140715);set.insert(20140716);set.insert(20140717);set.insert(20140718);set.insert(20140721);set.insert(20140722);set.insert(20140723);set.insert(20140724);set.insert(20140725);set.insert(20140728);set.insert(20140729);set.insert(20140730);set.insert(20140731);set.insert(20140801);set.insert(20140804);set.insert(20140805);set.insert(20140806);set.insert(20140807);set.insert(20140808);set.insert(20140811);set.insert(20140812);set.insert(20140813);set.insert(20140814);set.insert(20140815);set.insert(20140818);set.insert(20140819);set.insert(20140820);set.insert(20140821);set.insert(20140822);set.insert(20140825);set.insert(20140826);set.insert(20140827);set.insert(20140828);set.insert(20140829);set.insert(20140901);set.insert(20140902);set.insert(20140903);set.insert(20140904);set.insert(20140905);set.insert(20140909);set.insert(20140910);set.insert(20140911);set.insert(20140912);set.insert(20140915);set.insert(20140916);set.insert(20140917);set.insert(20140918);set.insert(20140919);set.insert(20140922);set.insert(20140923);set.insert(20140924);set.insert(20140925);set.insert(20140926);set.insert(20140929);set.insert(20140930);set.insert(20141008);set.insert(20141009);set.insert(20141010);set.insert(20141013);set.insert(20141014);set.insert(20141015);set.insert(20141016);set.insert(20141017);set.insert(20141020);set.insert(20141021);set.insert(20141022);set.insert(20141023);set.insert(20141024);set.insert(20141027);set.insert(20141028);set.insert(20141029);set.insert(20141030);set.insert(20141031);set.insert(20141103);set.insert(20141104);set.insert(20141105);set.insert(20141106);set.insert(20141107);set.insert(20141110);set.insert(20141111);set.insert(20141112);set.insert(20141113);set.insert(20141114);set.insert(20141117);set.insert(20141118);set.insert(20141119);set.insert(20141120);set.insert(20141121);set.insert(20141124);set.insert(20141125);set.insert(20141126);set.insert(20141127);set.insert(20141128);set.insert(20141201);set.insert(20141202);set.insert(20141203);set.insert(20141204);set.insert(20141205);set.insert(20141208);set.insert(20141209);set.insert(20141210);set.insert(20141211);set.insert(20141212);set.insert(20141215);set.insert(20141216);set.insert(20141217);set.insert(20141218);set.insert(20141219);set.insert(20141222);set.insert(20141223);set.insert(20141224);set.insert(20141225);set.insert(20141226);set.insert(20141229);set.insert(20141230);set.insert(20141231);set.insert(20150105);set.insert(20150106);set.insert(20150107);set.insert(20150108);set.insert(20150109);set.insert(20150112);set.insert(20150113);set.insert(20150114);set.insert(20150115);set.insert(20150116);set.insert(20150119);set.insert(20150120);set.insert(20150121);set.insert(20150122);set.insert(20150123);set.insert(20150126);set.insert(20150127);set.insert(20150128);set.insert(20150129);set.insert(20150130);set.insert(20150202);set.insert(20150203);set.insert(20150204);set.insert(20150205);set.insert(20150206);set.insert(20150209);set.insert(20150210);set.insert(20150211);set.insert(20150212);set.insert(20150213);set.insert(20150216);set.insert(20150217);set.insert(20150225);set.insert(20150226);set.insert(20150227);set.insert(20150302);set.insert(20150303);set.insert(20150304);set.insert(20150305);set.insert(20150306);set.insert(20150309);set.insert(20150310);set.insert(20150311);set.insert(20150312);set.insert(20150313);set.insert(20150316);set.insert(20150317);set.insert(20150318);set.insert(20150319);set.insert(20150320);set.insert(20150323);set.insert(20150324);set.insert(20150325);set.insert(20150326);set.insert(20150327);set.insert(20150330);set.insert(20150331);set.insert(20150401);set.insert(20150402);set.insert(20150403);set.insert(20150407);set.insert(20150408);set.insert(20150409);set.insert(20150410);set.insert(20150413);set.insert(20150414);set.insert(20150415);set.insert(20150416);set.insert(20150417);set.insert(20150420);set.insert(20150421);set.insert(20150422);set.insert(20150423);set.insert(20150424);set.insert(20150427);set.insert(20150428);set.insert(20150429);set.insert(20150430);set.insert(20150504);set.insert(20150505);set.insert(20150506);set.insert(20150507);set.insert(20150508);set.insert(20150511);set.insert(20150512);set.insert(20150513);set.insert(20150514);set.insert(20150515);set.insert(20150518);set.insert(20150519);set.insert(20150520);set.insert(20150521);set.insert(20150522);set.insert(20150525);set.insert(20150526);set.insert(20150527);set.insert(20150528);set.insert(20150529);set.insert(20150601);set.insert(20150602);set.insert(20150603);set.insert(20150604);set.insert(20150605);set.insert(20150608);set.insert(20150609);set.insert(20150610);set.insert(20150611);set.insert(20150612);set.insert(20150615);set.insert(20150616);set.insert(20150617);set.insert(20150618);set.insert(20150619);set.insert(20150623);set.insert(20150624);set.insert(20150625);set.insert(20150626);set.insert(20150629);set.insert(20150630);set.insert(20150701);set.insert(20150702);set.insert(20150703);set.insert(20150706);set.insert(20150707);set.insert(20150708);set.insert(20150709);set.insert(20150710);set.insert(20150713);set.insert(20150714);set.insert(20150715);set.insert(20150716);set.insert(20150717);set.insert(20150720);set.insert(20150721);set.insert(20150722);set.insert(20150723);set.insert(20150724);set.insert(20150727);set.insert(20150728);set.insert(20150729);set.insert(20150730);set.insert(20150731);set.insert(20150803);set.insert(20150804);set.insert(20150805);set.insert(20150806);set.insert(20150807);set.insert(20150810);set.insert(20150811);set.insert(20150812);set.insert(20150813);set.insert(20150814);set.insert(20150817);set.insert(20150818);set.insert(20150819);set.insert(20150820);set.insert(20150821);set.insert(20150824);set.insert(20150825);set.insert(20150826);set.insert(20150827);set.insert(20150828);set.insert(20150831);set.insert(20150901);set.insert(20150902);set.insert(20150907);set.insert(20150908);set.insert(20150909);set.insert(20150910);set.insert(20150911);set.insert(20150914);set.insert(20150915);set.insert(20150916);set.insert(20150917);set.insert(20150918);set.insert(20150921);set.insert(20150922);set.insert(20150923);set.insert(20150924);set.insert(20150925);set.insert(20150928);set.insert(20150929);set.insert(20150930);set.insert(20151008);set.insert(20151009);set.insert(20151012);set.insert(20151013);set.insert(20151014);set.insert(20151015);set.insert(20151016);set.insert(20151019);set.insert(20151020);set.insert(20151021);set.insert(20151022);set.insert(20151023);set.insert(20151026);set.insert(20151027);set.insert(20151028);set.insert(20151029);set.insert(20151030);set.insert(20151102);set.insert(20151103);set.insert(20151104);set.insert(20151105);set.insert(20151106);set.insert(20151109);set.insert(20151110);set.insert(20151111);set.insert(20151112);set.insert(20151113);set.insert(20151116);set.insert(20151117);set.insert(20151118);set.insert(20151119);set.insert(20151120);set.insert(20151123);set.insert(20151124);set.insert(20151125);set.insert(20151126);set.insert(20151127);set.insert(20151130);set.insert(20151201);set.insert(20151202);set.insert(20151203);set.insert(20151204);set.insert(20151207);set.insert(20151208);set.insert(20151209);set.insert(20151210);set.insert(20151211);set.insert(20151214);set.insert(20151215);set.insert(20151216);set.insert(20151217);set.insert(20151218);set.insert(20151221);set.insert(20151222);set.insert(20151223);set.insert(20151224);set.insert(20151225);set.insert(20151228);set.insert(20151229);set.insert(20151230);set.insert(20151231);set.insert(20160104);set.insert(20160105);set.insert(20160106);set.insert(20160107);set.insert(20160108);set.insert(20160111);set.insert(20160112);set.insert(20160113);set.insert(20160114);set.insert(20160115);set.insert(20160118);set.insert(20160119);set.insert(20160120);set.insert(20160121);set.insert(20160122);set.insert(20160125);set.insert(20160126);set.insert(20160127);set.insert(20160128);set.insert(20160129);set.insert(20160201);set.insert(20160202);set.insert(20160203);set.insert(20160204);set.insert(20160205);set.insert(20160215);set.insert(20160216);set.insert(20160217);set.insert(20160218);set.insert(20160219);set.insert(20160222);set.insert(20160223);set.insert(20160224);set.insert(20160225);set.insert(20160226);set.insert(20160229);set.insert(20160301);set.insert(20160302);set.insert(20160303);set.insert(20160304);set.insert(20160307);set.insert(20160308);set.insert(20160309);set.insert(20160310);set.insert(20160311);set.insert(20160314);set.insert(20160315);set.insert(20160316);set.insert(20160317);set.insert(20160318);set.insert(20160321);set.insert(20160322);set.insert(20160323);set.insert(20160324);set.insert(20160325);set.insert(20160328);set.insert(20160329);set.insert(20160330);set.insert(20160331);set.insert(20160401);set.insert(20160405);set.insert(20160406);set.insert(20160407);set.insert(20160408);set.insert(20160411);set.insert(20160412);set.insert(20160413);set.insert(20160414);set.insert(20160415);set.insert(20160418);set.insert(20160419);set.insert(20160420);set.insert(20160421);set.insert(20160422);set.insert(20160425);set.insert(20160426);set.insert(20160427);set.insert(20160428);set.insert(20160429);set.insert(20160503);set.insert(20160504);set.insert(20160505);set.insert(20160506);set.insert(20160509);set.insert(20160510);set.insert(20160511);set.insert(20160512);set.insert(20160513);set.insert(20160516);set.insert(20160517);set.insert(20160518);set.insert(20160519);set.insert(20160520);set.insert(20160523);set.insert(20160524);set.insert(20160525);set.insert(20160526);set.insert(20160527);set.insert(20160530);set.insert(20160531);set.insert(20160601);set.insert(20160602);set.insert(20160603);set.insert(20160606);set.insert(20160607);set.insert(20160608);set.insert(20160613);set.insert(20160614);set.insert(20160615);set.insert(20160616);set.insert(20160617);set.insert(20160620);set.insert(20160621);set.insert(20160622);set.insert(20160623);set.insert(20160624);set.insert(20160627);set.insert(20160628);set.insert(20160629);set.insert(20160630);set.insert(20160701);set.insert(20160704);set.insert(20160705);set.insert(20160706);set.insert(20160707);set.insert(20160708);set.insert(20160711);set.insert(20160712);set.insert(20160713);set.insert(20160714);set.insert(20160715);set.insert(20160718);set.insert(20160719);set.insert(20160720);set.insert(20160721);set.insert(20160722);set.insert(20160725);set.insert(20160726);set.insert(20160727);set.insert(20160728);set.insert(20160729);set.insert(20160801);set.insert(20160802);set.insert(20160803);set.insert(20160804);set.insert(20160805);set.insert(20160808);set.insert(20160809);set.insert(20160810);set.insert(20160811);set.insert(20160812);set.insert(20160815);set.insert(20160816);set.insert(20160817);set.insert(20160818);set.insert(20160819);set.insert(20160822);set.insert(20160823);set.insert(20160824);set.insert(20160825);set.insert(20160826);set.insert(20160829);set.insert(20160830);set.insert(20160831);set.insert(20160901);set.insert(20160902);set.insert(20160905);set.insert(20160906);set.insert(20160907);set.insert(20160908);set.insert(20160909);set.insert(20160912);set.insert(20160913);set.insert(20160914);set.insert(20160919);set.insert(20160920);set.insert(20160921);set.insert(20160922);set.insert(20160923);set.insert(20160926);set.insert(20160927);set.insert(20160928);set.insert(20160929);set.insert(20160930);set.insert(20161010);set.insert(20161011);set.insert(20161012);set.insert(20161013);set.insert(20161014);set.insert(20161017);set.insert(20161018);set.insert(20161019);set.insert(20161020);set.insert(20161021);set.insert(20161024);set.insert(20161025);set.insert(20161026);set.insert(20161027);set.insert(20161028);set.insert(20161031);set.insert(20161101);set.insert(20161102);set.insert(20161103);set.insert(20161104);set.insert(20161107);set.insert(20161108);set.insert(20161109);set.insert(20161110);set.insert(20161111);set.insert(20161114);set.insert(20161115);set.insert(20161116);set.insert(20161117);set.insert(20161118);set.insert(20161121);set.insert(20161122);set.insert(20161123);set.insert(20161124);set.insert(20161125);set.insert(20161128);set.insert(20161129);set.insert(20161130);set.insert(20161201);set.insert(20161202);set.insert(20161205);set.insert(20161206);set.insert(20161207);set.insert(20161208);set.insert(20161209);set.insert(20161212);set.insert(20161213);set.insert(20161214);set.insert(20161215);set.insert(20161216);set.insert(20161219);set.insert(20161220);set.insert(20161221);set.insert(20161222);set.insert(20161223);set.insert(20161226);set.insert(20161227);set.insert(20161228);set.insert(20161229);set.insert(20161230);set.insert(20170103);set.insert(20170104);set.insert(20170105);set.insert(20170106);set.insert(20170109);set.insert(20170110);set.insert(20170111);set.insert(20170112);set.insert(20170113);set.insert(20170116);set.insert(20170117);set.insert(20170118);set.insert(20170119);set.insert(20170120);set.insert(20170123);set.insert(20170124);set.insert(20170125);set.insert(20170126);set.insert(20170203);set.insert(20170206);set.insert(20170207);set.insert(20170208);set.insert(20170209);set.insert(20170210);set.insert(20170213);set.insert(20170214);set.insert(20170215);set.insert(20170216);set.insert(20170217);set.insert(20170220);set.insert(20170221);set.insert(20170222);set.insert(20170223);set.insert(20170224);set.insert(20170227);set.insert(20170228);set.insert(20170301);set.insert(20170302);set.insert(20170303);set.insert(20170306);set.insert(20170307);set.insert(20170308);set.insert(20170309);set.insert(20170310);set.insert(20170313);set.insert(20170314);set.insert(20170315);set.insert(20170316);set.insert(20170317);set.insert(20170320);set.insert(20170321);set.insert(20170322);set.insert(20170323);set.insert(20170324);set.insert(20170327);set.insert(20170328);set.insert(20170329);set.insert(20170330);set.insert(20170331);set.insert(20170405);set.insert(20170406);set.insert(20170407);set.insert(20170410);set.insert(20170411);set.insert(20170412);set.insert(20170413);set.insert(20170414);set.insert(20170417);set.insert(20170418);set.insert(20170419);set.insert(20170420);set.insert(20170421);set.insert(20170424);set.insert(20170425);set.insert(20170426);set.insert(20170427);set.insert(20170428);set.insert(20170502);set.insert(20170503);set.insert(20170504);set.insert(20170505);set.insert(20170508);set.insert(20170509);set.insert(20170510);set.insert(20170511);set.insert(20170512);set.insert(20170515);set.insert(20170516);set.insert(20170517);set.insert(20170518);set.insert(20170519);set.insert(20170522);set.insert(20170523);set.insert(20170524);set.insert(20170525);set.insert(20170526);set.insert(20170531);set.insert(20170601);set.insert(20170602);set.insert(20170605);set.insert(20170606);set.insert(20170607);set.insert(20170608);set.insert(20170609);set.insert(20170612);set.insert(20170613);set.insert(20170614);set.insert(20170615);set.insert(20170616);set.insert(20170619);set.insert(20170620);set.insert(20170621);set.insert(20170622);set.insert(20170623);set.insert(20170626);set.insert(20170627);set.insert(20170628);set.insert(20170629);set.insert(20170630);set.insert(20170703);set.insert(20170704);set.insert(20170705);set.insert(20170706);set.insert(20170707);set.insert(20170710);set.insert(20170711);set.insert(20170712);set.insert(20170713);set.insert(20170714);set.insert(20170717);set.insert(20170718);set.insert(20170719);set.insert(20170720);set.insert(20170721);set.insert(20170724);set.insert(20170725);set.insert(20170726);set.insert(20170727);set.insert(20170728);set.insert(20170731);set.insert(20170801);set.insert(20170802);set.insert(20170803);set.insert(20170804);set.insert(20170807);set.insert(20170808);set.insert(20170809);set.insert(20170810);set.insert(20170811);set.insert(20170814);set.insert(20170815);set.insert(20170816);set.insert(20170817);set.insert(20170818);set.insert(20170821);set.insert(20170822);set.insert(20170823);set.insert(20170824);set.insert(20170825);set.insert(20170828);set.insert(20170829);set.insert(20170830);set.insert(20170831);set.insert(20170901);set.insert(20170904);set.insert(20170905);set.insert(20170906);set.insert(20170907);set.insert(20170908);set.insert(20170911);set.insert(20170912);set.insert(20170913);set.insert(20170914);set.insert(20170915);set.insert(20170918);set.insert(20170919);set.insert(20170920);set.insert(20170921);set.insert(20170922);set.insert(20170925);set.insert(20170926);set.insert(20170927);set.insert(20170928);set.insert(20170929);set.insert(20171009);set.insert(20171010);set.insert(20171011);set.insert(20171012);set.insert(20171013);set.insert(20171016);set.insert(20171017);set.insert(20171018);set.insert(20171019);set.insert(20171020);set.insert(20171023);set.insert(20171024);set.insert(20171025);set.insert(20171026);set.insert(20171027);set.insert(20171030);set.insert(20171031);set.insert(20171101);set.insert(20171102);set.insert(20171103);set.insert(20171106);set.insert(20171107);set.insert(20171108);set.insert(20171109);set.insert(20171110);set.insert(20171113);set.insert(20171114);set.insert(20171115);set.insert(20171116);set.insert(20171117);set.insert(20171120);set.insert(20171121);set.insert(20171122);set.insert(20171123);set.insert(20171124);set.insert(20171127);set.insert(20171128);set.insert(20171129);set.insert(20171130);set.insert(20171201);set.insert(20171204);set.insert(20171205);set.insert(20171206);set.insert(20171207);set.insert(20171208);set.insert(20171211);set.insert(20171212);set.insert(20171213);set.insert(20171214);set.insert(20171215);set.insert(20171218);set.insert(20171219);set.insert(20171220);set.insert(20171221);set.insert(20171222);set.insert(20171225);set.insert(20171226);set.insert(20171227);set.insert(20171228);set.insert(20171229);set.insert(20180102);set.insert(20180103);set.insert(20180104);set.insert(20180105);set.insert(20180108);set.insert(20180109);set.insert(20180110);set.insert(20180111);set.insert(20180112);set.insert(20180115);set.insert(20180116);set.insert(20180117);set.insert(20180118);set.insert(20180119);set.insert(20180122);set.insert(20180123);set.insert(20180124);set.insert(20180125);set.insert(20180126);set.insert(20180129);set.insert(20180130);set.insert(20180131);set.insert(20180201);set.insert(20180202);set.insert(20180205);set.insert(20180206);set.insert(20180207);set.insert(20180208);set.insert(20180209);set.insert(20180212);set.insert(20180213);set.insert(20180214);set.insert(20180222);set.insert(20180223);set.insert(20180226);set.insert(20180227);set.insert(20180228);set.insert(20180301);set.insert(20180302);set.insert(20180305);set.insert(20180306);set.insert(20180307);set.insert(20180308);set.insert(20180309);set.insert(20180312);set.insert(20180313);set.insert(20180314);set.insert(20180315);set.insert(20180316);set.insert(20180319);set.insert(20180320);set.insert(20180321);set.insert(20180322);set.insert(20180323);set.insert(20180326);set.insert(20180327);set.insert(20180328);set.insert(20180329);set.insert(20180330);set.insert(20180402);set.insert(20180403);set.insert(20180404);set.insert(20180409);set.insert(20180410);set.insert(20180411);set.insert(20180412);set.insert(20180413);set.insert(20180416);set.insert(20180417);set.insert(20180418);set.insert(20180419);set.insert(20180420);set.insert(20180423);set.insert(20180424);set.insert(20180425);set.insert(20180426);set.insert(20180427);set.insert(20180502);set.insert(20180503);set.insert(20180504);set.insert(20180507);set.insert(20180508);set.insert(20180509);set.insert(20180510);set.insert(20180511);set.insert(20180514);set.insert(20180515);set.insert(20180516);set.insert(20180517);set.insert(20180518);set.insert(20180521);set.insert(20180522);set.insert(20180523);set.insert(20180524);set.insert(20180525);set.insert(20180528);set.insert(20180529);set.insert(20180530);set.insert(20180531);set.insert(20180601);set.insert(20180604);set.insert(20180605);set.insert(20180606);set.insert(20180607);set.insert(20180608);set.insert(20180611);set.insert(20180612);set.insert(20180613);set.insert(20180614);set.insert(20180615);set.insert(20180619);set.insert(20180620);set.insert(20180621);set.insert(20180622);set.insert(20180625);set.insert(20180626);set.insert(20180627);set.insert(20180628);set.insert(20180629);set.insert(20180702);set.insert(20180703);set.insert(20180704);set.insert(20180705);set.insert(20180706);set.insert(20180709);set.insert(20180710);set.insert(20180711);set.insert(20180712);set.insert(20180713);set.insert(20180716);set.insert(20180717);set.insert(20180718);set.insert(20180719);set.insert(20180720);set.insert(20180723);set.insert(20180724);set.insert(20180725);set.insert(20180726);set.insert(20180727);set.insert(20180730);set.insert(20180731);set.insert(20180801);set.insert(20180802);set.insert(20180803);set.insert(20180806);set.insert(20180807);set.insert(20180808);set.insert(20180809);set.insert(20180810);set.insert(20180813);set.insert(20180814);set.insert(20180815);set.insert(20180816);set.insert(20180817);set.insert(20180820);set.insert(20180821);set.insert(20180822);set.insert(20180823);set.insert(20180824);set.insert(20180827);set.insert(20180828);set.insert(20180829);set.insert(20180830);set.insert(20180831);set.insert(20180903);set.insert(20180904);set.insert(20180905);set.insert(20180906);set.insert(20180907);set.insert(20180910);set.insert(20180911);set.insert(20180912);set.insert(20180913);set.insert(20180914);set.insert(20180917);set.insert(20180918);set.insert(20180919);set.insert(20180920);set.insert(20180921);set.insert(20180925);set.insert(20180926);set.insert(20180927);set.insert(20180928);set.insert(20181008);set.insert(20181009);set.insert(20181010);set.insert(20181011);set.insert(20181012);set.insert(20181015);set.insert(20181016);set.insert(20181017);set.insert(20181018);set.insert(20181019);set.insert(20181022);set.insert(20181023);set.insert(20181024);set.insert(20181025);set.insert(20181026);set.insert(20181029);set.insert(20181030);set.insert(20181031);set.insert(20181101);set.insert(20181102);set.insert(20181105);set.insert(20181106);set.insert(20181107);set.insert(20181108);set.insert(20181109);set.insert(20181112);set.insert(20181113);set.insert(20181114);set.insert(20181115);set.insert(20181116);set.insert(20181119);set.insert(20181120);set.insert(20181121);set.insert(20181122);set.insert(20181123);set.insert(20181126);set.insert(20181127);set.insert(20181128);set.insert(20181129);set.insert(20181130);set.insert(20181203);set.insert(20181204);set.insert(20181205);set.insert(20181206);set.insert(20181207);set.insert(20181210);set.insert(20181211);set.insert(20181212);set.insert(20181213);set.insert(20181214);set.insert(20181217);set.insert(20181218);set.insert(20181219);set.insert(20181220);set.insert(20181221);set.insert(20181224);set.insert(20181225);set.insert(20181226);set.insert(20181227);set.insert(20181228);set.insert(20190102);set.insert(20190103);set.insert(20190104);set.insert(20190107);set.insert(20190108);set.insert(20190109);set.insert(20190110);set.insert(20190111);set.insert(20190114);set.insert(20190115);set.insert(20190116);set.insert(20190117);set.insert(20190118);set.insert(20190121);set.insert(20190122);set.insert(20190123);set.insert(20190124);set.insert(20190125);set.insert(20190128);set.insert(20190129);set.insert(20190130);set.insert(20190131);set.insert(20190201);set.insert(20190211);set.insert(20190212);set.insert(20190213);set.insert(20190214);set.insert(20190215);set.insert(20190218);set.insert(20190219);set.insert(20190220);set.insert(20190221);set.insert(20190222);set.insert(20190225);set.insert(20190226);set.insert(20190227);set.insert(20190228);set.insert(20190301);set.insert(20190304);set.insert(20190305);set.insert(20190306);set.insert(20190307);set.insert(20190308);set.insert(20190311);set.insert(20190312);set.insert(20190313);set.insert(20190314);set.insert(20190315);set.insert(20190318);set.insert(20190319);set.insert(20190320);set.insert(20190321);set.insert(20190322);set.insert(20190325);set.insert(20190326);set.insert(20190327);set.insert(20190328);set.insert(20190329);set.insert(20190401);set.insert(20190402);set.insert(20190403);set.insert(20190404);set.insert(20190408);set.insert(20190409);set.insert(20190410);set.insert(20190411);set.insert(20190412);set.insert(20190415);set.insert(20190416);set.insert(20190417);set.insert(20190418);set.insert(20190419);set.insert(20190422);set.insert(20190423);set.insert(20190424);set.insert(20190425);set.insert(20190426);set.insert(20190429);set.insert(20190430);set.insert(20190506);set.insert(20190507);set.insert(20190508);set.insert(20190509);set.insert(20190510);set.insert(20190513);set.insert(20190514);set.insert(20190515);set.insert(20190516);set.insert(20190517);set.insert(20190520);set.insert(20190521);set.insert(20190522);set.insert(20190523);set.insert(20190524);set.insert(20190527);set.insert(20190528);set.insert(20190529);set.insert(20190530);set.insert(20190531);set.insert(20190603);set.insert(20190604);set.insert(20190605);set.insert(20190606);set.insert(20190610);set.insert(20190611);set.insert(20190612);set.insert(20190613);set.insert(20190614);set.insert(20190617);set.insert(20190618);set.insert(20190619);set.insert(20190620);set.insert(20190621);set.insert(20190624);set.insert(20190625);set.insert(20190626);set.insert(20190627);set.insert(20190628);set.insert(20190701);set.insert(20190702);set.insert(20190703);set.insert(20190704);set.insert(20190705);set.insert(20190708);set.insert(20190709);set.insert(20190710);set.insert(20190711);set.insert(20190712);set.insert(20190715);set.insert(20190716);set.insert(20190717);set.insert(20190718);set.insert(20190719);set.insert(20190722);set.insert(20190723);set.insert(20190724);set.insert(20190725);set.insert(20190726);set.insert(20190729);set.insert(20190730);set.insert(20190731);set.insert(20190801);set.insert(20190802);set.insert(20190805);set.insert(20190806);set.insert(20190807);set.insert(20190808);set.insert(20190809);set.insert(20190812);set.insert(20190813);set.insert(20190814);set.insert(20190815);set.insert(20190816);set.insert(20190819);set.insert(20190820);set.insert(20190821);set.insert(20190822);set.insert(20190823);set.insert(20190826);set.insert(20190827);set.insert(20190828);set.insert(20190829);set.insert(20190830);set.insert(20190902);set.insert(20190903);set.insert(20190904);set.insert(20190905);set.insert(20190906);set.insert(20190909);set.insert(20190910);set.insert(20190911);set.insert(20190912);set.insert(20190916);set.insert(20190917);set.insert(20190918);set.insert(20190919);set.insert(20190920);set.insert(20190923);set.insert(20190924);set.insert(20190925);set.insert(20190926);set.insert(20190927);set.insert(20190930);set.insert(20191008);set.insert(20191009);set.insert(20191010);set.insert(20191011);set.insert(20191014);set.insert(20191015);set.insert(20191016);set.insert(20191017);set.insert(20191018);set.insert(20191021);set.insert(20191022);set.insert(20191023);set.insert(20191024);set.insert(20191025);set.insert(20191028);set.insert(20191029);set.insert(20191030);set.insert(20191031);set.insert(20191101);set.insert(20191104);set.insert(20191105);set.insert(20191106);set.insert(20191107);set.insert(20191108);set.insert(20191111);set.insert(20191112);set.insert(20191113);set.insert(20191114);set.insert(20191115);set.insert(20191118);set.insert(20191119);set.insert(20191120);set.insert(20191121);set.insert(20191122);set.insert(20191125);set.insert(20191126);set.insert(20191127);set.insert(20191128);set.insert(20191129);set.insert(20191202);set.insert(20191203);set.insert(20191204);set.insert(20191205);set.insert(20191206);set.insert(20191209);set.insert(20191210);set.insert(20191211);set.insert(20191212);set.insert(20191213);set.insert(20191216);set.insert(20191217);set.insert(20191218);set.insert(20191219);set.insert(20191220);set.insert(20191223);set.insert(20191224);set.insert(20191225);set.insert(20191226);set.insert(20191227);set.insert(20191230);set.insert(20191231);set.insert(20200102);set.insert(20200103);set.insert(20200106);set.insert(20200107);set.insert(20200108);set.insert(20200109);set.insert(20200110);set.insert(20200113);set.insert(20200114);set.insert(20200115);set.insert(20200116);set.insert(20200117);set.insert(20200120);set.insert(20200121);set.insert(20200122);set.insert(20200123);set.insert(20200203);set.insert(20200204);set.insert(20200205);set.insert(20200206);set.insert(20200207);set.insert(20200210);set.insert(20200211);set.insert(20200212);set.insert(20200213);set.insert(20200214);set.insert(20200217);set.insert(20200218);set.insert(20200219);set.insert(20200220);set.insert(20200221);set.insert(20200224);set.insert(20200225);set.insert(20200226);set.insert(20200227);set.insert(20200228);set.insert(20200302);set.insert(20200303);set.insert(20200304);set.insert(20200305);set.insert(20200306);set.insert(20200309);set.insert(20200310);set.insert(20200311);set.insert(20200312);set.insert(20200313);set.insert(20200316);set.insert(20200317);set.insert(20200318);set.insert(20200319);set.insert(20200320);set.insert(20200323);set.insert(20200324);set.insert(20200325);set.insert(20200326);set.insert(20200327);set.insert(20200330);set.insert(20200331);set.insert(20200401);set.insert(20200402);set.insert(20200403);set.insert(20200407);set.insert(20200408);set.insert(20200409);set.insert(20200410);set.insert(20200413);set.insert(20200414);set.insert(20200415);set.insert(20200416);set.insert(20200417);set.insert(20200420);set.insert(20200421);set.insert(20200422);set.insert(20200423);set.insert(20200424);set.insert(20200427);set.insert(20200428);set.insert(20200429);set.insert(20200430);set.insert(20200506);set.insert(20200507);set.insert(20200508);set.insert(20200511);set.insert(20200512);set.insert(20200513);set.insert(20200514);set.insert(20200515);set.insert(20200518);set.insert(20200519);set.insert(20200520);set.insert(20200521);set.insert(20200522);set.insert(20200525);set.insert(20200526);set.insert(20200527);set.insert(20200528);set.insert(20200529);set.insert(20200601);set.insert(20200602);set.insert(20200603);set.insert(20200604);set.insert(20200605);set.insert(20200608);set.insert(20200609);set.insert(20200610);set.insert(20200611);set.insert(20200612);set.insert(20200615);set.insert(20200616);set.insert(20200617);set.insert(20200618);set.insert(20200619);set.insert(20200622);set.insert(20200623);set.insert(20200624);set.insert(20200629);set.insert(20200630);set.insert(20200701);set.insert(20200702);set.insert(20200703);set.insert(20200706);set.insert(20200707);set.insert(20200708);set.insert(20200709);set.insert(20200710);set.insert(20200713);set.insert(20200714);set.insert(20200715);set.insert(20200716);set.insert(20200717);set.insert(20200720);set.insert(20200721);set.insert(20200722);set.insert(20200723);set.insert(20200724);set.insert(20200727);set.insert(20200728);set.insert(20200729);set.insert(20200730);set.insert(20200731);set.insert(20200803);set.insert(20200804);set.insert(20200805);set.insert(20200806);set.insert(20200807);set.insert(20200810);set.insert(20200811);set.insert(20200812);set.insert(20200813);set.insert(20200814);set.insert(20200817);set.insert(20200818);set.insert(20200819);set.insert(20200820);set.insert(20200821);set.insert(20200824);set.insert(20200825);set.insert(20200826);set.insert(20200827);set.insert(20200828);set.insert(20200831);set.insert(20200901);set.insert(20200902);set.insert(20200903);set.insert(20200904);set.insert(20200907);set.insert(20200908);set.insert(20200909);set.insert(20200910);set.insert(20200911);set.insert(20200914);set.insert(20200915);set.insert(20200916);set.insert(20200917);set.insert(20200918);set.insert(20200921);set.insert(20200922);set.insert(20200923);set.insert(20200924);set.insert(20200925);set.insert(20200928);set.insert(20200929);set.insert(20200930);set.insert(20201009);set.insert(20201012);set.insert(20201013);set.insert(20201014);set.insert(20201015);set.insert(20201016);set.insert(20201019);set.insert(20201020);set.insert(20201021);set.insert(20201022);set.insert(20201023);set.insert(20201026);set.insert(20201027);set.insert(20201028);set.insert(20201029);set.insert(20201030);set.insert(20201102);set.insert(20201103);set.insert(20201104);set.insert(20201105);set.insert(20201106);set.insert(20201109);set.insert(20201110);set.insert(20201111);set.insert(20201112);set.insert(20201113);set.insert(20201116);set.insert(20201117);set.insert(20201118);set.insert(20201119);set.insert(20201120);set.insert(20201123);set.insert(20201124);set.insert(20201125);set.insert(20201126);set.insert(20201127);set.insert(20201130);set.insert(20201201);set.insert(20201202);set.insert(20201203);set.insert(20201204);set.insert(20201207);set.insert(20201208);set.insert(20201209);set.insert(20201210);set.insert(20201211);set.insert(20201214);set.insert(20201215);set.insert(20201216);set.insert(20201217);set.insert(20201218);set.insert(20201221);set.insert(20201222);set.insert(20201223);set.insert(20201224);set.insert(20201225);set.insert(20201228);set.insert(20201229);set.insert(20201230);set.insert(20201231);set.insert(20210104);set.insert(20210105);set.insert(20210106);set.insert(20210107);set.insert(20210108);set.insert(20210111);set.insert(20210112);set.insert(20210113);set.insert(20210114);set.insert(20210115);set.insert(20210118);set.insert(20210119);set.insert(20210120);set.insert(20210121);set.insert(20210122);set.insert(20210125);set.insert(20210126);set.insert(20210127);set.insert(20210128);set.insert(20210129);set.insert(20210201);set.insert(20210202);set.insert(20210203);set.insert(20210204);set.insert(20210205);set.insert(20210208);set.insert(20210209);set.insert(20210210);set.insert(20210218);set.insert(20210219);set.insert(20210222);set.insert(20210223);set.insert(20210224);set.insert(20210225);set.insert(20210226);set.insert(20210301);set.insert(20210302);set.insert(20210303);set.insert(20210304);set.insert(20210305);set.insert(20210308);set.insert(20210309);set.insert(20210310);set.insert(20210311);set.insert(20210312);set.insert(20210315);set.insert(20210316);set.insert(20210317);set.insert(20210318);set.insert(20210319);set.insert(20210322);set.insert(20210323);set.insert(20210324);set.insert(20210325);set.insert(20210326);set.insert(20210329);set.insert(20210330);set.insert(20210331);set.insert(20210401);set.insert(20210402);set.insert(20210406);set.insert(20210407);set.insert(20210408);set.insert(20210409);set.insert(20210412);set.insert(20210413);set.insert(20210414);set.insert(20210415);set.insert(20210416);set.insert(20210419);set.insert(20210420);set.insert(20210421);set.insert(20210422);set.insert(20210423);set.insert(20210426);set.insert(20210427);set.insert(20210428);set.insert(20210429);set.insert(20210430);set.insert(20210506);set.insert(20210507);set.insert(20210510);set.insert(20210511);set.insert(20210512);set.insert(20210513);set.insert(20210514);set.insert(20210517);set.insert(20210518);set.insert(20210519);set.insert(20210520);set.insert(20210521);set.insert(20210524);set.insert(20210525);set.insert(20210526);set.insert(20210527);set.insert(20210528);set.insert(20210531);set.insert(20210601);set.insert(20210602);set.insert(20210603);set.insert(20210604);set.insert(20210607);set.insert(20210608);set.insert(20210609);set.insert(20210610);set.insert(20210611);set.insert(20210615);set.insert(20210616);set.insert(20210617);set.insert(20210618);set.insert(20210621);set.insert(20210622);set.insert(20210623);set.insert(20210624);set.insert(20210625);set.insert(20210628);set.insert(20210629);set.insert(20210630);set.insert(20210701);set.insert(20210702);set.insert(20210705);set.insert(20210706);set.insert(20210707);set.insert(20210708);set.insert(20210709);set.insert(20210712);set.insert(20210713);set.insert(20210714);set.insert(20210715);set.insert(20210716);set.insert(20210719);set.insert(20210720);set.insert(20210721);set.insert(20210722);set.insert(20210723);set.insert(20210726);set.insert(20210727);set.insert(20210728);set.insert(20210729);set.insert(20210730);set.insert(20210802);set.insert(20210803);set.insert(20210804);set.insert(20210805);set.insert(20210806);set.insert(20210809);set.insert(20210810);set.insert(20210811);set.insert(20210812);set.insert(20210813);set.insert(20210816);set.insert(20210817);set.insert(20210818);set.insert(20210819);set.insert(20210820);set.insert(20210823);set.insert(20210824);set.insert(20210825);set.insert(20210826);set.insert(20210827);set.insert(20210830);set.insert(20210831);set.insert(20210901);set.insert(20210902);set.insert(20210903);set.insert(20210906);set.insert(20210907);set.insert(20210908);set.insert(20210909);set.insert(20210910);set.insert(20210913);set.insert(20210914);set.insert(20210915);set.insert(20210916);set.insert(20210917);set.insert(20210922);set.insert(20210923);set.insert(20210924);set.insert(20210927);set.insert(20210928);set.insert(20210929);set.insert(20210930);set.insert(20211008);set.insert(20211011);set.insert(20211012);set.insert(20211013);set.insert(20211014);set.insert(20211015);set.insert(20211018);set.insert(20211019);set.insert(20211020);set.insert(20211021);set.insert(20211022);set.insert(20211025);set.insert(20211026);set.insert(20211027);set.insert(20211028);set.insert(20211029);set.insert(20211101);set.insert(20211102);set.insert(20211103);set.insert(20211104);set.insert(20211105);set.insert(20211108);set.insert(20211109);set.insert(20211110);set.insert(20211111);set.insert(20211112);set.insert(20211115);set.insert(20211116);set.insert(20211117);set.insert(20211118);set.insert(20211119);set.insert(20211122);set.insert(20211123);set.insert(20211124);set.insert(20211125);set.insert(20211126);set.insert(20211129);set.insert(20211130);set.insert(20211201);set.insert(20211202);set.insert(20211203);set.insert(20211206);set.insert(20211207);set.insert(20211208);set.insert(20211209);set.insert(20211210);set.insert(20211213);set.insert(20211214);set.insert(20211215);set.insert(20211216);set.insert(20211217);set.insert(20211220);set.insert(20211221);set.insert(20211222);set.insert(20211223);set.insert(20211224);set.insert(20211227);set.insert(20211228);set.insert(20211229);set.insert(20211230);set.insert(20211231);set.insert(20220104);set.insert(20220105);set.insert(20220106);set.insert(20220107);set.insert(20220110);set.insert(20220111);set.insert(20220112);set.insert(20220113);set.insert(20220114);set.insert(20220117);set.insert(20220118);set.insert(20220119);set.insert(20220120);set.insert(20220121);set.insert(20220124);set.insert(20220125);set.insert(20220126);set.insert(20220127);set.insert(20220128);set.insert(20220207);set.insert(20220208);set.insert(20220209);set.insert(20220210);set.insert(20220211);set.insert(20220214);set.insert(20220215);set.insert(20220216);set.insert(20220217);set.insert(20220218);set.insert(20220221);set.insert(20220222);set.insert(20220223);set.insert(20220224);set.insert(20220225);set.insert(20220228);set.insert(20220301);set.insert(20220302);set.insert(20220303);set.insert(20220304);set.insert(20220307);set.insert(20220308);set.insert(20220309);set.insert(20220310);set.insert(20220311);set.insert(20220314);set.insert(20220315);set.insert(20220316);set.insert(20220317);set.insert(20220318);set.insert(20220321);set.insert(20220322);set.insert(20220323);set.insert(20220324);set.insert(20220325);set.insert(20220328);set.insert(20220329);set.insert(20220330);set.insert(20220331);set.insert(20220401);set.insert(20220406);set.insert(20220407);set.insert(20220408);set.insert(20220411);set.insert(20220412);set.insert(20220413);set.insert(20220414);set.insert(20220415);set.insert(20220418);set.insert(20220419);set.insert(20220420);set.insert(20220421);set.insert(20220422);set.insert(20220425);set.insert(20220426);set.insert(20220427);set.insert(20220428);set.insert(20220429);set.insert(20220505);set.insert(20220506);set.insert(20220509);set.insert(20220510);set.insert(20220511);set.insert(20220512);set.insert(20220513);set.insert(20220516);set.insert(20220517);set.insert(20220518);set.insert(20220519);set.insert(20220520);set.insert(20220523);set.insert(20220524);set.insert(20220525);set.insert(20220526);set.insert(20220527);set.insert(20220530);set.insert(20220531);set.insert(20220601);set.insert(20220602);set.insert(20220606);set.insert(20220607);set.insert(20220608);set.insert(20220609);set.insert(20220610);set.insert(20220613);set.insert(20220614);set.insert(20220615);set.insert(20220616);set.insert(20220617);set.insert(20220620);set.insert(20220621);set.insert(20220622);set.insert(20220623);set.insert(20220624);set.insert(20220627);set.insert(20220628);set.insert(20220629);set.insert(20220630);set.insert(20220701);set.insert(20220704);set.insert(20220705);set.insert(20220706);set.insert(20220707);set.insert(20220708);set.insert(20220711);set.insert(20220712);set.insert(20220713);set.insert(20220714);set.insert(20220715);set.insert(20220718);set.insert(20220719);set.insert(20220720);set.insert(20220721);set.insert(20220722);set.insert(20220725);set.insert(20220726);set.insert(20220727);set.insert(20220728);set.insert(20220729);set.insert(20220801);set.insert(20220802);set.insert(20220803);set.insert(20220804);set.insert(20220805);set.insert(20220808);set.insert(20220809);set.insert(20220810);set.insert(20220811);set.insert(20220812);set.insert(20220815);set.insert(20220816);set.insert(20220817);set.insert(20220818);set.insert(20220819);set.insert(20220822);set.insert(20220823);set.insert(20220824);set.insert(20220825);set.insert(20220826);set.insert(20220829);set.insert(20220830);set.insert(20220831);set.insert(20220901);set.insert(20220902);set.insert(20220905);set.insert(20220906);set.insert(20220907);set.insert(20220908);set.insert(20220909);set.insert(20220913);set.insert(20220914);set.insert(20220915);set.insert(20220916);set.insert(20220919);set.insert(20220920);set.insert(20220921);set.insert(20220922);set.insert(20220923);set.insert(20220926);set.insert(20220927);set.insert(20220928);set.insert(20220929);set.insert(20220930);set.insert(20221010);set.insert(20221011);set.insert(20221012);set.insert(20221013);set.insert(20221014);set.insert(20221017);set.insert(20221018);set.insert(20221019);set.insert(20221020);set.insert(20221021);set.insert(20221024);set.insert(20221025);set.insert(20221026);set.insert(20221027);set.insert(20221028);set.insert(20221031);set.insert(20221101);set.insert(20221102);set.insert(20221103);set.insert(20221104);set.insert(20221107);set.insert(20221108);set.insert(20221109);set.insert(20221110);set.insert(20221111);set.insert(20221114);set.insert(20221115);set.insert(20221116);set.insert(20221117);set.insert(20221118);set.insert(20221121);set.insert(20221122);set.insert(20221123);set.insert(20221124);set.insert(20221125);set.insert(20221128);set.insert(20221129);set.insert(20221130);set.insert(20221201);set.insert(20221202);set.insert(20221205);set.insert(20221206);set.insert(20221207);set.insert(20221208);set.insert(20221209);set.insert(20221212);set.insert(20221213);set.insert(20221214);set.insert(20221215);set.insert(20221216);set.insert(20221219);set.insert(20221220);set.insert(20221221);set.insert(20221222);set.insert(20221223);set.insert(20221226);set.insert(20221227);set.insert(20221228);set.insert(20221229);set.insert(20221230);
-        RwLock::new(set)
-    };
-}
-
-#[allow(dead_code)]
-pub fn is_trade_date(date: &NaiveDate) -> bool {
-    let mut reset = false;
-    let test_day: i32 = date.format("%Y%m%d").to_string().parse().unwrap();
-    {
-        let trade_days = TRADE_DAYS.read().unwrap();
-        let is_trade_day = trade_days.contains(&test_day);
-        if is_trade_day {
-            return true;
-        }
-        if !is_trade_day {
-            let n = Local::now().naive_local();
-            let n_year = n.year();
-            if trade_days.is_empty() {
-                reset = true;
-            } else {
-                let latest_year = *trade_days.iter().next_back().unwrap() / 10000;
-                if n_year > latest_year {
-                    reset = true;
-                }
-            }
-        }
-        // trade_days not send
-    }
-    if reset {
-        let path = std::env::var("BBQ_TRADE_DATE");
-        if path.is_err() {
-            return false;
-        }
-        let s = std::fs::read_to_string(path.unwrap()).unwrap_or("".to_string());
-        if s.is_empty() {
-            return false;
-        }
-
-        let mut tmp_set = BTreeSet::new();
-        for it in s.split(",") {
-            let day: i32 = it.parse().unwrap_or(-1);
-            if day < 0 {
-                return false;
-            }
-            tmp_set.insert(day);
-        }
-        let mut trade_days = TRADE_DAYS.write().unwrap();
-        trade_days.clear();
-        trade_days.extend(tmp_set.into_iter());
-        return trade_days.contains(&test_day);
-    }
-    false
-}
-
-#[cfg(test)]
-mod test {
-    #[test]
-    fn test_is_trade_date() {
-        use super::is_trade_date;
-        use chrono::NaiveDate;
-
-        std::env::set_var("BBQ_TRADE_DATE", "src/fetch/trade_date.txt");
-        let date: NaiveDate = NaiveDate::parse_from_str("2022-03-02", "%Y-%m-%d").unwrap();
-        assert!(is_trade_date(&date));
-
-        let date: NaiveDate = NaiveDate::parse_from_str("2022-03-07", "%Y-%m-%d").unwrap();
-        assert!(is_trade_date(&date));
-
-        let date: NaiveDate = NaiveDate::parse_from_str("2022-03-06", "%Y-%m-%d").unwrap();
-        assert!(!is_trade_date(&date));
-    }
-}
@@ -0,0 +1,440 @@
+use crate::fetch::SecurityInfo;
+use crate::{OrderType, Price, QuotData, Signal, SignalType};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// 交易所
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Exchange {
+    #[default]
+    Sh,
+    Sz,
+    Bj,
+}
+
+impl Display for Exchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            Exchange::Sh => "上交所",
+            Exchange::Sz => "深交所",
+            Exchange::Bj => "北交所",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl Exchange {
+    /// 代码前缀, 如 `sh`
+    pub fn prefix(&self) -> &'static str {
+        match &self {
+            Exchange::Sh => "sh",
+            Exchange::Sz => "sz",
+            Exchange::Bj => "bj",
+        }
+    }
+}
+
+/// 板块
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Board {
+    #[default]
+    Main,
+    Star,
+    ChiNext,
+    Bj,
+    Fund,
+    Bond,
+    Index,
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            Board::Main => "主板",
+            Board::Star => "科创板",
+            Board::ChiNext => "创业板",
+            Board::Bj => "北交所",
+            Board::Fund => "场内基金",
+            Board::Bond => "可转债",
+            Board::Index => "指数",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 交易品种及其交易规则
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct Instrument {
+    // 代码, 统一为 sh600063 格式
+    pub code: String,
+    // 不带交易所前缀的代码
+    pub symbol: String,
+    pub exchange: Exchange,
+    pub board: Board,
+    // 最小买入数量
+    pub min_volume: u32,
+    // 买入数量递增单位
+    pub lot: u32,
+    // 最小价格变动单位
+    pub tick: Price,
+    // 涨跌幅限制, 0 为不限制
+    pub limit_pct: f64,
+    // 停牌
+    pub suspended: bool,
+}
+
+impl Instrument {
+    /// 解析代码, 支持 `sh600063`, `SH600063`, `sh.600063`, `600063.SH`, `600063`
+    pub fn parse(code: &str) -> Result<Self> {
+        let code = code.trim().to_lowercase();
+        let (exchange, symbol) = if let Some((symbol, market)) = code.split_once('.') {
+            if symbol.chars().all(|c| c.is_ascii_digit()) {
+                (Some(market.to_string()), symbol.to_string())
+            } else {
+                (Some(symbol.to_string()), market.to_string())
+            }
+        } else if code.len() > 2
+            && code.is_char_boundary(2)
+            && !code[..2].chars().all(|c| c.is_ascii_digit())
+        {
+            (Some(code[..2].to_string()), code[2..].to_string())
+        } else {
+            (None, code.clone())
+        };
+
+        if symbol.len() != 6 || !symbol.chars().all(|c| c.is_ascii_digit()) {
+            bail!("invalid code: {}", code);
+        }
+        let exchange = match exchange.as_deref() {
+            Some("sh") | Some("ss") => Exchange::Sh,
+            Some("sz") => Exchange::Sz,
+            Some("bj") => Exchange::Bj,
+            Some(v) => bail!("invalid exchange: {}, code: {}", v, code),
+            None => match &symbol[..1] {
+                "5" | "6" | "9" => Exchange::Sh,
+                "4" | "8" => Exchange::Bj,
+                _ if symbol.starts_with("11") => Exchange::Sh,
+                _ => Exchange::Sz,
+            },
+        };
+
+        let board = match exchange {
+            Exchange::Sh if symbol.starts_with("688") || symbol.starts_with("689") => Board::Star,
+            Exchange::Sh if symbol.starts_with("000") => Board::Index,
+            Exchange::Sh if symbol.starts_with("11") => Board::Bond,
+            Exchange::Sh if symbol.starts_with('5') => Board::Fund,
+            Exchange::Sz if symbol.starts_with("300") || symbol.starts_with("301") => {
+                Board::ChiNext
+            }
+            Exchange::Sz if symbol.starts_with("399") => Board::Index,
+            Exchange::Sz if symbol.starts_with("12") => Board::Bond,
+            Exchange::Sz if symbol.starts_with("15") || symbol.starts_with("16") => Board::Fund,
+            Exchange::Bj => Board::Bj,
+            _ => Board::Main,
+        };
+
+        let (min_volume, lot, tick, limit_pct) = match board {
+            Board::Main => (100, 100, 0.01, 0.1),
+            Board::Star => (200, 1, 0.01, 0.2),
+            Board::ChiNext => (100, 100, 0.01, 0.2),
+            Board::Bj => (100, 1, 0.01, 0.3),
+            Board::Fund => (100, 100, 0.001, 0.1),
+            Board::Bond => (10, 10, 0.001, 0.2),
+            Board::Index => (0, 0, 0.01, 0.0),
+        };
+
+        Ok(Self {
+            code: format!("{}{}", exchange.prefix(), &symbol),
+            symbol,
+            exchange,
+            board,
+            min_volume,
+            lot,
+            tick: Price::from(tick),
+            limit_pct,
+            suspended: false,
+        })
+    }
+
+    /// 按证券信息生成交易规则, 主板 ST 股票涨跌幅为 5%
+    pub fn from_info(info: &SecurityInfo) -> Result<Self> {
+        let mut instrument = Self::parse(&info.code)?;
+        if info.st && instrument.board == Board::Main {
+            instrument.limit_pct = 0.05;
+        }
+        instrument.suspended = info.suspended;
+        Ok(instrument)
+    }
+
+    pub fn is_tradable(&self) -> bool {
+        self.board != Board::Index && !self.suspended
+    }
+
+    /// 价格按最小变动单位四舍五入
+    pub fn round_price(&self, price: Price) -> Price {
+        price.round_to(self.tick)
+    }
+
+    /// 买入数量按交易单位向下取整, 不足最小买入数量返回 0
+    pub fn round_volume(&self, volume: u32) -> u32 {
+        if volume < self.min_volume || self.lot == 0 {
+            return 0;
+        }
+        self.min_volume + (volume - self.min_volume) / self.lot * self.lot
+    }
+
+    /// 涨跌停价格(跌停, 涨停)
+    pub fn price_limit(&self, pre_close: Price) -> Option<(Price, Price)> {
+        if self.limit_pct <= 0.0 || pre_close <= Price::ZERO {
+            return None;
+        }
+        Some((
+            self.round_price(pre_close * (1.0 - self.limit_pct)),
+            self.round_price(pre_close * (1.0 + self.limit_pct)),
+        ))
+    }
+}
+
+/// 品种注册表, 按代码缓存交易规则, 并记录行情昨收用于涨跌停检查
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentRegistry {
+    instruments: HashMap<String, Instrument>,
+    pre_close: HashMap<String, Price>,
+}
+
+impl InstrumentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册/覆盖交易规则, 如 ST 股票涨跌幅为 5%
+    pub fn register(&mut self, instrument: Instrument) {
+        self.instruments.insert(instrument.code.clone(), instrument);
+    }
+
+    /// 按证券列表注册交易规则, 返回注册数量, 无法解析的代码忽略
+    pub fn register_list(&mut self, list: &[SecurityInfo]) -> usize {
+        let mut count = 0;
+        for info in list {
+            if let Ok(instrument) = Instrument::from_info(info) {
+                self.register(instrument);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn get(&self, code: &str) -> Result<Instrument> {
+        if let Some(instrument) = self.instruments.get(code) {
+            return Ok(instrument.clone());
+        }
+        let instrument = Instrument::parse(code)?;
+        Ok(self
+            .instruments
+            .get(&instrument.code)
+            .cloned()
+            .unwrap_or(instrument))
+    }
+
+    pub fn pre_close(&self, code: &str) -> Option<Price> {
+        self.pre_close.get(code).copied()
+    }
+
+    pub fn on_quot(&mut self, quot: &QuotData) {
+        if let QuotData::Quot(bars) = quot {
+            for (code, bar) in bars.iter() {
+                if bar.quot.pre_close > Price::ZERO {
+                    self.pre_close.insert(code.clone(), bar.quot.pre_close);
+                }
+            }
+        }
+    }
+
+    /// 按交易规则检查信号, 价格按最小变动单位取整, 数量按交易单位向下取整,
+    /// 不可交易品种/数量不足/超出涨跌停的信号返回错误
+    ///
+    /// `available` 为可卖数量, 卖出全部可卖数量时允许零股
+    pub fn check_signal(&self, signal: &Signal, available: u32) -> Result<Signal> {
+        let mut signal = signal.clone();
+        if let SignalType::Cancel = signal.signal {
+            return Ok(signal);
+        }
+        let instrument = self.get(&signal.code)?;
+        if instrument.suspended {
+            bail!("{} is suspended", &signal.code);
+        }
+        if !instrument.is_tradable() {
+            bail!("{}({}) is not tradable", &signal.code, &instrument.board);
+        }
+
+        if signal.price > Price::ZERO {
+            signal.price = instrument.round_price(signal.price);
+        }
+        if signal.stop_price > Price::ZERO {
+            signal.stop_price = instrument.round_price(signal.stop_price);
+        }
+
+        let volume = match signal.signal {
+            SignalType::Sell if signal.volume == available => signal.volume,
+            SignalType::Sell => signal.volume / instrument.lot.max(1) * instrument.lot.max(1),
+            _ => instrument.round_volume(signal.volume),
+        };
+        if volume == 0 {
+            bail!(
+                "{} volume {} less than min volume {}",
+                &signal.code,
+                signal.volume,
+                instrument.min_volume
+            );
+        }
+        signal.volume = volume;
+
+        let limit = self
+            .pre_close(&instrument.code)
+            .and_then(|pre_close| instrument.price_limit(pre_close));
+        if let Some((down, up)) = limit {
+            let check_price = match signal.order_type {
+                OrderType::Limit | OrderType::StopLimit => Some(signal.price),
+                _ => None,
+            };
+            if let Some(price) = check_price {
+                if price < down || price > up {
+                    bail!(
+                        "{} price {} out of limit [{}, {}]",
+                        &signal.code,
+                        price,
+                        down,
+                        up
+                    );
+                }
+            }
+        }
+        Ok(signal)
+    }
+}
+
+#[cfg(test)]
+mod test_instrument {
+    use super::{Board, Exchange, Instrument, InstrumentRegistry};
+    use crate::fetch::{Quot, SecurityInfo};
+    use crate::{Price, QuotBar, QuotData, RtQuotBar, Signal, SignalType};
+
+    #[test]
+    fn test_parse() {
+        let a = Instrument::parse("sh600063").unwrap();
+        let b = Instrument::parse("600063.SH").unwrap();
+        let c = Instrument::parse("600063").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_eq!(a.exchange, Exchange::Sh);
+        assert_eq!(a.board, Board::Main);
+
+        assert_eq!(Instrument::parse("688981.SH").unwrap().board, Board::Star);
+        assert_eq!(Instrument::parse("sz300750").unwrap().board, Board::ChiNext);
+        assert_eq!(Instrument::parse("BJ430047").unwrap().board, Board::Bj);
+        assert_eq!(Instrument::parse("sh510300").unwrap().board, Board::Fund);
+        assert_eq!(Instrument::parse("sz123001").unwrap().board, Board::Bond);
+        assert_eq!(Instrument::parse("sh000001").unwrap().board, Board::Index);
+        assert!(Instrument::parse("sh6000").is_err());
+        assert!(Instrument::parse("hk00700").is_err());
+
+        let star = Instrument::parse("sh688981").unwrap();
+        assert_eq!(star.round_volume(150), 0);
+        assert_eq!(star.round_volume(201), 201);
+        assert_eq!(a.round_volume(250), 200);
+        assert_eq!(
+            a.price_limit(Price::from(12.34)),
+            Some((Price::from(11.11), Price::from(13.57)))
+        );
+    }
+
+    #[test]
+    fn test_check_signal() {
+        let mut registry = InstrumentRegistry::new();
+        let mut bars = RtQuotBar::new();
+        bars.insert(
+            "sh600063".to_string(),
+            QuotBar {
+                frequency: 60,
+                open: Price::from(10.0),
+                high: Price::from(10.0),
+                low: Price::from(10.0),
+                close: Price::from(10.0),
+                start: Default::default(),
+                end: Default::default(),
+                quot: Quot {
+                    pre_close: Price::from(10.0),
+                    ..Default::default()
+                },
+                corp_action: None,
+            },
+        );
+        registry.on_quot(&QuotData::Quot(bars));
+
+        let signal = Signal {
+            signal: SignalType::Buy,
+            code: "sh600063".to_string(),
+            price: Price::from(10.123),
+            volume: 250,
+            ..Default::default()
+        };
+        let s = registry.check_signal(&signal, 0).unwrap();
+        assert_eq!(s.price, Price::from(10.12));
+        assert_eq!(s.volume, 200);
+
+        let s = Signal {
+            price: Price::from(11.5),
+            ..signal.clone()
+        };
+        assert!(registry.check_signal(&s, 0).is_err());
+        let s = Signal {
+            volume: 50,
+            ..signal.clone()
+        };
+        assert!(registry.check_signal(&s, 0).is_err());
+
+        // 卖出全部允许零股
+        let s = Signal {
+            signal: SignalType::Sell,
+            volume: 150,
+            ..signal.clone()
+        };
+        assert_eq!(registry.check_signal(&s, 150).unwrap().volume, 150);
+        assert_eq!(registry.check_signal(&s, 300).unwrap().volume, 100);
+    }
+
+    #[test]
+    fn test_register_list() {
+        let info = |code: &str, st: bool, suspended: bool| SecurityInfo {
+            code: code.to_string(),
+            st,
+            suspended,
+            ..Default::default()
+        };
+        let mut registry = InstrumentRegistry::new();
+        let count = registry.register_list(&[
+            info("sh600063", true, false),
+            info("sz300750", true, false),
+            info("sz000001", false, true),
+            info("hk00700", false, false),
+        ]);
+        assert_eq!(count, 3);
+        assert_eq!(registry.get("sh600063").unwrap().limit_pct, 0.05);
+        assert_eq!(registry.get("sz300750").unwrap().limit_pct, 0.2);
+        assert!(!registry.get("sz000001").unwrap().is_tradable());
+
+        let signal = Signal {
+            signal: SignalType::Buy,
+            code: "sz000001".to_string(),
+            price: Price::from(10.0),
+            volume: 100,
+            ..Default::default()
+        };
+        assert!(registry.check_signal(&signal, 0).is_err());
+    }
+}
@@ -8,10 +8,23 @@ pub use trader::*;
 mod consts;
 pub use consts::*;
 
+pub mod money;
+pub use money::*;
+
+pub mod instrument;
+pub use instrument::*;
+
+pub mod calendar;
+pub use calendar::*;
+
+pub mod time_fmt;
+
 mod proto;
 
 pub mod data;
 
+pub mod analytics;
+
 pub fn setup_log( file: &str, level: &str) -> Result<()> {
     use std::str::FromStr;
 
@@ -0,0 +1,345 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Debug, Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// 定点数小数位数
+pub const DECIMAL_PLACES: usize = 4;
+/// 定点数放大倍数
+pub const DECIMAL_SCALE: i64 = 10_000;
+
+/// 按 `part / total` 比例分摊, 四舍五入
+fn prorate(raw: i64, part: u32, total: u32) -> i64 {
+    if total == 0 {
+        return 0;
+    }
+    let num = raw as i128 * part as i128;
+    let den = total as i128;
+    let half = den / 2;
+    let v = if num >= 0 {
+        (num + half) / den
+    } else {
+        (num - half) / den
+    };
+    v as i64
+}
+
+/// 解析十进制字符串, 超出精度部分四舍五入, 不经过浮点
+fn parse_decimal(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (int, frac) = match s.split_once('.') {
+        Some((int, frac)) => (int, frac),
+        None => (s, ""),
+    };
+    if (int.is_empty() && frac.is_empty())
+        || !int.chars().all(|c| c.is_ascii_digit())
+        || !frac.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let mut v: i64 = if int.is_empty() { 0 } else { int.parse().ok()? };
+    v = v.checked_mul(DECIMAL_SCALE)?;
+    let digits: Vec<i64> = frac.bytes().map(|b| (b - b'0') as i64).collect();
+    let mut f = 0;
+    for i in 0..DECIMAL_PLACES {
+        f = f * 10 + digits.get(i).copied().unwrap_or(0);
+    }
+    if digits.get(DECIMAL_PLACES).copied().unwrap_or(0) >= 5 {
+        f += 1;
+    }
+    v = v.checked_add(f)?;
+    Some(if neg { -v } else { v })
+}
+
+/// 浮点转定点, 四舍五入
+fn from_f64(v: f64) -> i64 {
+    (v * DECIMAL_SCALE as f64).round() as i64
+}
+
+macro_rules! decimal_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(i64);
+
+        impl $name {
+            pub const ZERO: $name = $name(0);
+
+            /// 以最小单位(万分之一元)构造
+            pub const fn from_raw(raw: i64) -> Self {
+                Self(raw)
+            }
+
+            /// 最小单位(万分之一元)数值
+            pub const fn raw(&self) -> i64 {
+                self.0
+            }
+
+            pub fn from_f64(v: f64) -> Self {
+                Self(from_f64(v))
+            }
+
+            pub fn to_f64(&self) -> f64 {
+                self.0 as f64 / DECIMAL_SCALE as f64
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn abs(&self) -> Self {
+                Self(self.0.abs())
+            }
+
+            /// 按最小变动单位 `unit` 四舍五入
+            pub fn round_to(&self, unit: Self) -> Self {
+                if unit.0 <= 0 {
+                    return *self;
+                }
+                let v = prorate(self.0, 1, unit.0 as u32);
+                Self(v * unit.0)
+            }
+
+            /// 按 `part / total` 比例分摊, 四舍五入
+            pub fn prorate(&self, part: u32, total: u32) -> Self {
+                Self(prorate(self.0, part, total))
+            }
+        }
+
+        impl From<f64> for $name {
+            fn from(v: f64) -> Self {
+                Self::from_f64(v)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_decimal(s)
+                    .map(Self)
+                    .ok_or_else(|| anyhow::anyhow!("invalid decimal: {}", s))
+            }
+        }
+
+        impl Display for $name {
+            /// 未指定精度时输出完整小数(去除末尾0), 指定精度时按浮点格式化
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                if f.precision().is_some() {
+                    return Display::fmt(&self.to_f64(), f);
+                }
+                let sign = if self.0 < 0 { "-" } else { "" };
+                let v = self.0.unsigned_abs();
+                let scale = DECIMAL_SCALE as u64;
+                let frac = format!("{:0width$}", v % scale, width = DECIMAL_PLACES);
+                let frac = frac.trim_end_matches('0');
+                if frac.is_empty() {
+                    write!(f, "{}{}", sign, v / scale)
+                } else {
+                    write!(f, "{}{}.{}", sign, v / scale, frac)
+                }
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        /// 乘以比例(费率, 除权比例等), 四舍五入
+        impl Mul<f64> for $name {
+            type Output = Self;
+            fn mul(self, rhs: f64) -> Self {
+                Self((self.0 as f64 * rhs).round() as i64)
+            }
+        }
+
+        /// 两数之比
+        impl Div for $name {
+            type Output = f64;
+            fn div(self, rhs: Self) -> f64 {
+                self.0 as f64 / rhs.0 as f64
+            }
+        }
+
+        impl Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self(iter.map(|v| v.0).sum())
+            }
+        }
+
+        /// 文本格式(json/toml/bson)序列化为数值, 输出文本即精确小数;
+        /// 二进制格式(bincode)序列化为最小单位整数
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_f64(self.to_f64())
+                } else {
+                    serializer.serialize_i64(self.0)
+                }
+            }
+        }
+
+        /// 文本格式兼容数值及十进制字符串
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                if !deserializer.is_human_readable() {
+                    return i64::deserialize(deserializer).map(Self);
+                }
+                struct DecimalVisitor;
+
+                impl<'de> de::Visitor<'de> for DecimalVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                        write!(f, "a decimal number or string")
+                    }
+
+                    fn visit_i64<E: de::Error>(self, v: i64) -> Result<$name, E> {
+                        v.checked_mul(DECIMAL_SCALE)
+                            .map($name)
+                            .ok_or_else(|| E::custom("decimal overflow"))
+                    }
+
+                    fn visit_u64<E: de::Error>(self, v: u64) -> Result<$name, E> {
+                        i64::try_from(v)
+                            .ok()
+                            .and_then(|v| v.checked_mul(DECIMAL_SCALE))
+                            .map($name)
+                            .ok_or_else(|| E::custom("decimal overflow"))
+                    }
+
+                    fn visit_f64<E: de::Error>(self, v: f64) -> Result<$name, E> {
+                        Ok($name::from_f64(v))
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<$name, E> {
+                        parse_decimal(v)
+                            .map($name)
+                            .ok_or_else(|| E::custom(format!("invalid decimal: {}", v)))
+                    }
+                }
+
+                deserializer.deserialize_any(DecimalVisitor)
+            }
+        }
+    };
+}
+
+decimal_type!(
+    /// 价格(定点, 精确到万分之一元)
+    Price
+);
+
+decimal_type!(
+    /// 金额(定点, 精确到万分之一元)
+    Money
+);
+
+impl Money {
+    /// 四舍五入到分, 与券商对账单一致
+    pub fn round_cent(&self) -> Self {
+        self.round_to(Money::from_raw(DECIMAL_SCALE / 100))
+    }
+
+    /// 按数量计算单价
+    pub fn per(&self, volume: u32) -> Price {
+        Price(prorate(self.0, 1, volume))
+    }
+}
+
+/// 成交金额 = 价格 * 数量
+impl Mul<u32> for Price {
+    type Output = Money;
+    fn mul(self, volume: u32) -> Money {
+        Money(self.0 * volume as i64)
+    }
+}
+
+impl Price {
+    /// 每股价格对应的金额(如每股派现)
+    pub fn to_money(&self) -> Money {
+        Money(self.0)
+    }
+}
+
+#[cfg(test)]
+mod test_money {
+    use super::{Money, Price};
+
+    #[test]
+    fn test_decimal() {
+        let price: Price = "10.23".parse().unwrap();
+        assert_eq!(price.raw(), 102300);
+        assert_eq!("-0.00005".parse::<Money>().unwrap().raw(), -1);
+        assert!("1.2.3".parse::<Price>().is_err());
+        assert_eq!(format!("{}", Money::from_raw(-12345)), "-1.2345");
+        assert_eq!(format!("{:.2}", Money::from_raw(12345)), "1.23");
+        assert_eq!(format!("{}", Price::from(10.0)), "10");
+
+        // 0.1 + 0.2 不产生浮点误差
+        let mut total = Money::ZERO;
+        for _ in 0..1000 {
+            total += Money::from(0.1) + Money::from(0.2);
+        }
+        assert_eq!(total, Money::from(300.0));
+
+        let amount = price * 1000;
+        assert_eq!(amount, Money::from(10230.0));
+        assert_eq!((amount * 0.00025).round_cent(), Money::from(2.56));
+        assert_eq!(Money::from(10.0).prorate(1, 3), Money::from_raw(33333));
+        assert_eq!(amount.per(1000), price);
+        assert_eq!(Price::from(10.234).round_to(Price::from(0.01)), price);
+
+        let js = serde_json::to_string(&(price, amount, Money::from_raw(1))).unwrap();
+        assert_eq!(js, "[10.23,10230.0,0.0001]");
+        let (p, a, m): (Price, Money, Money) = serde_json::from_str(&js).unwrap();
+        assert_eq!((p, a, m.raw()), (price, amount, 1));
+        let p: Price = serde_json::from_str("\"10.23\"").unwrap();
+        assert_eq!(p, price);
+        let p: Price = serde_json::from_str("10").unwrap();
+        assert_eq!(p, Price::from(10.0));
+
+        let bin = bincode::serialize(&amount).unwrap();
+        assert_eq!(bincode::deserialize::<Money>(&bin).unwrap(), amount);
+    }
+}
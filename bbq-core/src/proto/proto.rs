@@ -24,7 +24,9 @@ pub enum Push {
 
 #[cfg(test)]
 mod test {
-    use crate::fetch::{Quot, RtQuot};
+    use crate::fetch::{Quot, RtQuot, TradeStatus};
+    use crate::{Money, Price};
+    use chrono::NaiveDate;
     use super::Push;
 
     #[test]
@@ -33,19 +35,22 @@ mod test {
         let quot = Quot {
             code: "123".to_string(),
             name: "123".to_string(),
-            open: 1.2,
-            pre_close: 1.44,
-            now: 1.33,
-            high: 1.99,
-            low: 0.44,
-            buy: 2.33,
-            sell: 4.44,
+            open: Price::from(1.2),
+            pre_close: Price::from(1.44),
+            now: Price::from(1.33),
+            high: Price::from(1.99),
+            low: Price::from(0.44),
+            buy: Price::from(2.33),
+            sell: Price::from(4.44),
             vol: 100,
-            amount: 4.5,
-            bid: ((1, 2.0), (1, 2.0), (1, 2.0), (1, 2.0), (1, 2.0)),
-            ask: ((1, 2.0), (1, 2.0), (1, 2.0), (1, 2.0), (1, 2.0)),
-            date: "1900-0909".to_string(),
-            time: "1900-0909".to_string(),
+            amount: Money::from(4.5),
+            bid: ((1, Price::from(2.0)), (1, Price::from(2.0)), (1, Price::from(2.0)), (1, Price::from(2.0)), (1, Price::from(2.0))),
+            ask: ((1, Price::from(2.0)), (1, Price::from(2.0)), (1, Price::from(2.0)), (1, Price::from(2.0)), (1, Price::from(2.0))),
+            date: NaiveDate::from_ymd_opt(2022, 3, 1).unwrap(),
+            time: NaiveDate::from_ymd_opt(2022, 3, 1)
+                .and_then(|d| d.and_hms_opt(15, 0, 3))
+                .unwrap(),
+            status: TradeStatus::Suspended,
         };
         rt.insert("123".to_string(), quot);
 
@@ -60,6 +65,12 @@ mod test {
 
         let s = serde_json::to_string(&msg).unwrap();
         println!("json: {}", s);
+        // 与 python 约定的时间格式
+        assert!(s.contains(r#""date":"2022-03-01","time":"2022-03-01 15:00:03","status":"suspended""#));
+        let ds: Push = serde_json::from_str(&s).unwrap();
+        let Push::Quot(rt) = ds;
+        assert_eq!(rt["123"].time.to_string(), "2022-03-01 15:00:03");
+        assert_eq!(rt["123"].status, TradeStatus::Suspended);
 
     }
 }
@@ -0,0 +1,84 @@
+//! 行情时间的 serde 格式, 与 python 策略约定的字符串一致:
+//! 日期 `%Y-%m-%d`, 时间 `%Y-%m-%d %H:%M:%S`
+use anyhow::{Context, Result};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+pub const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 解析时间, 仅有日期时为当日 00:00:00
+pub fn parse_datetime(s: &str) -> Result<NaiveDateTime> {
+    let s = s.trim();
+    if s.len() == 10 {
+        return Ok(parse_date(s)?.and_time(NaiveTime::MIN));
+    }
+    NaiveDateTime::parse_from_str(s, DATETIME_FORMAT)
+        .with_context(|| format!("invalid datetime: {}", s))
+}
+
+pub fn parse_date(s: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(s.trim(), DATE_FORMAT).with_context(|| format!("invalid date: {}", s))
+}
+
+/// `NaiveDateTime` <-> `%Y-%m-%d %H:%M:%S`, 反序列化兼容 `%Y-%m-%d`
+pub mod datetime {
+    use super::{parse_datetime, DATETIME_FORMAT};
+    use chrono::NaiveDateTime;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(time: &NaiveDateTime, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(&time.format(DATETIME_FORMAT))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<NaiveDateTime, D::Error> {
+        let s = String::deserialize(d)?;
+        parse_datetime(&s).map_err(|e| D::Error::custom(format!("{:#}", e)))
+    }
+}
+
+/// `NaiveDate` <-> `%Y-%m-%d`
+pub mod date {
+    use super::{parse_date, DATE_FORMAT};
+    use chrono::NaiveDate;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(date: &NaiveDate, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(&date.format(DATE_FORMAT))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<NaiveDate, D::Error> {
+        let s = String::deserialize(d)?;
+        parse_date(&s).map_err(|e| D::Error::custom(format!("{:#}", e)))
+    }
+}
+
+#[cfg(test)]
+mod test_time_fmt {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Bar {
+        #[serde(with = "super::date")]
+        date: NaiveDate,
+        #[serde(with = "super::datetime")]
+        time: NaiveDateTime,
+    }
+
+    #[test]
+    fn test_time_fmt() {
+        let js = r#"{"date":"2022-03-01","time":"2022-03-01 15:00:03"}"#;
+        let bar: Bar = serde_json::from_str(js).unwrap();
+        assert_eq!(bar.time.to_string(), "2022-03-01 15:00:03");
+        assert_eq!(serde_json::to_string(&bar).unwrap(), js);
+
+        let bar: Bar =
+            serde_json::from_str(r#"{"date":"2022-03-01","time":"2022-03-01"}"#).unwrap();
+        assert_eq!(bar.time, bar.date.and_hms_opt(0, 0, 0).unwrap());
+
+        assert!(
+            serde_json::from_str::<Bar>(r#"{"date":"2022-0301","time":"2022-03-01"}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Bar>(r#"{"date":"2022-03-01","time":"15:00:03"}"#).is_err());
+    }
+}
@@ -1,13 +1,34 @@
+use super::corp_action::{CorpAction, LedgerEntry, LedgerType};
 use super::entrust::EntrustStatus;
 use super::quot::QuotData;
+use super::snapshot::{AccountSnapshot, SnapshotType};
 use crate::trader::deal::Deal;
 use crate::trader::entrust::Entrust;
+use crate::trader::fee::{AShareFee, FeeDetail, FeeModel};
 use crate::trader::position::Position;
 use crate::trader::signal::Signal;
-use crate::{AcctStatus, AcctType, ActionType, BrokerEvent, Kind, EntrustType};
+use crate::{AcctStatus, AcctType, ActionType, BrokerEvent, Kind, EntrustType, LotMethod, Money, Price, SyncPolicy};
+use anyhow::{bail, Context, Result};
 use chrono::{Local, NaiveDateTime};
+use log::{debug, error, warn};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// 资金同步允许误差(1 分)
+const SYNC_TOLERANCE: Money = Money::from_raw(100);
+
+/// 券商同步与本地账户的差异项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct SyncMismatch {
+    // 差异项, 如: cash_available, volume
+    pub item: String,
+    // 股票代码(持仓差异)
+    pub code: Option<String>,
+    pub local: f64,
+    pub broker: f64,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, default, rename_all = "snake_case")]
@@ -17,31 +38,31 @@ pub struct Account {
     pub typ: AcctType,
     pub kind: Kind,
 
-    pub cash_init: f64,
-    pub cash_available: f64,
-    pub cash_frozen: f64,
-    pub total_net_value: f64,
+    pub cash_init: Money,
+    pub cash_available: Money,
+    pub cash_frozen: Money,
+    pub total_net_value: Money,
 
-    pub total_hold_value: f64,
+    pub total_hold_value: Money,
     // 持仓陈本
-    pub cost: f64,
+    pub cost: Money,
     // 持仓盈亏
-    pub profit: f64,
+    pub profit: Money,
     // 持仓盈比例
     pub profit_rate: f64,
     // 平仓盈亏
-    pub close_profit: f64,
+    pub close_profit: Money,
     // 总盈亏
-    pub total_profit: f64,
+    pub total_profit: Money,
     // 总盈亏比例
     pub total_profit_rate: f64,
 
-    // 券商手续费
-    pub broker_fee: f64,
-    // 过户费
-    pub transfer_fee: f64,
-    // 印花税
-    pub tax_fee: f64,
+    // 累计券商佣金
+    pub broker_fee: Money,
+    // 累计过户费
+    pub transfer_fee: Money,
+    // 累计印花税
+    pub tax_fee: Money,
 
     pub start_time: NaiveDateTime,
     pub end_time: Option<NaiveDateTime>,
@@ -53,6 +74,27 @@ pub struct Account {
     // # 成交 backtest
     pub deal: Vec<Deal>,
     pub signal: Vec<Signal>,
+    // 流水(分红/送转)
+    pub ledger: Vec<LedgerEntry>,
+    // 资金曲线
+    pub snapshot: Vec<AccountSnapshot>,
+    // 基准代码
+    pub benchmark: Option<String>,
+    // 基准最新价
+    pub benchmark_price: Price,
+    // 最新行情时间, 券商回报无成交时间时作为成交时间
+    pub quot_time: Option<NaiveDateTime>,
+
+    // 费用模型
+    #[serde(skip)]
+    pub fee_model: Arc<dyn FeeModel>,
+
+    // 券商同步策略(实盘)
+    pub sync_policy: SyncPolicy,
+    // 卖出时持仓批次匹配顺序
+    pub lot_method: LotMethod,
+    // 最近一次券商同步的差异
+    pub sync_mismatch: Vec<SyncMismatch>,
 
     #[serde(skip)]
     pub is_trading: bool,
@@ -85,6 +127,15 @@ impl Default for Account {
             entrust: Default::default(),
             deal: Default::default(),
             signal: Default::default(),
+            ledger: Default::default(),
+            snapshot: Default::default(),
+            benchmark: Default::default(),
+            benchmark_price: Default::default(),
+            quot_time: Default::default(),
+            fee_model: Arc::new(AShareFee::default()),
+            sync_policy: Default::default(),
+            lot_method: Default::default(),
+            sync_mismatch: Default::default(),
             is_trading: false,
         }
     }
@@ -92,83 +143,182 @@ impl Default for Account {
 
 impl Account {
     pub fn new(account_id: String) -> Self {
-        let mut acct = Self::default();
-        acct.account_id = account_id;
-        acct
-    }
-    pub fn get_fee(&self, typ: ActionType, code: &str, price: f64, volume: u32) -> f64 {
-        let total = price * volume as f64;
-        let mut broker_fee = total * self.broker_fee as f64;
-        if broker_fee < 5.0 {
-            broker_fee = 5.0;
-        }
-        let mut tax_fee = 0.0;
-        if matches!(typ, ActionType::Buy) {
-            if code.starts_with("sh6") {
-                tax_fee = total * self.transfer_fee
-            }
-        } else if matches!(typ, ActionType::Sell) {
-            if code.starts_with("sz") || code.starts_with("sh") {
-                tax_fee = total * self.tax_fee
-            }
+        Self {
+            account_id,
+            ..Default::default()
         }
-        broker_fee + tax_fee
+    }
+    pub fn get_fee(&self, typ: ActionType, code: &str, price: Price, volume: u32) -> Money {
+        self.fee_model.get_fee(&self.kind, &typ, code, price, volume)
+    }
+
+    pub fn get_fee_detail(
+        &self,
+        typ: ActionType,
+        code: &str,
+        price: Price,
+        volume: u32,
+    ) -> FeeDetail {
+        self.fee_model
+            .get_fee_detail(&self.kind, &typ, code, price, volume)
     }
 
-    pub fn get_cost(&self, typ: ActionType, code: &str, price: f64, volume: u32) -> f64 {
+    pub fn get_cost(&self, typ: ActionType, code: &str, price: Price, volume: u32) -> Money {
         let fee = self.get_fee(typ, code, price, volume);
-        fee + price * volume as f64
+        fee + price * volume
     }
     pub fn update_account_quot(&mut self, quot: &QuotData) {
         match quot {
             QuotData::Quot(quot) => {
-                self.profit = 0.0;
-                self.cost = 0.0;
-                self.total_hold_value = 0.0;
+                self.profit = Money::ZERO;
+                self.cost = Money::ZERO;
+                self.total_hold_value = Money::ZERO;
+                for bar in quot.values() {
+                    if let Some(action) = &bar.corp_action {
+                        self.apply_corp_action(action, Some(bar.end));
+                    }
+                }
                 for position in self.position.values_mut() {
                     if quot.contains_key(&position.code) {
                         position.on_update_quot(quot.get(&position.code).unwrap());
                     }
-                    self.profit = self.profit + position.profit;
-                    self.total_hold_value =
-                        self.total_hold_value + (position.now_price * position.volume as f64);
-                    self.cost =
-                        self.cost + (position.price * position.volume as f64 + position.fee);
+                    self.profit += position.profit;
+                    self.total_hold_value += position.now_price * position.volume;
+                    self.cost += position.price * position.volume + position.fee;
                 }
 
-                if self.cost > 0.0 {
+                if self.cost > Money::ZERO {
                     self.profit_rate = self.profit / self.cost * 100.0;
                 }
                 self.total_net_value =
                     self.cash_available + self.cash_frozen + self.total_hold_value;
                 self.total_profit = self.close_profit + self.profit;
-                self.total_profit_rate = self.total_profit / self.cash_init * 100.0
+                self.total_profit_rate = self.total_profit / self.cash_init * 100.0;
+
+                if let Some(bar) = self.benchmark.as_ref().and_then(|code| quot.get(code)) {
+                    self.benchmark_price = bar.close;
+                }
+                let time = quot.values().map(|bar| bar.end).max();
+                if time.is_some() {
+                    self.quot_time = time;
+                }
+                self.take_snapshot(SnapshotType::Bar, time);
             }
-            QuotData::QuotStart(_) => {}
-            QuotData::MorningStart(_) | QuotData::NoonStart(_) => self.is_trading = true,
-            QuotData::MorningEnd(_) => self.is_trading = false,
-            QuotData::NoonEnd(_) => {
-                self.is_trading = false;
+            QuotData::QuotStart(status) => self.benchmark = status.opts.benchmark.clone(),
+            QuotData::MorningStart(status) => {
+                self.is_trading = true;
+                let date = status.time.date();
                 for position in self.position.values_mut() {
-                    if position.volume != position.volume_available {
-                        position.volume_frozen = 0;
-                        position.volume_available = position.volume;
-                    }
+                    position.settle(date);
                 }
+            }
+            QuotData::NoonStart(_) => self.is_trading = true,
+            QuotData::MorningEnd(status) => {
+                self.is_trading = false;
+                self.take_snapshot(SnapshotType::MorningEnd, Some(status.time));
+            }
+            QuotData::NoonEnd(status) => {
+                self.is_trading = false;
 
-                for entrust in self.entrust.iter_mut() {
-                    if matches!(entrust.status, EntrustStatus::Commit) {
-                        entrust.status = EntrustStatus::Cancel;
-                    }
+                let open_entrust: Vec<usize> = self
+                    .entrust
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, e)| e.is_open())
+                    .map(|(index, _)| index)
+                    .collect();
+                for index in open_entrust {
+                    self.release_entrust(index);
+                    self.entrust[index].status = EntrustStatus::Expired;
                 }
+                // 委托均已失效, 剩余冻结资金(券商同步差异)转回可用
                 self.cash_available += self.cash_frozen;
+                self.cash_frozen = Money::ZERO;
+
+                // 当日买入批次次日开盘后可卖
+                let date = status.time.date();
+                for position in self.position.values_mut() {
+                    position.volume_frozen = 0;
+                    position.settle(date);
+                }
 
                 if !matches!(self.typ, AcctType::Backtest) {
                     self.entrust.clear();
                     self.deal.clear();
                 }
+                self.take_snapshot(SnapshotType::NoonEnd, Some(status.time));
             }
-            QuotData::QuotEnd(_) => self.end_time = Some(Local::now().naive_local()),
+            QuotData::QuotEnd(status) => {
+                self.end_time = Some(status.time);
+                self.take_snapshot(SnapshotType::QuotEnd, Some(status.time));
+            }
+        }
+    }
+    /// 记录账户快照, 持仓市值按最新价计算
+    pub fn take_snapshot(&mut self, typ: SnapshotType, time: Option<NaiveDateTime>) {
+        let hold_value: Money = self
+            .position
+            .values()
+            .map(|p| p.now_price * p.volume)
+            .sum();
+        let cash = self.cash_available + self.cash_frozen;
+        let net_value = cash + hold_value;
+        let max_net_value = self
+            .snapshot
+            .last()
+            .map(|s| s.max_net_value)
+            .unwrap_or(self.cash_init)
+            .max(net_value);
+        self.snapshot.push(AccountSnapshot {
+            typ,
+            time,
+            net_value,
+            cash,
+            hold_value,
+            exposure: if net_value > Money::ZERO {
+                hold_value / net_value
+            } else {
+                0.0
+            },
+            max_net_value,
+            drawdown: if max_net_value > Money::ZERO {
+                (max_net_value - net_value) / max_net_value
+            } else {
+                0.0
+            },
+            benchmark: self.benchmark_price,
+        });
+    }
+    /// 持仓除权除息: 派现计入可用资金及平仓盈亏, 送转股增加持仓并摊薄持仓均价
+    pub fn apply_corp_action(&mut self, action: &CorpAction, time: Option<NaiveDateTime>) {
+        let position = self.position.get_mut(&action.code);
+        if position.is_none() {
+            return;
+        }
+        let position = position.unwrap();
+        let volume = position.volume;
+        let (cash, bonus) = position.on_corp_action(action);
+        if cash > Money::ZERO {
+            self.cash_available += cash;
+            self.close_profit += cash;
+            self.ledger.push(LedgerEntry {
+                typ: LedgerType::Dividend,
+                code: action.code.clone(),
+                time,
+                volume,
+                amount: cash,
+                desc: format!("每股派现 {}", action.cash),
+            });
+        }
+        if bonus > 0 {
+            self.ledger.push(LedgerEntry {
+                typ: LedgerType::Bonus,
+                code: action.code.clone(),
+                time,
+                volume: bonus,
+                amount: Money::ZERO,
+                desc: format!("每股送转 {:.4}", action.share),
+            });
         }
     }
     pub fn update_account_signal(&mut self, signal: &Signal) {
@@ -176,9 +326,100 @@ impl Account {
             self.signal.push(signal.clone());
         }
     }
-    pub fn update_account_entrust(&mut self, entrust: &Entrust) {
-        self.entrust.push(entrust.clone());
+    /// 委托提交: 买入冻结预估资金, 卖出冻结持仓
+    pub fn update_account_entrust(&mut self, entrust: &Entrust) -> Result<()> {
+        let mut entrust = entrust.clone();
+        if self.benchmark.as_ref() == Some(&entrust.code)
+            && !matches!(entrust.entrust_type, EntrustType::Cancel)
+        {
+            bail!("benchmark {} is not tradable", &entrust.code);
+        }
+        match entrust.entrust_type {
+            EntrustType::Buy => {
+                let mut price = entrust.frozen_price();
+                if price <= Price::ZERO {
+                    // 市价委托无参考价, 以持仓最新价估算
+                    price = self
+                        .position
+                        .get(&entrust.code)
+                        .map(|p| p.now_price)
+                        .unwrap_or_default();
+                }
+                if price <= Price::ZERO {
+                    bail!(
+                        "{} entrust without reference price, code: {}",
+                        &entrust.order_type,
+                        &entrust.code
+                    );
+                }
+                let cost =
+                    self.get_cost(ActionType::Buy, entrust.code.as_str(), price, entrust.volume);
+                if cost > self.cash_available {
+                    bail!(
+                        "cash not enough, code: {}, cost: {}, available: {}",
+                        &entrust.code,
+                        cost,
+                        self.cash_available
+                    );
+                }
+                self.cash_available -= cost;
+                self.cash_frozen += cost;
+                entrust.cash_frozen = cost;
+            }
+            EntrustType::Sell => {
+                let position = self
+                    .position
+                    .get_mut(&entrust.code)
+                    .with_context(|| format!("position not found, code: {}", &entrust.code))?;
+                if entrust.volume > position.volume_available {
+                    bail!(
+                        "volume not enough, code: {}, volume: {}, available: {}",
+                        &entrust.code,
+                        entrust.volume,
+                        position.volume_available
+                    );
+                }
+                position.volume_available -= entrust.volume;
+                position.volume_frozen += entrust.volume;
+            }
+            EntrustType::Cancel => {
+                // 撤销委托沿用被撤委托的id, 由券商推送撤销结果
+                let found = self
+                    .entrust
+                    .iter()
+                    .any(|e| e.entrust_id == entrust.entrust_id && e.is_open());
+                if !found {
+                    bail!("cancel entrust not found, entrust_id: {}", &entrust.entrust_id);
+                }
+                return Ok(());
+            }
+        }
+        self.entrust.push(entrust);
+        Ok(())
+    }
+
+    /// 释放委托未成交部分的冻结资金或冻结持仓
+    fn release_entrust(&mut self, index: usize) {
+        let e = &mut self.entrust[index];
+        let remain = e.volume - e.volume_deal.min(e.volume);
+        e.volume_cancel = remain;
+        match e.entrust_type {
+            EntrustType::Buy => {
+                self.cash_frozen -= e.cash_frozen;
+                self.cash_available += e.cash_frozen;
+                e.cash_frozen = Money::ZERO;
+            }
+            EntrustType::Sell => {
+                if let Some(position) = self.position.get_mut(&e.code) {
+                    let remain = remain.min(position.volume_frozen);
+                    position.volume_frozen -= remain;
+                    position.volume_available += remain;
+                }
+            }
+            EntrustType::Cancel => {}
+        }
     }
+
     pub fn update_broker_push(&mut self, event: &BrokerEvent) {
         match event {
             BrokerEvent::Entrust(entrust) => {
@@ -186,59 +427,602 @@ impl Account {
                     .entrust
                     .iter()
                     .position(|e| e.entrust_id == *entrust.entrust_id);
+                if found.is_none() {
+                    error!("broker push entrust not found, entrust_id: {}", &entrust.entrust_id);
+                    return;
+                }
+                let index = found.unwrap();
+                let status = match entrust.status {
+                    // 部分成交后撤销
+                    EntrustStatus::Cancel if self.entrust[index].volume_deal > 0 => {
+                        EntrustStatus::PartCancel
+                    }
+                    _ => entrust.status.clone(),
+                };
+                let current = &self.entrust[index].status;
+                if !current.can_transit(&status) {
+                    if *current != status {
+                        error!(
+                            "broker push entrust illegal transition, entrust_id: {}, {} -> {}",
+                            &entrust.entrust_id, current, &status
+                        );
+                    }
+                    return;
+                }
+                self.entrust[index].broker_entrust_id = entrust.broker_entrust_id.clone();
 
-                if let Some(index) = found {
-                    let e = self.entrust.get_mut(index).unwrap();
-                    e.status = entrust.status.clone();
-                    e.broker_entrust_id = entrust.broker_entrust_id.clone();
-                    match entrust.status {
-                        EntrustStatus::Deal | EntrustStatus::PartDeal => {
-                            e.volume_deal += entrust.volume_deal;
-                            if matches!(entrust.status, EntrustStatus::PartDeal) {
-                                if e.volume_deal == e.volume {
-                                    e.status = EntrustStatus::Deal;
-                                }
-                            }
-                            let mut deal = Deal::new_from_entrust(entrust);
-                            match entrust.entrust_type {
-                                EntrustType::Buy => {
-                                    deal.fee = self.get_fee(
-                                        ActionType::Buy,
-                                        entrust.code.as_str(),
-                                        entrust.price,
-                                        entrust.volume_deal,
-                                    );
-                                },
-                                EntrustType::Sell => {
-                                    deal.fee = self.get_fee(
-                                        ActionType::Sell,
-                                        entrust.code.as_str(),
-                                        entrust.price,
-                                        entrust.volume_deal,
-                                    );
-                                },
-                                EntrustType::Cancel => return,
+                match status {
+                    EntrustStatus::Deal | EntrustStatus::PartDeal => {
+                        let e = &self.entrust[index];
+                        let typ = e.entrust_type.clone();
+                        let remain = e.volume - (e.volume_deal + e.volume_cancel).min(e.volume);
+                        // 回报的成交量为累计成交量
+                        let volume = entrust.volume_deal.saturating_sub(e.volume_deal).min(remain);
+                        if volume == 0 {
+                            return;
+                        }
+                        let action = match typ {
+                            EntrustType::Buy => ActionType::Buy,
+                            EntrustType::Sell => ActionType::Sell,
+                            EntrustType::Cancel => return,
+                        };
+
+                        let volume_deal = e.volume_deal + volume;
+                        let mut deal = Deal::new_from_entrust(entrust, volume, volume_deal);
+                        if deal.time.is_none() {
+                            deal.time = self.quot_time;
+                        }
+                        let fee = self.get_fee_detail(
+                            action,
+                            entrust.code.as_str(),
+                            entrust.price,
+                            volume,
+                        );
+                        deal.fee = fee.total();
+                        self.broker_fee += fee.broker;
+                        self.transfer_fee += fee.transfer;
+                        self.tax_fee += fee.tax;
+                        let amount = deal.price * volume;
+
+                        let e = &mut self.entrust[index];
+                        e.volume_deal = volume_deal;
+                        e.deal_time = deal.time;
+                        e.status = if e.volume_deal >= e.volume {
+                            EntrustStatus::Deal
+                        } else {
+                            EntrustStatus::PartDeal
+                        };
+                        match typ {
+                            EntrustType::Buy => {
+                                let release = if volume == remain {
+                                    e.cash_frozen
+                                } else {
+                                    e.cash_frozen.prorate(volume, remain)
+                                };
+                                e.cash_frozen -= release;
+                                self.cash_frozen -= release;
+                                self.cash_available += release - (amount + deal.fee);
                             }
-                            
-                            self.update_position(&deal);
-                            self.deal.push(deal);
+                            _ => self.cash_available += amount - deal.fee,
                         }
-                        EntrustStatus::Cancel => {
-                            e.volume_cancel = entrust.volume_cancel;
+
+                        self.update_position(&mut deal);
+                        self.deal.push(deal);
+                    }
+                    EntrustStatus::Cancel
+                    | EntrustStatus::PartCancel
+                    | EntrustStatus::Rejected
+                    | EntrustStatus::Expired => {
+                        self.release_entrust(index);
+                        let e = &mut self.entrust[index];
+                        e.status = status;
+                        e.reason = entrust.reason.clone();
+                        if matches!(e.status, EntrustStatus::Rejected) {
+                            warn!(
+                                "broker rejected entrust, entrust_id: {}, reason: {:?}",
+                                &e.entrust_id, &e.reason
+                            );
                         }
-                        _ => {}
                     }
+                    _ => self.entrust[index].status = status,
+                }
+            }
+            BrokerEvent::FundSync((total, available, hold)) => {
+                if !matches!(self.typ, AcctType::Real) {
+                    debug!("ignore fund sync, account type: {}", &self.typ);
+                    return;
                 }
+                self.sync_fund(*total, *available, *hold);
+            }
+            BrokerEvent::Position(position) => {
+                if !matches!(self.typ, AcctType::Real) {
+                    debug!("ignore position sync, account type: {}", &self.typ);
+                    return;
+                }
+                self.sync_position(position);
             }
-            BrokerEvent::FundSync((total, available, hold)) => {}
-            BrokerEvent::Position(position) => {}
             _ => {}
         }
     }
 
-    pub fn update_position(&mut self, deal: &Deal) {
-        if self.position.contains_key(&deal.code) {
-            let position = self.position.get_mut(&deal.code).unwrap();
+    fn on_sync_mismatch(&mut self, item: &str, code: Option<&str>, local: f64, broker: f64) {
+        warn!(
+            "account: {}, broker sync mismatch, item: {}, code: {:?}, local: {:.4}, broker: {:.4}, policy: {}",
+            &self.account_id, item, code, local, broker, &self.sync_policy
+        );
+        self.sync_mismatch.push(SyncMismatch {
+            item: item.to_string(),
+            code: code.map(|c| c.to_string()),
+            local,
+            broker,
+        });
+    }
+
+    /// 资金同步: 总资金, 可用资金, 持仓市值
+    fn sync_fund(&mut self, total: Money, available: Money, hold: Money) {
+        self.sync_mismatch.retain(|m| m.code.is_some());
+        let fund = [
+            ("total_net_value", self.total_net_value, total),
+            ("cash_available", self.cash_available, available),
+            ("total_hold_value", self.total_hold_value, hold),
+        ];
+        for (item, local, broker) in fund {
+            if (local - broker).abs() > SYNC_TOLERANCE {
+                self.on_sync_mismatch(item, None, local.to_f64(), broker.to_f64());
+            }
+        }
+
+        if matches!(self.sync_policy, SyncPolicy::Broker) {
+            self.total_net_value = total;
+            self.cash_available = available;
+            self.total_hold_value = hold;
+            self.cash_frozen = (total - available - hold).max(Money::ZERO);
+        }
+    }
+
+    /// 持仓同步
+    fn sync_position(&mut self, position: &[Position]) {
+        self.sync_mismatch.retain(|m| m.code.is_none());
+        let broker: HashMap<&str, &Position> =
+            position.iter().map(|p| (p.code.as_str(), p)).collect();
+
+        let local_only: Vec<String> = self
+            .position
+            .keys()
+            .filter(|code| !broker.contains_key(code.as_str()))
+            .cloned()
+            .collect();
+        for code in local_only.iter() {
+            let volume = self.position.get(code).unwrap().volume;
+            self.on_sync_mismatch("volume", Some(code), volume as f64, 0.0);
+        }
+
+        for (code, broker_pos) in broker.iter() {
+            let (volume, volume_available) = match self.position.get(*code) {
+                Some(local_pos) => (local_pos.volume, local_pos.volume_available),
+                None => (0, 0),
+            };
+            if volume != broker_pos.volume {
+                self.on_sync_mismatch(
+                    "volume",
+                    Some(code),
+                    volume as f64,
+                    broker_pos.volume as f64,
+                );
+            }
+            if volume_available != broker_pos.volume_available {
+                self.on_sync_mismatch(
+                    "volume_available",
+                    Some(code),
+                    volume_available as f64,
+                    broker_pos.volume_available as f64,
+                );
+            }
+        }
+
+        if matches!(self.sync_policy, SyncPolicy::Broker) {
+            for code in local_only.iter() {
+                self.position.remove(code);
+            }
+            for (code, broker_pos) in broker.into_iter() {
+                if broker_pos.volume == 0 {
+                    self.position.remove(code);
+                    continue;
+                }
+                match self.position.get_mut(code) {
+                    Some(local_pos) => {
+                        local_pos.volume = broker_pos.volume;
+                        local_pos.volume_available = broker_pos.volume_available;
+                        local_pos.volume_frozen = broker_pos
+                            .volume
+                            .saturating_sub(broker_pos.volume_available)
+                            .min(local_pos.volume_frozen);
+                        if broker_pos.price > Price::ZERO {
+                            local_pos.price = broker_pos.price;
+                        }
+                        local_pos.reset_lots();
+                    }
+                    None => {
+                        let mut local_pos = broker_pos.clone();
+                        if local_pos.position_id.is_empty() {
+                            local_pos.position_id = format!("sync-{}", code);
+                        }
+                        // 无建仓时间时以最新行情时间计, 当日可卖数量以券商为准
+                        if local_pos.time.is_none() {
+                            local_pos.time = self.quot_time.or(Some(Local::now().naive_local()));
+                        }
+                        local_pos.reset_lots();
+                        self.position.insert(code.to_string(), local_pos);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn update_position(&mut self, deal: &mut Deal) {
+        match deal.deal_type {
+            EntrustType::Buy => {
+                let position = self
+                    .position
+                    .entry(deal.code.clone())
+                    .or_insert_with(|| Position::new_from_deal(deal));
+                position.on_buy_deal(deal);
+            }
+            EntrustType::Sell => {
+                let position = self.position.get_mut(&deal.code);
+                if position.is_none() {
+                    error!("sell deal without position, code: {}", &deal.code);
+                    return;
+                }
+                let position = position.unwrap();
+                let (profit, close_lots) = position.on_sell_deal(deal, &self.lot_method);
+                deal.profit = profit;
+                deal.close_lots = close_lots;
+                self.close_profit += deal.profit;
+
+                if position.volume == 0 {
+                    self.position.remove(&deal.code);
+                }
+            }
+            EntrustType::Cancel => {}
         }
     }
 }
+
+#[cfg(test)]
+mod test_account {
+    use crate::{
+        AShareFee, Account, AcctType, BrokerEvent, CorpAction, Deal, Entrust, EntrustStatus,
+        EntrustType, FeeRule, LedgerType, LotMethod, Money, Position, Price, QuotBar, QuotData,
+        QuotOpts, QuotStatus, RtQuotBar, SnapshotType, SyncPolicy,
+    };
+    use crate::fetch::Quot;
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::sync::Arc;
+
+    fn deal(typ: EntrustType, price: f64, volume: u32, fee: f64) -> Deal {
+        Deal {
+            code: "sh600063".to_string(),
+            deal_type: typ,
+            price: Price::from(price),
+            volume,
+            fee: Money::from(fee),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_update_position() {
+        let mut acct = Account::new("test".to_string());
+
+        acct.update_position(&mut deal(EntrustType::Buy, 10.0, 1000, 5.0));
+        acct.update_position(&mut deal(EntrustType::Buy, 12.0, 1000, 5.0));
+        let position = acct.position.get("sh600063").unwrap();
+        assert_eq!(position.volume, 2000);
+        assert_eq!(position.price, Price::from(11.0));
+        assert_eq!(position.fee, Money::from(10.0));
+
+        // 先进先出, 平 10 元批次
+        let mut sell = deal(EntrustType::Sell, 12.0, 1000, 5.0);
+        acct.update_position(&mut sell);
+        assert_eq!(sell.profit, Money::from(1990.0));
+        assert_eq!(acct.close_profit, Money::from(1990.0));
+        assert_eq!(sell.close_lots.len(), 1);
+        assert_eq!(sell.close_lots[0].open_price, Price::from(10.0));
+        let position = acct.position.get("sh600063").unwrap();
+        assert_eq!(position.volume, 1000);
+        assert_eq!(position.price, Price::from(12.0));
+
+        let mut sell = deal(EntrustType::Sell, 10.0, 1000, 5.0);
+        acct.update_position(&mut sell);
+        assert_eq!(sell.profit, Money::from(-2010.0));
+        assert!(acct.position.is_empty());
+
+        // 后进先出, 平 12 元批次
+        let mut acct = Account::new("test".to_string());
+        acct.lot_method = LotMethod::Lifo;
+        acct.update_position(&mut deal(EntrustType::Buy, 10.0, 1000, 5.0));
+        acct.update_position(&mut deal(EntrustType::Buy, 12.0, 1000, 5.0));
+        let mut sell = deal(EntrustType::Sell, 12.0, 1500, 5.0);
+        acct.update_position(&mut sell);
+        assert_eq!(sell.close_lots.len(), 2);
+        assert_eq!(sell.close_lots[0].volume, 1000);
+        assert_eq!(sell.close_lots[0].open_price, Price::from(12.0));
+        assert_eq!(sell.profit, Money::from(1000.0 - 7.5 - 5.0));
+        let position = acct.position.get("sh600063").unwrap();
+        assert_eq!((position.volume, position.lots.len()), (500, 1));
+        assert_eq!(position.fee, Money::from(2.5));
+    }
+
+    #[test]
+    fn test_lot_available() {
+        let mut acct = Account::new("test".to_string());
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+        let status = |s| QuotStatus {
+            opts: QuotOpts::default(),
+            time: time(s),
+        };
+        let mut buy = deal(EntrustType::Buy, 10.0, 1000, 0.0);
+        buy.time = Some(time("2022-03-01 10:00:00"));
+        acct.update_position(&mut buy);
+        acct.update_account_quot(&QuotData::NoonEnd(status("2022-03-01 15:00:00")));
+        assert_eq!(acct.position.get("sh600063").unwrap().volume_available, 0);
+
+        acct.update_account_quot(&QuotData::MorningStart(status("2022-03-02 09:30:00")));
+        let mut buy = deal(EntrustType::Buy, 11.0, 500, 0.0);
+        buy.time = Some(time("2022-03-02 10:00:00"));
+        acct.update_position(&mut buy);
+        let position = acct.position.get("sh600063").unwrap();
+        assert_eq!((position.volume, position.volume_available), (1500, 1000));
+
+        // 优先平可卖批次
+        acct.lot_method = LotMethod::Lifo;
+        let mut sell = deal(EntrustType::Sell, 12.0, 1000, 0.0);
+        sell.time = Some(time("2022-03-02 14:00:00"));
+        acct.update_position(&mut sell);
+        assert_eq!(sell.profit, Money::from(2000.0));
+        assert!((sell.close_lots[0].holding_days - 1.0 - 4.0 / 24.0).abs() < 1e-9);
+        let position = acct.position.get("sh600063").unwrap();
+        assert_eq!((position.volume, position.volume_available), (500, 0));
+    }
+
+    #[test]
+    fn test_corp_action() {
+        let mut acct = Account::new("test".to_string());
+        acct.update_position(&mut deal(EntrustType::Buy, 10.0, 1000, 0.0));
+        let date = NaiveDate::parse_from_str("2022-06-01", "%Y-%m-%d").unwrap();
+
+        // 10 派 5 元
+        let action = CorpAction::from_factor("sh600063", date, Price::from(10.0), 1.0, 1.0 / 0.95).unwrap();
+        assert_eq!(action.cash, Price::from(0.5));
+        acct.apply_corp_action(&action, None);
+        assert_eq!(acct.cash_available, Money::from(500.0));
+        assert_eq!(acct.close_profit, Money::from(500.0));
+
+        // 10 转 5
+        let action = CorpAction::from_factor("sh600063", date, Price::from(9.5), 1.0, 1.5).unwrap();
+        assert!((action.share - 0.5).abs() < 1e-9);
+        acct.apply_corp_action(&action, None);
+        let position = acct.position.get("sh600063").unwrap();
+        assert_eq!(position.volume, 1500);
+        assert_eq!(position.volume_available, 0);
+        assert_eq!(position.price, Price::from(10.0 / 1.5));
+
+        assert_eq!(acct.ledger.len(), 2);
+        assert!(matches!(acct.ledger[0].typ, LedgerType::Dividend));
+        assert!(matches!(acct.ledger[1].typ, LedgerType::Bonus));
+        assert_eq!(acct.ledger[1].volume, 500);
+
+        // 未持仓不处理
+        let action = CorpAction {
+            code: "sz000001".to_string(),
+            cash: Price::from(1.0),
+            ..Default::default()
+        };
+        acct.apply_corp_action(&action, None);
+        assert_eq!(acct.ledger.len(), 2);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(20000.0);
+        acct.cash_available = Money::from(10000.0);
+        acct.update_position(&mut deal(EntrustType::Buy, 10.0, 1000, 0.0));
+        acct.take_snapshot(SnapshotType::Bar, None);
+
+        acct.position.get_mut("sh600063").unwrap().now_price = Price::from(8.0);
+        acct.take_snapshot(SnapshotType::QuotEnd, None);
+
+        let s = acct.snapshot.last().unwrap();
+        assert_eq!(s.net_value, Money::from(18000.0));
+        assert_eq!(s.max_net_value, Money::from(20000.0));
+        assert!((s.drawdown - 0.1).abs() < 1e-9);
+        assert!((s.exposure - 8000.0 / 18000.0).abs() < 1e-9);
+
+        acct.benchmark = Some("sh000300".to_string());
+        let entrust = Entrust {
+            code: "sh000300".to_string(),
+            entrust_type: EntrustType::Buy,
+            price: Price::from(4000.0),
+            volume: 100,
+            ..Default::default()
+        };
+        assert!(acct.update_account_entrust(&entrust).is_err());
+    }
+
+    #[test]
+    fn test_entrust_frozen() {
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(100000.0);
+        acct.cash_available = Money::from(100000.0);
+        acct.fee_model = Arc::new(AShareFee::new(FeeRule {
+            transfer: 0.0,
+            ..Default::default()
+        }));
+
+        let mut entrust = Entrust {
+            entrust_id: "buy".to_string(),
+            code: "sz000001".to_string(),
+            entrust_type: EntrustType::Buy,
+            price: Price::from(10.0),
+            volume: 2000,
+            ..Default::default()
+        };
+        acct.update_account_entrust(&entrust).unwrap();
+        assert_eq!(acct.cash_frozen, Money::from(20005.0));
+        assert_eq!(acct.cash_available, Money::from(79995.0));
+
+        entrust.status = EntrustStatus::PartDeal;
+        entrust.volume_deal = 1000;
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        assert_eq!(acct.cash_frozen, Money::from(10002.5));
+        assert_eq!(acct.cash_available, Money::from(79992.5));
+        assert_eq!(acct.position.get("sz000001").unwrap().volume, 1000);
+        assert_eq!(acct.broker_fee, Money::from(5.0));
+        assert_eq!((acct.transfer_fee, acct.tax_fee), (Money::ZERO, Money::ZERO));
+
+        entrust.status = EntrustStatus::Cancel;
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        assert_eq!(acct.cash_frozen, Money::ZERO);
+        assert_eq!(acct.cash_available, Money::from(89995.0));
+        assert_eq!(acct.entrust[0].volume_cancel, 1000);
+        assert_eq!(acct.entrust[0].status, EntrustStatus::PartCancel);
+
+        // 终结状态不可迁移
+        entrust.status = EntrustStatus::Commit;
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        assert_eq!(acct.entrust[0].status, EntrustStatus::PartCancel);
+
+        let sell = Entrust {
+            entrust_id: "sell".to_string(),
+            code: "sz000001".to_string(),
+            entrust_type: EntrustType::Sell,
+            price: Price::from(11.0),
+            volume: 1000,
+            ..Default::default()
+        };
+        assert!(acct.update_account_entrust(&sell).is_err());
+        acct.position.get_mut("sz000001").unwrap().volume_available = 1000;
+        acct.update_account_entrust(&sell).unwrap();
+        let position = acct.position.get("sz000001").unwrap();
+        assert_eq!((position.volume_available, position.volume_frozen), (0, 1000));
+
+        let mut rejected = sell.clone();
+        rejected.status = EntrustStatus::Rejected;
+        rejected.reason = Some("no quota".to_string());
+        acct.update_broker_push(&BrokerEvent::Entrust(rejected));
+        assert_eq!(acct.entrust[1].status, EntrustStatus::Rejected);
+        assert_eq!(acct.entrust[1].reason.as_deref(), Some("no quota"));
+        let position = acct.position.get("sz000001").unwrap();
+        assert_eq!((position.volume_available, position.volume_frozen), (1000, 0));
+    }
+
+    #[test]
+    fn test_cumulative_deal() {
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(100000.0);
+        acct.cash_available = Money::from(100000.0);
+        let time = |s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut entrust = Entrust {
+            entrust_id: "buy".to_string(),
+            code: "sz000001".to_string(),
+            entrust_type: EntrustType::Buy,
+            price: Price::from(10.0),
+            volume: 900,
+            ..Default::default()
+        };
+        acct.update_account_entrust(&entrust).unwrap();
+
+        // 券商回报累计成交量, 重复回报不重复记账
+        entrust.status = EntrustStatus::PartDeal;
+        entrust.volume_deal = 300;
+        entrust.deal_time = Some(time("2022-03-01 10:00:00"));
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        entrust.volume_deal = 600;
+        entrust.deal_time = Some(time("2022-03-01 10:30:00"));
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        assert_eq!(acct.position.get("sz000001").unwrap().volume, 600);
+        assert_eq!(acct.entrust[0].volume_deal, 600);
+        assert_eq!(acct.deal.len(), 2);
+        assert_eq!(acct.deal[1].volume, 300);
+        assert_eq!(acct.deal[1].time, Some(time("2022-03-01 10:30:00")));
+
+        // 无成交时间时取最新行情时间
+        let mut bars = RtQuotBar::new();
+        bars.insert(
+            "sz000001".to_string(),
+            QuotBar {
+                frequency: 60,
+                open: Price::from(10.0),
+                high: Price::from(10.0),
+                low: Price::from(10.0),
+                close: Price::from(10.0),
+                start: time("2022-03-01 13:59:00"),
+                end: time("2022-03-01 14:00:00"),
+                quot: Quot::default(),
+                corp_action: None,
+            },
+        );
+        acct.update_account_quot(&QuotData::Quot(bars));
+        entrust.status = EntrustStatus::Deal;
+        entrust.volume_deal = 900;
+        entrust.deal_time = None;
+        acct.update_broker_push(&BrokerEvent::Entrust(entrust.clone()));
+        let position = acct.position.get("sz000001").unwrap();
+        assert_eq!(position.volume, 900);
+        assert_eq!(position.lots[2].time, Some(time("2022-03-01 14:00:00")));
+        assert_eq!(acct.entrust[0].status, EntrustStatus::Deal);
+        assert_eq!(acct.cash_frozen, Money::ZERO);
+
+        // 当日买入不可卖, 次日可卖
+        let status = |s| QuotStatus {
+            opts: QuotOpts::default(),
+            time: time(s),
+        };
+        acct.update_account_quot(&QuotData::NoonEnd(status("2022-03-01 15:00:00")));
+        assert_eq!(acct.position.get("sz000001").unwrap().volume_available, 0);
+        acct.update_account_quot(&QuotData::MorningStart(status("2022-03-02 09:30:00")));
+        assert_eq!(acct.position.get("sz000001").unwrap().volume_available, 900);
+    }
+
+    #[test]
+    fn test_broker_sync() {
+        let mut acct = Account::new("test".to_string());
+        acct.typ = AcctType::Real;
+        acct.cash_available = Money::from(1000.0);
+        acct.update_position(&mut deal(EntrustType::Buy, 10.0, 100, 5.0));
+
+        acct.sync_policy = SyncPolicy::Local;
+        acct.update_broker_push(&BrokerEvent::FundSync((
+            Money::from(3000.0),
+            Money::from(2000.0),
+            Money::from(1000.0),
+        )));
+        assert_eq!(acct.sync_mismatch.len(), 3);
+        assert_eq!(acct.cash_available, Money::from(1000.0));
+
+        acct.sync_policy = SyncPolicy::Broker;
+        let broker_pos = Position {
+            code: "sz000001".to_string(),
+            volume: 200,
+            volume_available: 200,
+            price: Price::from(9.0),
+            ..Default::default()
+        };
+        acct.update_broker_push(&BrokerEvent::Position(vec![broker_pos]));
+        assert_eq!(acct.sync_mismatch.len(), 3 + 3);
+        assert!(!acct.position.contains_key("sh600063"));
+        assert_eq!(acct.position.get("sz000001").unwrap().volume, 200);
+
+        acct.update_broker_push(&BrokerEvent::FundSync((
+            Money::from(3000.0),
+            Money::from(2000.0),
+            Money::from(1000.0),
+        )));
+        assert_eq!(acct.cash_available, Money::from(2000.0));
+        assert_eq!(acct.sync_mismatch.len(), 3 + 3);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Money, Price};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// 复权因子比例达到该值按送转股处理, 否则按现金分红处理
+///
+/// 经验阈值: 高比例派现(股息率 >= 约 9%)会被误判为送转, 小比例送转及派现加送转会被折算为现金
+const FACTOR_SHARE_RATIO: f64 = 1.1;
+
+/// 除权除息(现金分红, 送股, 转增, 拆股)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct CorpAction {
+    // 股票代码
+    pub code: String,
+    // 除权除息日
+    pub date: Option<NaiveDate>,
+    // 每股派现
+    pub cash: Price,
+    // 每股送转股数, 如 10 送 3 为 0.3
+    pub share: f64,
+}
+
+impl CorpAction {
+    /// 由除权日前后后复权因子推算, 仅在没有实际分红送转数据时兜底使用
+    ///
+    /// 复权因子无法区分派现与送转, 比例较大(>= 1.1)时按送转股处理, 否则折算为等价现金分红,
+    /// 两者对持仓市值的影响一致, 但持仓数量/现金及费用会与实际不符, 应优先使用存储的除权除息数据
+    pub fn from_factor(
+        code: &str,
+        date: NaiveDate,
+        pre_close: Price,
+        pre_factor: f64,
+        factor: f64,
+    ) -> Option<Self> {
+        if pre_factor <= 0.0 || pre_close <= Price::ZERO {
+            return None;
+        }
+        let ratio = factor / pre_factor;
+        if ratio <= 1.0 + 1e-6 {
+            return None;
+        }
+        let mut action = Self {
+            code: code.to_string(),
+            date: Some(date),
+            ..Default::default()
+        };
+        if ratio >= FACTOR_SHARE_RATIO {
+            action.share = ratio - 1.0;
+        } else {
+            action.cash = pre_close * (1.0 - 1.0 / ratio);
+        }
+        Some(action)
+    }
+
+    /// 除权除息参考价, (昨收 - 每股派现) / (1 + 每股送转股数)
+    pub fn ex_price(&self, pre_close: Price) -> Price {
+        (pre_close - self.cash) * (1.0 / (1.0 + self.share))
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerType {
+    #[default]
+    Dividend,
+    Bonus,
+}
+
+impl Display for LedgerType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            LedgerType::Dividend => "现金分红",
+            LedgerType::Bonus => "送转股",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 账户流水, 记录非交易引起的资金/持仓变动
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct LedgerEntry {
+    pub typ: LedgerType,
+    // 股票代码
+    pub code: String,
+    pub time: Option<NaiveDateTime>,
+    // 变动数量
+    pub volume: u32,
+    // 变动金额
+    pub amount: Money,
+    // 描述
+    pub desc: String,
+}
@@ -1,8 +1,7 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-use crate::{EntrustType, Entrust};
+use crate::{ClosedLot, EntrustType, Entrust, Money, Price};
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, default, rename_all = "snake_case")]
@@ -20,28 +19,32 @@ pub struct Deal {
     // 成交类型
     pub deal_type: EntrustType,
     // 成交价格
-    pub price: f64,
+    pub price: Price,
     // 成交量
     pub volume: u32,
     // 盈利额
-    pub profit: f64,
+    pub profit: Money,
     // 手续费
-    pub fee: f64,
+    pub fee: Money,
+    // 平仓明细(卖出成交)
+    pub close_lots: Vec<ClosedLot>,
 }
 
 impl Deal {
-    pub fn new_from_entrust(entrust: &Entrust) -> Self {
+    /// 成交编号按委托累计成交量(含本次)生成, 重放时保持一致; 成交时间取委托回报的成交时间
+    pub fn new_from_entrust(entrust: &Entrust, volume: u32, volume_deal: u32) -> Self {
         Self {
-            deal_id: Uuid::new_v4().to_simple().to_string(),
+            deal_id: format!("{}-{}", &entrust.entrust_id, volume_deal),
             entrust_id: entrust.entrust_id.clone(),
             name: entrust.name.clone(),
             code: entrust.code.clone(),
-            time: entrust.time.clone(),
+            time: entrust.deal_time,
             deal_type: entrust.entrust_type.clone(),
             price: entrust.price,
-            volume: entrust.volume_deal,
-            profit: 0.0,
-            fee: 0.0,
+            volume,
+            profit: Money::ZERO,
+            fee: Money::ZERO,
+            close_lots: vec![],
         }
     }
 }
\ No newline at end of file
@@ -3,8 +3,9 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use uuid::Uuid;
 use super::signal::Signal;
+use crate::{Money, Price};
 
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EntrustStatus {
     Init,
@@ -12,6 +13,12 @@ pub enum EntrustStatus {
     Deal,
     PartDeal,
     Cancel,
+    /// 部分成交后撤销
+    PartCancel,
+    /// 券商拒绝
+    Rejected,
+    /// 当日未成交失效
+    Expired,
 }
 
 impl Default for EntrustStatus {
@@ -28,11 +35,49 @@ impl Display for EntrustStatus {
             EntrustStatus::Deal => "已成交",
             EntrustStatus::PartDeal => "部分成交",
             EntrustStatus::Cancel => "已撤销",
+            EntrustStatus::PartCancel => "部成部撤",
+            EntrustStatus::Rejected => "已拒绝",
+            EntrustStatus::Expired => "已失效",
         };
         write!(f, "{}", s)
     }
 }
 
+impl EntrustStatus {
+    /// 终结状态, 不再变化
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            EntrustStatus::Deal
+                | EntrustStatus::Cancel
+                | EntrustStatus::PartCancel
+                | EntrustStatus::Rejected
+                | EntrustStatus::Expired
+        )
+    }
+
+    /// 状态迁移是否合法
+    ///
+    /// - 初始化: 可迁移至除初始化外任意状态
+    /// - 已提交: 可继续提交(券商回报), 成交, 撤销, 拒绝, 失效
+    /// - 部分成交: 可继续成交, 部成部撤, 失效
+    /// - 终结状态不可迁移
+    pub fn can_transit(&self, to: &EntrustStatus) -> bool {
+        match self {
+            EntrustStatus::Init => !matches!(to, EntrustStatus::Init),
+            EntrustStatus::Commit => !matches!(to, EntrustStatus::Init | EntrustStatus::PartCancel),
+            EntrustStatus::PartDeal => matches!(
+                to,
+                EntrustStatus::PartDeal
+                    | EntrustStatus::Deal
+                    | EntrustStatus::PartCancel
+                    | EntrustStatus::Expired
+            ),
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EntrustType {
@@ -58,6 +103,36 @@ impl Display for EntrustType {
     }
 }
 
+/// 委托价格类型
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    /// 限价
+    #[default]
+    Limit,
+    /// 市价
+    Market,
+    /// 最优五档即时成交剩余撤销
+    BestFiveMarket,
+    /// 止损(触发后市价)
+    Stop,
+    /// 止损限价(触发后限价)
+    StopLimit,
+}
+
+impl Display for OrderType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            OrderType::Limit => "限价",
+            OrderType::Market => "市价",
+            OrderType::BestFiveMarket => "最优五档",
+            OrderType::Stop => "止损",
+            OrderType::StopLimit => "止损限价",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, default, rename_all = "snake_case")]
 pub struct Entrust {
@@ -69,13 +144,24 @@ pub struct Entrust {
     pub entrust_type: EntrustType,
     pub status: EntrustStatus,
 
-    pub price: f64,
+    pub order_type: OrderType,
+    // 委托价, 市价委托为参考价(冻结资金用)
+    pub price: Price,
+    // 止损触发价
+    pub stop_price: Price,
     pub volume: u32,
 
     pub volume_deal: u32,
     pub volume_cancel: u32,
+    // 最近一次成交时间, 由撮合/券商回报
+    pub deal_time: Option<NaiveDateTime>,
+
+    // 买入冻结资金(未成交部分)
+    pub cash_frozen: Money,
 
     pub desc: String,
+    // 拒绝/撤销原因, 由券商回报
+    pub reason: Option<String>,
 
     pub broker_entrust_id: Option<String>,
 }
@@ -83,22 +169,43 @@ pub struct Entrust {
 impl Entrust {
     pub fn new_from_signal(signal: &Signal) -> Self {
         Self {
-            entrust_id: Uuid::new_v4().to_simple().to_string(),
+            entrust_id: match (&signal.signal, &signal.entrust_id) {
+                (super::signal::SignalType::Cancel, Some(entrust_id)) => entrust_id.clone(),
+                _ => Uuid::new_v4().to_simple().to_string(),
+            },
             name: signal.name.clone(),
             code: signal.code.clone(),
-            time: signal.time.clone(),
+            time: signal.time,
             entrust_type: match signal.signal {
                 super::signal::SignalType::Sell => EntrustType::Sell,
                 super::signal::SignalType::Buy => EntrustType::Buy,
                 super::signal::SignalType::Cancel => EntrustType::Cancel,
             },
             status: EntrustStatus::Init,
+            order_type: signal.order_type.clone(),
             price: signal.price,
+            stop_price: signal.stop_price,
             volume: signal.volume,
             volume_deal: 0,
             volume_cancel: 0,
+            deal_time: None,
+            cash_frozen: Money::ZERO,
             desc: "".to_string(),
+            reason: None,
             broker_entrust_id: None,
         }
     }
+
+    /// 冻结资金的估算价格
+    pub fn frozen_price(&self) -> Price {
+        match self.order_type {
+            OrderType::Stop if self.price <= Price::ZERO => self.stop_price,
+            _ => self.price,
+        }
+    }
+
+    /// 未完结委托(可成交/可撤销)
+    pub fn is_open(&self) -> bool {
+        !self.status.is_final()
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{Signal, Entrust, Position};
+use crate::{Signal, Entrust, Money, Position, QuotData};
 use serde::{Serialize, Deserialize};
 
 
@@ -13,6 +13,8 @@ pub enum Event {
     Entrust(Entrust),
     /// 券商推送/同步, 发出: broker
     Broker(BrokerEvent),
+    /// 行情, 发往: broker(撮合止损/限价委托)
+    Quot(QuotData),
 
     ///
     EventNone(String),
@@ -29,7 +31,7 @@ impl Default for Event {
 pub enum BrokerEvent {
     Entrust(Entrust),
     /// 总资金，可用资金，持仓市值
-    FundSync((f64, f64, f64)),
+    FundSync((Money, Money, Money)),
     Position(Vec<Position>),
     ///
     EventNone,
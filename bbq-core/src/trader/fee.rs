@@ -0,0 +1,270 @@
+use crate::{ActionType, Board, Instrument, Kind, Money, Price};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+/// 交易费用模型
+pub trait FeeModel: Debug + Send + Sync {
+    /// 单笔成交费用明细
+    fn get_fee_detail(
+        &self,
+        kind: &Kind,
+        typ: &ActionType,
+        code: &str,
+        price: Price,
+        volume: u32,
+    ) -> FeeDetail;
+
+    /// 单笔成交费用(佣金 + 过户费 + 印花税)
+    fn get_fee(
+        &self,
+        kind: &Kind,
+        typ: &ActionType,
+        code: &str,
+        price: Price,
+        volume: u32,
+    ) -> Money {
+        self.get_fee_detail(kind, typ, code, price, volume).total()
+    }
+}
+
+/// 费用明细
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct FeeDetail {
+    // 券商佣金
+    pub broker: Money,
+    // 过户费
+    pub transfer: Money,
+    // 印花税
+    pub tax: Money,
+}
+
+impl FeeDetail {
+    pub fn total(&self) -> Money {
+        self.broker + self.transfer + self.tax
+    }
+}
+
+/// 费率规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct FeeRule {
+    // 券商佣金费率, 双向
+    pub broker: f64,
+    // 最低佣金
+    pub min_broker: Money,
+    // 过户费率, 双向
+    pub transfer: f64,
+    // 印花税率, 卖出
+    pub tax: f64,
+}
+
+impl Default for FeeRule {
+    fn default() -> Self {
+        Self {
+            broker: 0.00025,
+            min_broker: Money::from(5.0),
+            transfer: 0.00002,
+            tax: 0.001,
+        }
+    }
+}
+
+impl FeeRule {
+    /// 各项费用分别四舍五入到分
+    pub fn get_fee_detail(&self, typ: &ActionType, price: Price, volume: u32) -> FeeDetail {
+        let total = price * volume;
+        FeeDetail {
+            broker: (total * self.broker).round_cent().max(self.min_broker),
+            transfer: (total * self.transfer).round_cent(),
+            tax: match typ {
+                ActionType::Sell => (total * self.tax).round_cent(),
+                ActionType::Buy => Money::ZERO,
+            },
+        }
+    }
+
+    pub fn get_fee(&self, typ: &ActionType, price: Price, volume: u32) -> Money {
+        self.get_fee_detail(typ, price, volume).total()
+    }
+}
+
+/// A股费用模型
+///
+/// 规则匹配顺序: `市场.品种`(如 `sh.fund`), `品种`(stock/fund/bond), `市场`(sh/sz/bj), 默认规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct AShareFee {
+    pub rule: FeeRule,
+    pub rules: HashMap<String, FeeRule>,
+}
+
+impl Default for AShareFee {
+    fn default() -> Self {
+        Self::new(FeeRule::default())
+    }
+}
+
+impl AShareFee {
+    /// 默认规则之外, 内置场内基金/可转债/北交所规则
+    pub fn new(rule: FeeRule) -> Self {
+        let mut rules = HashMap::new();
+        rules.insert(
+            "fund".to_string(),
+            FeeRule {
+                transfer: 0.0,
+                tax: 0.0,
+                ..rule.clone()
+            },
+        );
+        rules.insert(
+            "bond".to_string(),
+            FeeRule {
+                broker: 0.0001,
+                min_broker: Money::ZERO,
+                transfer: 0.0,
+                tax: 0.0,
+            },
+        );
+        rules.insert(
+            "bj".to_string(),
+            FeeRule {
+                transfer: 0.0,
+                ..rule.clone()
+            },
+        );
+        Self { rule, rules }
+    }
+
+    pub fn with_rules(mut self, rules: HashMap<String, FeeRule>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    fn category(kind: &Kind, instrument: &Instrument) -> &'static str {
+        match instrument.board {
+            Board::Bond => "bond",
+            Board::Fund => "fund",
+            _ => match kind {
+                Kind::Fund => "fund",
+                Kind::Stock => "stock",
+            },
+        }
+    }
+
+    pub fn get_rule(&self, kind: &Kind, code: &str) -> &FeeRule {
+        let instrument = match Instrument::parse(code) {
+            Ok(instrument) => instrument,
+            Err(_) => return &self.rule,
+        };
+        let market = instrument.exchange.prefix();
+        let category = Self::category(kind, &instrument);
+        self.rules
+            .get(&format!("{}.{}", market, category))
+            .or_else(|| self.rules.get(category))
+            .or_else(|| self.rules.get(market))
+            .unwrap_or(&self.rule)
+    }
+}
+
+impl FeeModel for AShareFee {
+    fn get_fee_detail(
+        &self,
+        kind: &Kind,
+        typ: &ActionType,
+        code: &str,
+        price: Price,
+        volume: u32,
+    ) -> FeeDetail {
+        self.get_rule(kind, code).get_fee_detail(typ, price, volume)
+    }
+}
+
+#[cfg(test)]
+mod test_fee {
+    use super::{AShareFee, FeeModel, FeeRule};
+    use crate::{ActionType, Kind, Money, Price};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_a_share_fee() {
+        let fee = AShareFee::default();
+        let stock = Kind::Stock;
+
+        // 最低佣金 5 元 + 过户费
+        let v = fee.get_fee(
+            &stock,
+            &ActionType::Buy,
+            "sh600063",
+            Price::from(10.0),
+            1000,
+        );
+        assert_eq!(v, Money::from(5.2));
+        // 卖出另收印花税
+        let v = fee.get_fee(
+            &stock,
+            &ActionType::Sell,
+            "sz000001",
+            Price::from(10.0),
+            1000,
+        );
+        assert_eq!(v, Money::from(15.2));
+        let detail = fee.get_fee_detail(
+            &stock,
+            &ActionType::Sell,
+            "sz000001",
+            Price::from(10.0),
+            1000,
+        );
+        assert_eq!(
+            (detail.broker, detail.transfer, detail.tax),
+            (Money::from(5.0), Money::from(0.2), Money::from(10.0))
+        );
+        // ETF 无印花税, 无过户费
+        let v = fee.get_fee(
+            &stock,
+            &ActionType::Sell,
+            "sh510300",
+            Price::from(4.0),
+            10000,
+        );
+        assert_eq!(v, Money::from(10.0));
+        // 北交所无过户费
+        let v = fee.get_fee(
+            &stock,
+            &ActionType::Buy,
+            "bj430047",
+            Price::from(10.0),
+            1000,
+        );
+        assert_eq!(v, Money::from(5.0));
+        // 可转债
+        let v = fee.get_fee(
+            &stock,
+            &ActionType::Sell,
+            "sz123001",
+            Price::from(100.0),
+            100,
+        );
+        assert_eq!(v, Money::from(1.0));
+
+        let mut rules = HashMap::new();
+        rules.insert(
+            "sz.stock".to_string(),
+            FeeRule {
+                min_broker: Money::ZERO,
+                ..Default::default()
+            },
+        );
+        let fee = AShareFee::default().with_rules(rules);
+        let v = fee.get_fee(
+            &stock,
+            &ActionType::Buy,
+            "sz000001",
+            Price::from(10.0),
+            1000,
+        );
+        assert_eq!(v, Money::from(2.7));
+    }
+}
@@ -0,0 +1,329 @@
+use super::entrust::{Entrust, EntrustStatus, EntrustType, OrderType};
+use super::quot::{QuotBar, QuotData};
+use crate::{Money, Price};
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+/// 模拟撮合, 按委托价格类型及最新行情成交
+///
+/// - 限价: 价格可成交时按委托价与最新价中较优者成交, 无行情时按委托价成交, 否则挂单等待
+/// - 市价: 按最新价成交, 无行情时按参考价成交
+/// - 最优五档: 按五档盘口逐档成交, 剩余撤销, 无盘口时同市价
+/// - 止损/止损限价: 最新价触及止损价后转为市价/限价委托
+///
+/// 成交时间取该代码最新k线的结束时间, 无该代码行情时取最新行情时间
+#[derive(Debug, Default)]
+pub struct SimMatcher {
+    bar: HashMap<String, QuotBar>,
+    pending: Vec<Entrust>,
+    // 最新行情时间
+    time: Option<NaiveDateTime>,
+}
+
+impl SimMatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_entrust(&mut self, entrust: &Entrust) -> Vec<Entrust> {
+        let mut e = entrust.clone();
+        match e.entrust_type {
+            EntrustType::Cancel => {
+                let found = self
+                    .pending
+                    .iter()
+                    .position(|p| p.entrust_id == e.entrust_id);
+                // 已成交或已失效的委托不可撤销
+                if found.is_none() {
+                    return vec![];
+                }
+                e = self.pending.remove(found.unwrap());
+                e.status = EntrustStatus::Cancel;
+                e.volume_deal = 0;
+                e.volume_cancel = e.volume;
+                vec![e]
+            }
+            EntrustType::Buy | EntrustType::Sell => match Self::check(&e) {
+                Some(reason) => {
+                    e.status = EntrustStatus::Rejected;
+                    e.reason = Some(reason.to_string());
+                    e.volume_cancel = e.volume;
+                    vec![e]
+                }
+                None => self.try_match(e),
+            },
+        }
+    }
+
+    pub fn on_quot(&mut self, quot: &QuotData) -> Vec<Entrust> {
+        match quot {
+            QuotData::Quot(bars) => {
+                for (code, bar) in bars.iter() {
+                    self.bar.insert(code.clone(), bar.clone());
+                }
+                if let Some(time) = bars.values().map(|bar| bar.end).max() {
+                    self.time = Some(time);
+                }
+                let pending: Vec<Entrust> = self.pending.drain(..).collect();
+                pending
+                    .into_iter()
+                    .flat_map(|e| self.try_match(e))
+                    .collect()
+            }
+            QuotData::NoonEnd(_) | QuotData::QuotEnd(_) => {
+                // 当日委托收盘失效
+                self.pending
+                    .drain(..)
+                    .map(|mut e| {
+                        e.status = EntrustStatus::Expired;
+                        e.volume_deal = 0;
+                        e.volume_cancel = e.volume;
+                        e
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// 委托检查, 不合法返回拒绝原因
+    fn check(e: &Entrust) -> Option<&'static str> {
+        if e.volume == 0 {
+            return Some("invalid volume");
+        }
+        match e.order_type {
+            OrderType::Limit | OrderType::StopLimit if e.price <= Price::ZERO => {
+                Some("invalid price")
+            }
+            OrderType::Stop | OrderType::StopLimit if e.stop_price <= Price::ZERO => {
+                Some("invalid stop price")
+            }
+            _ => None,
+        }
+    }
+
+    fn is_buy(e: &Entrust) -> bool {
+        matches!(e.entrust_type, EntrustType::Buy)
+    }
+
+    fn try_match(&mut self, mut e: Entrust) -> Vec<Entrust> {
+        let last = self.bar.get(&e.code).map(|bar| bar.close);
+        match e.order_type {
+            OrderType::Stop | OrderType::StopLimit => {
+                let triggered = match last {
+                    Some(last) if Self::is_buy(&e) => last >= e.stop_price,
+                    Some(last) => last <= e.stop_price,
+                    None => false,
+                };
+                if !triggered {
+                    e.status = EntrustStatus::Commit;
+                    self.pending.push(e);
+                    return vec![];
+                }
+                e.order_type = if matches!(e.order_type, OrderType::Stop) {
+                    OrderType::Market
+                } else {
+                    OrderType::Limit
+                };
+                self.try_match(e)
+            }
+            OrderType::Limit => match last {
+                Some(last)
+                    if (Self::is_buy(&e) && e.price < last)
+                        || (!Self::is_buy(&e) && e.price > last) =>
+                {
+                    e.status = EntrustStatus::Commit;
+                    self.pending.push(e);
+                    vec![]
+                }
+                Some(last) => {
+                    let price = if Self::is_buy(&e) {
+                        e.price.min(last)
+                    } else {
+                        e.price.max(last)
+                    };
+                    vec![self.deal(e, price)]
+                }
+                None => {
+                    let price = e.price;
+                    vec![self.deal(e, price)]
+                }
+            },
+            OrderType::Market => {
+                let price = last.unwrap_or(e.price);
+                vec![self.deal(e, price)]
+            }
+            OrderType::BestFiveMarket => self.match_best_five(e, last),
+        }
+    }
+
+    fn match_best_five(&self, e: Entrust, last: Option<Price>) -> Vec<Entrust> {
+        let levels = self.bar.get(&e.code).map(|bar| {
+            let (a, b, c, d, f) = if Self::is_buy(&e) {
+                bar.quot.ask
+            } else {
+                bar.quot.bid
+            };
+            vec![a, b, c, d, f]
+        });
+        let levels: Vec<(u32, Price)> = levels
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(volume, price)| *volume > 0 && *price > Price::ZERO)
+            .collect();
+        if levels.is_empty() {
+            let price = last.unwrap_or(e.price);
+            return vec![self.deal(e, price)];
+        }
+
+        let (mut volume, mut amount) = (0u32, Money::ZERO);
+        for (level_volume, level_price) in levels {
+            let v = level_volume.min(e.volume - volume);
+            volume += v;
+            amount += level_price * v;
+            if volume >= e.volume {
+                break;
+            }
+        }
+        let mut rs = vec![];
+        let remain = e.volume - volume;
+        if volume > 0 {
+            let mut deal = self.deal(e.clone(), amount.per(volume));
+            deal.volume_deal = volume;
+            if remain > 0 {
+                deal.status = EntrustStatus::PartDeal;
+            }
+            rs.push(deal);
+        }
+        if remain > 0 {
+            let mut cancel = e;
+            cancel.status = EntrustStatus::Cancel;
+            cancel.volume_deal = 0;
+            cancel.volume_cancel = remain;
+            rs.push(cancel);
+        }
+        rs
+    }
+
+    fn deal(&self, mut e: Entrust, price: Price) -> Entrust {
+        e.status = EntrustStatus::Deal;
+        e.price = price;
+        e.deal_time = self.bar.get(&e.code).map(|bar| bar.end).or(self.time);
+        e.volume_deal = e.volume;
+        e.volume_cancel = 0;
+        e
+    }
+}
+
+#[cfg(test)]
+mod test_matcher {
+    use super::SimMatcher;
+    use crate::fetch::Quot;
+    use crate::{
+        Entrust, EntrustStatus, EntrustType, OrderType, Price, QuotBar, QuotData, QuotOpts,
+        QuotStatus, RtQuotBar,
+    };
+    use chrono::NaiveDateTime;
+
+    fn quot(close: f64) -> QuotData {
+        let close = Price::from(close);
+        let mut bars = RtQuotBar::new();
+        bars.insert(
+            "sh600063".to_string(),
+            QuotBar {
+                frequency: 60,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                start: Default::default(),
+                end: time(),
+                quot: Quot {
+                    code: "sh600063".to_string(),
+                    now: close,
+                    ask: (
+                        (100, close),
+                        (200, close + Price::from(0.01)),
+                        (0, Price::ZERO),
+                        (0, Price::ZERO),
+                        (0, Price::ZERO),
+                    ),
+                    ..Default::default()
+                },
+                corp_action: None,
+            },
+        );
+        QuotData::Quot(bars)
+    }
+
+    fn time() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2022-03-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn entrust(order_type: OrderType, price: f64, stop_price: f64, volume: u32) -> Entrust {
+        Entrust {
+            entrust_id: "test".to_string(),
+            code: "sh600063".to_string(),
+            entrust_type: EntrustType::Buy,
+            order_type,
+            price: Price::from(price),
+            stop_price: Price::from(stop_price),
+            volume,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_sim_matcher() {
+        let mut matcher = SimMatcher::new();
+        matcher.on_quot(&quot(10.0));
+
+        let rs = matcher.on_entrust(&entrust(OrderType::Market, 0.0, 0.0, 100));
+        assert!(matches!(rs[0].status, EntrustStatus::Deal));
+        assert_eq!(rs[0].price, Price::from(10.0));
+        assert_eq!(rs[0].deal_time, Some(time()));
+
+        // 限价可成交时按较优的最新价成交
+        let rs = matcher.on_entrust(&entrust(OrderType::Limit, 10.5, 0.0, 100));
+        assert_eq!(rs[0].price, Price::from(10.0));
+        let mut sell = entrust(OrderType::Limit, 9.5, 0.0, 100);
+        sell.entrust_type = EntrustType::Sell;
+        let rs = matcher.on_entrust(&sell);
+        assert_eq!(rs[0].price, Price::from(10.0));
+
+        let rs = matcher.on_entrust(&entrust(OrderType::Limit, 9.5, 0.0, 100));
+        assert!(rs.is_empty());
+        let rs = matcher.on_quot(&quot(9.4));
+        assert_eq!(rs[0].price, Price::from(9.4));
+
+        let rs = matcher.on_entrust(&entrust(OrderType::Stop, 0.0, 10.0, 100));
+        assert!(rs.is_empty());
+        let rs = matcher.on_quot(&quot(10.2));
+        assert_eq!(rs[0].price, Price::from(10.2));
+
+        let rs = matcher.on_entrust(&entrust(OrderType::BestFiveMarket, 0.0, 0.0, 400));
+        assert!(matches!(rs[0].status, EntrustStatus::PartDeal));
+        assert_eq!(rs[0].volume_deal, 300);
+        assert_eq!(rs[0].price, Price::from_raw(102067));
+        assert!(matches!(rs[1].status, EntrustStatus::Cancel));
+        assert_eq!(rs[1].volume_cancel, 100);
+
+        let rs = matcher.on_entrust(&entrust(OrderType::Limit, 0.0, 0.0, 100));
+        assert!(matches!(rs[0].status, EntrustStatus::Rejected));
+        assert_eq!(rs[0].reason.as_deref(), Some("invalid price"));
+
+        let mut cancel = entrust(OrderType::Limit, 9.0, 0.0, 100);
+        cancel.entrust_type = EntrustType::Cancel;
+        assert!(matcher.on_entrust(&cancel).is_empty());
+
+        let rs = matcher.on_entrust(&entrust(OrderType::Limit, 9.0, 0.0, 100));
+        assert!(rs.is_empty());
+        let time = NaiveDateTime::parse_from_str("2022-03-01 15:00:00", "%Y-%m-%d %H:%M:%S");
+        let rs = matcher.on_quot(&QuotData::NoonEnd(QuotStatus {
+            opts: QuotOpts::default(),
+            time: time.unwrap(),
+        }));
+        assert!(matches!(rs[0].status, EntrustStatus::Expired));
+        assert_eq!(rs[0].volume_cancel, 100);
+    }
+}
@@ -10,11 +10,23 @@ pub use position::*;
 pub mod signal;
 pub use signal::*;
 
+pub mod corp_action;
+pub use corp_action::*;
+
+pub mod snapshot;
+pub use snapshot::*;
+
+pub mod fee;
+pub use fee::*;
+
 pub mod account;
 pub use account::*;
 
 pub mod quot;
 pub use quot::*;
 
+pub mod matcher;
+pub use matcher::*;
+
 pub mod event;
 pub use event::*;
\ No newline at end of file
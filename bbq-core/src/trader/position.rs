@@ -1,7 +1,54 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 
+use super::corp_action::CorpAction;
 use super::quot::QuotBar;
+use crate::{Deal, LotMethod, Money, Price};
+
+/// 持仓批次, 每笔买入成交生成一个批次
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct Lot {
+    // 批次id, 即买入成交id
+    pub lot_id: String,
+    // 买入时间
+    pub time: Option<NaiveDateTime>,
+    // 剩余数量
+    pub volume: u32,
+    // 买入价, 除权后调整
+    pub price: Price,
+    // 剩余数量分摊的买入手续费
+    pub fee: Money,
+}
+
+impl Lot {
+    /// 交易日 `date` 是否可卖(T+1), 无买入时间视为不可卖
+    pub fn is_available(&self, date: NaiveDate) -> bool {
+        self.time.is_some_and(|t| t.date() < date)
+    }
+}
+
+/// 平仓明细, 卖出成交按批次拆分
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct ClosedLot {
+    // 批次id
+    pub lot_id: String,
+    // 买入时间
+    pub open_time: Option<NaiveDateTime>,
+    // 卖出时间
+    pub close_time: Option<NaiveDateTime>,
+    // 平仓数量
+    pub volume: u32,
+    // 买入价
+    pub open_price: Price,
+    // 卖出价
+    pub close_price: Price,
+    // 平仓盈亏(含买入及卖出分摊手续费)
+    pub profit: Money,
+    // 持有天数
+    pub holding_days: f64,
+}
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, default, rename_all = "snake_case")]
@@ -19,18 +66,18 @@ pub struct Position {
     pub volume: u32,
     // 可用持仓量
     pub volume_available: u32,
-    // 可用持仓量
+    // 冻结持仓量
     pub volume_frozen: u32,
     // 持仓费用
-    pub fee: f64,
+    pub fee: Money,
     // 平均持仓价
-    pub price: f64,
+    pub price: Price,
     // 最新价
-    pub now_price: f64,
+    pub now_price: Price,
     // 最高价
-    pub max_price: f64,
+    pub max_price: Price,
     // 最低价
-    pub min_price: f64,
+    pub min_price: Price,
     // 盈利比例
     pub profit_rate: f64,
     // 最大盈利比例
@@ -39,19 +86,186 @@ pub struct Position {
     pub min_profit_rate: f64,
 
     // 盈利
-    pub profit: f64,
+    pub profit: Money,
     // 最大盈利
-    pub max_profit: f64,
+    pub max_profit: Money,
     // 最小盈利
-    pub min_profit: f64,
+    pub min_profit: Money,
 
     // 最大盈利时间
     pub max_profit_time: Option<NaiveDateTime>,
     // 最小盈利时间
     pub min_profit_time: Option<NaiveDateTime>,
+
+    // 持仓批次, 按买入时间排序
+    pub lots: Vec<Lot>,
 }
 
 impl Position {
+    /// 以建仓成交id作为持仓id
+    pub fn new_from_deal(deal: &Deal) -> Self {
+        Self {
+            position_id: deal.deal_id.clone(),
+            name: deal.name.clone(),
+            code: deal.code.clone(),
+            time: deal.time,
+            now_price: deal.price,
+            max_price: deal.price,
+            min_price: deal.price,
+            ..Default::default()
+        }
+    }
+
+    /// 批次与持仓量不一致(券商同步/历史数据)时, 以当前持仓合并为单一批次
+    pub fn reset_lots(&mut self) {
+        let volume: u32 = self.lots.iter().map(|lot| lot.volume).sum();
+        if volume == self.volume {
+            return;
+        }
+        self.lots.clear();
+        if self.volume > 0 {
+            self.lots.push(Lot {
+                lot_id: self.position_id.clone(),
+                time: self.time,
+                volume: self.volume,
+                price: self.price,
+                fee: self.fee,
+            });
+        }
+    }
+
+    /// 由批次重新计算持仓量, 持仓均价及持仓费用
+    fn update_cost(&mut self) {
+        self.volume = self.lots.iter().map(|lot| lot.volume).sum();
+        self.fee = self.lots.iter().map(|lot| lot.fee).sum();
+        if self.volume > 0 {
+            let cost: Money = self.lots.iter().map(|lot| lot.price * lot.volume).sum();
+            self.price = cost.per(self.volume);
+        }
+    }
+
+    /// 按交易日重算可卖数量, 当日买入批次不可卖(T+1)
+    pub fn settle(&mut self, date: NaiveDate) {
+        self.reset_lots();
+        let available: u32 = self
+            .lots
+            .iter()
+            .filter(|lot| lot.is_available(date))
+            .map(|lot| lot.volume)
+            .sum();
+        self.volume_available = available.saturating_sub(self.volume_frozen);
+    }
+
+    /// 买入成交, 新增批次并重新计算持仓均价, 当日买入不可卖
+    pub fn on_buy_deal(&mut self, deal: &Deal) {
+        self.reset_lots();
+        self.lots.push(Lot {
+            lot_id: deal.deal_id.clone(),
+            time: deal.time,
+            volume: deal.volume,
+            price: deal.price,
+            fee: deal.fee,
+        });
+        self.update_cost();
+    }
+
+    /// 卖出成交, 按批次减仓, 返回平仓盈亏(含买入分摊手续费及卖出手续费)及平仓明细
+    ///
+    /// 优先匹配可卖批次, 同为可卖或不可卖时按 `method` 先进先出/后进先出
+    pub fn on_sell_deal(&mut self, deal: &Deal, method: &LotMethod) -> (Money, Vec<ClosedLot>) {
+        self.reset_lots();
+        let volume = deal.volume.min(self.volume);
+        if volume == 0 {
+            return (-deal.fee, vec![]);
+        }
+
+        let mut order: Vec<usize> = (0..self.lots.len()).collect();
+        if matches!(method, LotMethod::Lifo) {
+            order.reverse();
+        }
+        if let Some(time) = &deal.time {
+            let date = time.date();
+            order.sort_by_key(|index| !self.lots[*index].is_available(date));
+        }
+
+        let (mut profit, mut closed, mut remain) = (Money::ZERO, vec![], volume);
+        let mut sell_fee_remain = deal.fee;
+        for index in order {
+            if remain == 0 {
+                break;
+            }
+            let lot = &mut self.lots[index];
+            let lot_volume = remain.min(lot.volume);
+            if lot_volume == 0 {
+                continue;
+            }
+            let buy_fee = lot.fee.prorate(lot_volume, lot.volume);
+            // 卖出手续费按数量分摊, 尾差计入最后一个批次
+            let sell_fee = if lot_volume == remain {
+                sell_fee_remain
+            } else {
+                deal.fee.prorate(lot_volume, volume)
+            };
+            sell_fee_remain -= sell_fee;
+            let lot_profit = (deal.price - lot.price) * lot_volume - buy_fee - sell_fee;
+            let holding_days = match (&lot.time, &deal.time) {
+                (Some(buy), Some(sell)) => (*sell - *buy).num_seconds() as f64 / 86400.0,
+                _ => 0.0,
+            };
+            closed.push(ClosedLot {
+                lot_id: lot.lot_id.clone(),
+                open_time: lot.time,
+                close_time: deal.time,
+                volume: lot_volume,
+                open_price: lot.price,
+                close_price: deal.price,
+                profit: lot_profit,
+                holding_days,
+            });
+
+            lot.fee -= buy_fee;
+            lot.volume -= lot_volume;
+            remain -= lot_volume;
+            profit += lot_profit;
+        }
+        self.lots.retain(|lot| lot.volume > 0);
+        self.update_cost();
+
+        let frozen = volume.min(self.volume_frozen);
+        self.volume_frozen -= frozen;
+        self.volume_available = self.volume_available.saturating_sub(volume - frozen);
+        if self.volume == 0 {
+            self.fee = Money::ZERO;
+        }
+        (profit, closed)
+    }
+
+    /// 除权除息, 返回(派现金额, 送转股数), 送转股按批次分配, 次日可卖
+    pub fn on_corp_action(&mut self, action: &CorpAction) -> (Money, u32) {
+        let cash = action.cash * self.volume;
+        let bonus = (action.share * self.volume as f64).floor() as u32;
+        if bonus > 0 {
+            self.reset_lots();
+            let ratio = self.volume as f64 / (self.volume + bonus) as f64;
+            let (count, mut remain) = (self.lots.len(), bonus);
+            for (index, lot) in self.lots.iter_mut().enumerate() {
+                let lot_bonus = if index + 1 == count {
+                    remain
+                } else {
+                    ((action.share * lot.volume as f64).floor() as u32).min(remain)
+                };
+                lot.price = lot.price * (lot.volume as f64 / (lot.volume + lot_bonus) as f64);
+                lot.volume += lot_bonus;
+                remain -= lot_bonus;
+            }
+            self.update_cost();
+            self.now_price = self.now_price * ratio;
+            self.max_price = self.max_price * ratio;
+            self.min_price = self.min_price * ratio;
+        }
+        (cash, bonus)
+    }
+
     pub fn on_update_quot(&mut self, quot_bar: &QuotBar) {
         self.now_price = quot_bar.close;
         if self.max_price < self.now_price {
@@ -60,23 +274,18 @@ impl Position {
         if self.min_price > self.now_price {
             self.min_price = self.now_price
         }
-        self.profit = (self.now_price - self.price) * self.volume as f64 - self.fee;
-        self.profit_rate = self.profit / (self.price * self.volume as f64 + self.fee);
-        let t = NaiveDateTime::parse_from_str(quot_bar.quot.time.as_str(), "%Y-%m-%d %H:%M:%S");
+        self.profit = (self.now_price - self.price) * self.volume - self.fee;
+        self.profit_rate = self.profit / (self.price * self.volume + self.fee);
         if self.profit > self.max_profit {
             self.max_profit = self.profit;
-            if let Ok(time) = &t {
-                self.max_profit_time = Some(time.clone());
-            }
+            self.max_profit_time = Some(quot_bar.quot.time);
 
             self.max_profit_rate = self.profit_rate;
         }
 
         if self.profit < self.min_profit {
             self.min_profit = self.profit;
-            if let Ok(time) = &t {
-                self.min_profit_time = Some(time.clone());
-            }
+            self.min_profit_time = Some(quot_bar.quot.time);
             self.min_profit_rate = self.profit_rate;
         }
     }
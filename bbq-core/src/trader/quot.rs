@@ -1,5 +1,5 @@
 use crate::fetch::{Quot};
-use crate::Kind;
+use crate::{CorpAction, Kind, Price};
 use chrono::{NaiveDate, NaiveDateTime};
 use mongodb::bson::doc;
 
@@ -20,14 +20,19 @@ pub type RtQuotBar = HashMap<String, QuotBar>;
 #[serde(rename_all = "snake_case")]
 pub struct QuotBar {
     pub frequency: u32,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub start: String,
-    pub end: String,
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
+    #[serde(with = "crate::time_fmt::datetime")]
+    pub start: NaiveDateTime,
+    #[serde(with = "crate::time_fmt::datetime")]
+    pub end: NaiveDateTime,
 
     pub quot: Quot,
+    // 除权除息, 仅除权除息日首个bar
+    #[serde(default)]
+    pub corp_action: Option<CorpAction>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,5 +63,8 @@ pub struct QuotOpts {
     pub codes: Vec<String>,
     pub start_date: Option<NaiveDate>,
     pub end_date: Option<NaiveDate>,
+    /// 基准代码, 如 sh000300, 随行情加载, 不可交易
+    #[serde(default)]
+    pub benchmark: Option<String>,
 }
 
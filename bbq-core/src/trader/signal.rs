@@ -3,6 +3,9 @@ use std::fmt::{Display, Formatter};
 use chrono::NaiveDateTime;
 use serde::{Serialize, Deserialize};
 
+use super::entrust::OrderType;
+use crate::Price;
+
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SignalType {
@@ -70,8 +73,12 @@ pub struct Signal {
     // 信号时间
     pub time: Option<NaiveDateTime>,
 
-    // 价格
-    pub price: f64,
+    // 委托价格类型
+    pub order_type: OrderType,
+    // 价格, 市价委托为参考价
+    pub price: Price,
+    // 止损触发价
+    pub stop_price: Price,
     // 量
     pub volume: u32,
     // 描述
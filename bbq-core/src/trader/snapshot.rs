@@ -0,0 +1,48 @@
+use crate::{Money, Price};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotType {
+    #[default]
+    Bar,
+    MorningEnd,
+    NoonEnd,
+    QuotEnd,
+}
+
+impl Display for SnapshotType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SnapshotType::Bar => "行情",
+            SnapshotType::MorningEnd => "早市结束",
+            SnapshotType::NoonEnd => "午市结束",
+            SnapshotType::QuotEnd => "行情结束",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 账户快照, 按时间顺序构成资金曲线
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default, rename_all = "snake_case")]
+pub struct AccountSnapshot {
+    pub typ: SnapshotType,
+    pub time: Option<NaiveDateTime>,
+    // 总净值
+    pub net_value: Money,
+    // 现金(可用 + 冻结)
+    pub cash: Money,
+    // 持仓市值
+    pub hold_value: Money,
+    // 仓位, 持仓市值 / 总净值
+    pub exposure: f64,
+    // 历史最高净值
+    pub max_net_value: Money,
+    // 回撤比例, (最高净值 - 总净值) / 最高净值
+    pub drawdown: f64,
+    // 基准最新价, 无基准为 0
+    pub benchmark: Price,
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use bbq_strategy::{Broker, Entrust, EntrustStatus, EntrustType, Event, Opts, BrokerEvent};
+use bbq_strategy::{Broker, BrokerEvent, Entrust, Event, Opts, QuotData, SimMatcher};
 use log::{error, info};
 
 use tokio::sync::mpsc::UnboundedSender;
@@ -9,11 +9,25 @@ use tokio::sync::mpsc::UnboundedSender;
 pub struct Example {
     tx: UnboundedSender<Event>,
     opts: Opts,
+    matcher: SimMatcher,
 }
 
 impl Example {
     fn new(tx: UnboundedSender<Event>, opts: Opts) -> Self {
-        Self { tx, opts }
+        Self {
+            tx,
+            opts,
+            matcher: SimMatcher::new(),
+        }
+    }
+
+    fn feedback(&self, entrusts: Vec<Entrust>) {
+        for mut e in entrusts {
+            e.desc = format!("example dll broker handled! {}", &e.desc[..]);
+            if let Err(e) = self.tx.send(Event::Broker(BrokerEvent::Entrust(e))) {
+                error!("broker feedback entrust event failed: {}!", e);
+            }
+        }
     }
 }
 
@@ -33,24 +47,14 @@ impl Broker for Example {
     }
     async fn on_entrust(&mut self, entrust: &Entrust) -> Result<()> {
         info!("on_entrust dll broker on_entrust: {:?}!", entrust);
-        let mut e = entrust.clone();
-        e.desc = format!("example dll broker handled! {}", &e.desc[..]);
-        match &e.entrust_type {
-            EntrustType::Buy | EntrustType::Sell => {
-                e.status = EntrustStatus::Deal;
-                e.volume_deal = e.volume;
-                e.volume_cancel = 0;
-            }
-            EntrustType::Cancel => {
-                e.status = EntrustStatus::Cancel;
-                e.volume_deal = 0;
-                e.volume_cancel = e.volume;
-            }
-        }
+        let entrusts = self.matcher.on_entrust(entrust);
+        self.feedback(entrusts);
 
-        if let Err(e) = self.tx.send(Event::Broker(BrokerEvent::Entrust(e))) {
-            error!("broker feedback entrust event failed: {}!", e);
-        }
+        Ok(())
+    }
+    async fn on_quot(&mut self, quot: &QuotData) -> Result<()> {
+        let entrusts = self.matcher.on_quot(quot);
+        self.feedback(entrusts);
 
         Ok(())
     }
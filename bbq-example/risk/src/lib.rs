@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use bbq_strategy::{
-    get_id, Account, Event, Opts, Position, Risk, Signal, SignalSource, SignalType
+    get_id, Account, Event, Money, Opts, OrderType, Position, Risk, Signal, SignalSource,
+    SignalType,
 };
 use chrono::Local;
 use log::{error, info};
@@ -13,10 +14,10 @@ pub struct SimpleStop {
     tx: UnboundedSender<Event>,
     opts: Opts,
     // 止损正数
-    lost: Option<f64>,
+    lost: Option<Money>,
     lost_rate: Option<f64>,
     // 止盈正数
-    profit: Option<f64>,
+    profit: Option<Money>,
     profit_rate: Option<f64>,
 }
 
@@ -44,6 +45,8 @@ impl SimpleStop {
             volume: position.volume_available,
             desc: format!("{} risk signal", self.name()),
             entrust_id: None,
+            order_type: OrderType::Market,
+            ..Default::default()
         };
         self.tx.send(Event::Signal(signal))?;
 
@@ -59,7 +62,7 @@ impl Risk for SimpleStop {
     async fn on_init(&mut self) -> Result<()> {
         if let Some(o) = &self.opts {
             if let Some(value) = o.get(&"profit".to_string()) {
-                let value: f64 = value.parse().with_context(|| "parse profit to f64 error")?;
+                let value: Money = value.parse().with_context(|| "parse profit to money error")?;
                 self.profit = Some(value);
             }
             if let Some(value) = o.get(&"profit_rate".to_string()) {
@@ -69,7 +72,7 @@ impl Risk for SimpleStop {
                 self.profit_rate = Some(value);
             }
             if let Some(value) = o.get(&"lost".to_string()) {
-                let value: f64 = value.parse().with_context(|| "parse lost to f64 error")?;
+                let value: Money = value.parse().with_context(|| "parse lost to money error")?;
                 self.lost = Some(value);
             }
             if let Some(value) = o.get(&"lost_rate".to_string()) {
@@ -114,7 +117,10 @@ impl Risk for SimpleStop {
                 }
                 if let Some(profit) = self.profit {
                     // 止盈
-                    if position.profit > 0.0 && profit > 0.0 && position.profit > profit {
+                    if position.profit > Money::ZERO
+                        && profit > Money::ZERO
+                        && position.profit > profit
+                    {
                         if let Err(e) = self.emit(position) {
                             error!("emit risk signal error: {}", e);
                         }
@@ -135,7 +141,7 @@ impl Risk for SimpleStop {
                 }
                 if let Some(lost) = self.lost {
                     // 止损
-                    if position.profit < 0.0 && position.profit < -lost.abs() {
+                    if position.profit < Money::ZERO && position.profit < -lost.abs() {
                         if let Err(e) = self.emit(position) {
                             error!("emit risk signal error: {}", e);
                         }
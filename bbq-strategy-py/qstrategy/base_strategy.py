@@ -56,7 +56,8 @@ class BaseStrategy:
                    code: str = '', name: str = '',
                    price: float = 0.0, volume: int = 0,
                    desc: str = '', entrust_id: str = None,
-                   time: datetime = None):
+                   time: datetime = None,
+                   order_type: str = Signal.ord_limit, stop_price: float = 0.0):
         if time is None:
             # todo
             time = datetime.now()
@@ -64,20 +65,23 @@ class BaseStrategy:
         return Signal(signal=Signal.sig_buy,
                       code=code, name=name,
                       price=price, volume=volume,
-                      desc=desc, entrust_id=entrust_id, time=time)
+                      desc=desc, entrust_id=entrust_id, time=time,
+                      order_type=order_type, stop_price=stop_price)
 
     def sell_signal(self, *,
                     code: str = '', name: str = '',
                     price: float = 0.0, volume: int = 0,
                     desc: str = '', entrust_id: str = None,
-                    time: datetime = None):
+                    time: datetime = None,
+                    order_type: str = Signal.ord_limit, stop_price: float = 0.0):
         if time is None:
             # todo
             time = datetime.now()
         return Signal(signal=Signal.sig_sell,
                       code=code, name=name,
                       price=price, volume=volume,
-                      desc=desc, entrust_id=entrust_id, time=time)
+                      desc=desc, entrust_id=entrust_id, time=time,
+                      order_type=order_type, stop_price=stop_price)
 
     def cancel_signal(self, *,
                       code: str = '', name: str = '',
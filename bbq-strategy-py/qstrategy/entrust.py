@@ -3,7 +3,10 @@ from datetime import datetime
 
 class Entrust:
     st_init, st_commit, st_deal, st_part_deal, st_cancel = 'init', 'commit', 'deal', 'part_deal', 'cancel'
+    st_part_cancel, st_rejected, st_expired = 'part_cancel', 'rejected', 'expired'
     typ_buy, typ_sell, typ_cancel = 'buy', 'sell', 'cancel'
+    ord_limit, ord_market, ord_best_five_market, ord_stop, ord_stop_limit = \
+        'limit', 'market', 'best_five_market', 'stop', 'stop_limit'
     def __init__(self, js):
         self.entrust_id = js['entrust_id']
         self.name = js['name']
@@ -12,9 +15,12 @@ class Entrust:
         self.volume_cancel = js['volume_cancel']
         self.volume = js['volume']
         self.price = js['price']
+        self.order_type = js['order_type'] if 'order_type' in js else Entrust.ord_limit
+        self.stop_price = js['stop_price'] if 'stop_price' in js else 0.0
         self.status = js['status']
         self.entrust_type = js['entrust_type']
         self.desc = js['desc']
+        self.reason = js['reason'] if 'reason' in js else None
         self.broker_entrust_id = js['broker_entrust_id'] if 'broker_entrust_id' in js else None
 
         self.time = None
@@ -23,6 +29,11 @@ class Entrust:
             self.time = js['time']
             self.time = datetime.strptime(s, '%Y-%m-%dT%H:%M:%S')
 
+        # 成交时间, 由券商回报, 未设置时以最新行情时间计
+        self.deal_time = None
+        if js.get('deal_time') is not None and isinstance(js['deal_time'], str):
+            self.deal_time = datetime.fromisoformat(js['deal_time'])
+
     def to_dict(self):
         d = {}
         d['entrust_id'] = self.entrust_id
@@ -33,12 +44,16 @@ class Entrust:
         d['volume_cancel'] = self.volume_cancel
         d['volume'] = self.volume
         d['price'] = self.price
+        d['order_type'] = self.order_type
+        d['stop_price'] = self.stop_price
         d['status'] = self.status
         d['entrust_type'] = self.entrust_type
         d['desc'] = self.desc
+        d['reason'] = self.reason
         d['broker_entrust_id'] = self.broker_entrust_id
 
         d['time'] = None if self.time is None else self.time.strftime(
             "%Y-%m-%dT%H:%M:%S.%f")
+        d['deal_time'] = None if self.deal_time is None else self.deal_time.isoformat()
 
         return d
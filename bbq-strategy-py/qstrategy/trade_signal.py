@@ -2,12 +2,15 @@ from datetime import datetime
 
 class Signal:
     sig_sell, sig_buy, sig_cancel = 'sell', 'buy', 'cancel'
+    ord_limit, ord_market, ord_best_five_market, ord_stop, ord_stop_limit = \
+        'limit', 'market', 'best_five_market', 'stop', 'stop_limit'
 
     def __init__(self, *, signal: str = '',
                  code: str = '', name: str = '',
                  price: float = 0.0, volume: int = 0,
                  desc: str = '', entrust_id: str = None,
-                 time: datetime = None):
+                 time: datetime = None,
+                 order_type: str = 'limit', stop_price: float = 0.0):
         self.signal = signal  # sell, buy, cancel
 
         self.name = name  # 股票名称
@@ -16,6 +19,8 @@ class Signal:
 
         self.price = price
         self.volume = volume
+        self.order_type = order_type  # limit, market, best_five_market, stop, stop_limit
+        self.stop_price = stop_price  # stop / stop_limit 有效
         self.desc = desc
 
         self.entrust_id = None  # sell / cancel 有效
@@ -29,6 +34,8 @@ class Signal:
             "%Y-%m-%dT%H:%M:%S.%f")
         d['price'] = self.price
         d['volume'] = self.volume
+        d['order_type'] = self.order_type
+        d['stop_price'] = self.stop_price
         d['desc'] = self.desc
         d['entrust_id'] = self.entrust_id
         return d
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use bbq_core::Entrust;
+use bbq_core::{Entrust, QuotData};
 
 #[async_trait]
 pub trait Broker: Send + Sync {
@@ -17,4 +17,9 @@ pub trait Broker: Send + Sync {
     async fn on_entrust(&mut self, _entrust: &Entrust) -> Result<()> {
         Ok(())
     }
+
+    /// 行情推送, 可用于撮合止损/限价委托
+    async fn on_quot(&mut self, _quot: &QuotData) -> Result<()> {
+        Ok(())
+    }
 }
@@ -1,10 +1,16 @@
 use crate::broker::Broker;
+use crate::config::Quotation;
+use crate::journal::{Journal, JournalData};
 use crate::risk::Risk;
+use crate::store::Store;
 use crate::{quotation, strategy, TaskTarget};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use bbq_core::Event;
-use bbq_core::{data::mongo::MongoDB, fetch::Sina, Account, AcctType, Entrust, QuotData, QuotOpts};
-use log::{debug, error, info};
+use bbq_core::{
+    analytics::Performance, data::MarketDataStore, fetch::Fetcher, Account, AcctType, Entrust,
+    InstrumentRegistry, QuotData, QuotOpts, Signal, SignalType, TradeCalendar,
+};
+use log::{debug, error, info, warn};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
@@ -18,7 +24,16 @@ use tokio::task::JoinHandle;
 pub struct AcctOpts {
     pub typ: AcctType,
     pub quot_opts: QuotOpts,
-    pub db: Option<MongoDB>,
+    // 行情源配置
+    pub quotation: Quotation,
+    // 交易日历
+    pub calendar: Arc<TradeCalendar>,
+    // 行情数据存储, 回测时读取日线
+    pub data_store: Option<Arc<dyn MarketDataStore>>,
+    // 本地存储, 保存资金曲线及账户
+    pub store: Option<Store>,
+    // 年化无风险利率, 用于计算绩效
+    pub risk_free: f64,
 
     pub broker_path: Option<String>,
     pub broker_opts: Option<HashMap<String, String>>,
@@ -45,6 +60,23 @@ pub async fn run(
         &account_id[..]
     );
 
+    let fetcher = opts
+        .quotation
+        .fetcher()
+        .with_context(|| "build quotation fetcher failed")?;
+
+    // 实盘/模拟盘按证券列表注册 ST/停牌 交易规则, 获取失败时按代码推断
+    let mut instruments = InstrumentRegistry::new();
+    if !matches!(acct_type, AcctType::Backtest) {
+        match fetcher.fetch_stock_list().await {
+            Ok(list) => {
+                let count = instruments.register_list(&list);
+                info!("account: {}, register {} instruments", &account_id[..], count);
+            }
+            Err(e) => warn!("account: {}, fetch stock list error: {}", &account_id[..], e),
+        }
+    }
+
     let mut handlers = vec![];
 
     let (shutdown, _) = broadcast::channel::<bool>(1);
@@ -82,9 +114,8 @@ pub async fn run(
     handlers.push(h);
 
     let (quot_tx, mut quot_rx, h) = run_quotation(
-        opts.typ.clone(),
-        opts.quot_opts.clone(),
-        opts.db.clone(),
+        &opts,
+        fetcher,
         shutdown.subscribe(),
         barrier.clone(),
         except_tx.clone(),
@@ -93,6 +124,22 @@ pub async fn run(
 
     barrier.wait().await;
 
+    // 交易日志, 记录账户初始状态及后续所有事件, 可重放重建账户
+    let journal = match &opts.store {
+        Some(store) => match store.journal(&account_id) {
+            Ok(journal) => Some(journal),
+            Err(e) => {
+                error!("account: {}, open journal error: {}", &account_id[..], e);
+                None
+            }
+        },
+        None => None,
+    };
+    if let Some(journal) = &journal {
+        journal.record(JournalData::Account(account.read().unwrap().clone()));
+    }
+
+    let mut snapshot_saved = account.read().unwrap().snapshot.len();
     let mut is_except = false;
 
     let (mut is_quot_end, mut is_strategy_end, mut is_risk_end, mut is_broker_end) =
@@ -133,17 +180,33 @@ pub async fn run(
                 }
 
                 let quot_data = quot_data.unwrap();
+                if let Some(journal) = &journal {
+                    journal.record(JournalData::Quot(quot_data.clone()));
+                }
 
                 {
                     let mut acct = account.write().unwrap();
                     acct.update_account_quot(&quot_data);
+                    if let Some(store) = &opts.store {
+                        for index in snapshot_saved..acct.snapshot.len() {
+                            let snapshot = &acct.snapshot[index];
+                            if let Err(e) = store.save_snapshot(&account_id, index, snapshot) {
+                                error!("account: {}, save snapshot error: {}", &account_id[..], e);
+                            }
+                        }
+                    }
+                    snapshot_saved = acct.snapshot.len();
                 }
+                instruments.on_quot(&quot_data);
                 match &quot_data {
                     QuotData::QuotEnd(_) => {
                         is_quot_end = true;
                     }
                     _ => {}
                 }
+                if let Err(e) = broker_entrust_tx.send(Event::Quot(quot_data.clone())){
+                    error!("account: {}, broker fail to dispatch quot, error: {}", &account_id[..], e);
+                }
                 if let Err(e) = strategy_quot_tx.send(quot_data.clone()){
                     error!("account: {}, strategy fail to dispatch quot, error: {}", &account_id[..], e);
                 }
@@ -163,16 +226,12 @@ pub async fn run(
                 }
                 let strategy_event = strategy_event.unwrap();
                 debug!("account strategy event: {:?}", &strategy_event);
+                if let Some(journal) = &journal {
+                    journal.record(JournalData::Strategy(strategy_event.clone()));
+                }
                 match &strategy_event {
                     Event::Signal(signal) => {
-                        {
-                            let mut acct = account.write().unwrap();
-                            acct.update_account_signal(signal);
-                        }
-                        // test signal
-                        let entrust = Entrust::new_from_signal(signal);
-
-                        if let Err(e) = broker_entrust_tx.send(Event::Entrust(entrust)) {
+                        if let Err(e) = dispatch_signal(&account, &instruments, signal, &broker_entrust_tx, journal.as_ref()) {
                             error!("account: {}, strategy dispatch broker entrust failed: {}", &account_id[..], e);
                         }
                     },
@@ -194,16 +253,13 @@ pub async fn run(
 
                 }
                 let risk_event = risk_event.unwrap();
+                if let Some(journal) = &journal {
+                    journal.record(JournalData::Risk(risk_event.clone()));
+                }
 
                 match &risk_event {
                     Event::Signal(signal) => {
-                        {
-                            let mut acct = account.write().unwrap();
-                            acct.update_account_signal(signal);
-                        }
-                        let entrust = Entrust::new_from_signal(signal);
-
-                        if let Err(e) = broker_entrust_tx.send(Event::Entrust(entrust)) {
+                        if let Err(e) = dispatch_signal(&account, &instruments, signal, &broker_entrust_tx, journal.as_ref()) {
                             error!("account: {}, risk dispatch broker entrust failed: {}", &account_id[..], e);
                         }
                     },
@@ -221,6 +277,9 @@ pub async fn run(
                 match push_event {
                     Event::Broker(broker_event) => {
                         info!("broker push event: {:?}", broker_event);
+                        if let Some(journal) = &journal {
+                            journal.record(JournalData::Broker(broker_event.clone()));
+                        }
                         let mut acct = account.write().unwrap();
                         acct.update_broker_push(&broker_event);
                     },
@@ -236,6 +295,30 @@ pub async fn run(
 
         }
     }
+    let performance = {
+        let acct = account.read().unwrap();
+        Performance::from_account(&acct, opts.risk_free)
+    };
+    info!(
+        "account: {}, performance: {}",
+        &account_id[..],
+        serde_json::to_string(&performance).unwrap_or_default()
+    );
+    if let Some(store) = &opts.store {
+        let acct = account.read().unwrap();
+        if let Err(e) = store
+            .save_account(&acct)
+            .and_then(|_| store.save_performance(&account_id, &performance))
+            .and_then(|_| store.flush())
+        {
+            error!("account: {}, save account error: {}", &account_id[..], e);
+        }
+    }
+    if let Some(journal) = &journal {
+        if let Err(e) = journal.flush() {
+            error!("account: {}, flush journal error: {}", &account_id[..], e);
+        }
+    }
     info!("account: {}, waiting subtask end", &account_id[..]);
     for h in handlers {
         match h.await {
@@ -247,6 +330,42 @@ pub async fn run(
     Ok(())
 }
 
+/// 信号按交易规则检查/取整后转委托, 记账(冻结资金/持仓)成功后记日志并发往券商
+fn dispatch_signal(
+    account: &Arc<RwLock<Account>>,
+    instruments: &InstrumentRegistry,
+    signal: &Signal,
+    broker_entrust_tx: &UnboundedSender<Event>,
+    journal: Option<&Journal>,
+) -> Result<()> {
+    let entrust = {
+        let mut acct = account.write().unwrap();
+        let available = match signal.signal {
+            SignalType::Sell => acct
+                .position
+                .get(&signal.code)
+                .map(|p| p.volume_available)
+                .unwrap_or_default(),
+            _ => 0,
+        };
+        let signal = instruments
+            .check_signal(signal, available)
+            .with_context(|| format!("signal {} rejected", &signal.signal_id))?;
+        let entrust = Entrust::new_from_signal(&signal);
+        // 委托被拒绝的信号不记录, 与日志重放一致
+        acct.update_account_entrust(&entrust)?;
+        acct.update_account_signal(&signal);
+        if let Some(journal) = journal {
+            journal.record(JournalData::Entrust((signal, entrust.clone())));
+        }
+        entrust
+    };
+    broker_entrust_tx
+        .send(Event::Entrust(entrust))
+        .with_context(|| "broker entrust channel closed")?;
+    Ok(())
+}
+
 fn run_broker(
     path: Option<String>,
     opts: Option<HashMap<String, String>>,
@@ -329,9 +448,8 @@ fn run_strategy(
 }
 
 fn run_quotation(
-    acct_type: AcctType,
-    opts: QuotOpts,
-    db: Option<MongoDB>,
+    acct_opts: &AcctOpts,
+    fetcher: Box<dyn Fetcher>,
     shutdown: broadcast::Receiver<bool>,
     barrier: Arc<Barrier>,
     except_tx: broadcast::Sender<TaskTarget>,
@@ -344,14 +462,17 @@ fn run_quotation(
     let (quot_tx, quot_rx) = mpsc::unbounded_channel();
     let (sub_tx, sub_rx) = mpsc::unbounded_channel();
 
-    let (interval, quot) = if matches!(acct_type, AcctType::Backtest) {
-        let quot = quotation::BacktestQuotation::new(opts, Box::new(Sina::new()), db);
+    let opts = acct_opts.quot_opts.clone();
+    let calendar = acct_opts.calendar.clone();
+    let (interval, quot) = if matches!(acct_opts.typ, AcctType::Backtest) {
+        let data_store = acct_opts.data_store.clone();
+        let quot = quotation::BacktestQuotation::new(opts, fetcher, data_store, calendar);
         (
             Some(Duration::from_millis(50)),
             Box::new(quot) as Box<dyn quotation::Quotation>,
         )
     } else {
-        let quot = quotation::RtQuotation::new(opts, Box::new(Sina::new()));
+        let quot = quotation::RtQuotation::new(opts, fetcher, calendar);
         (
             Some(Duration::from_secs(1)),
             Box::new(quot) as Box<dyn quotation::Quotation>,
@@ -374,9 +495,14 @@ fn run_quotation(
 #[cfg(test)]
 mod test_account {
 
-    use bbq_core::{Deal, Entrust, Position, Signal};
+    use bbq_core::{
+        AShareFee, Deal, Entrust, InstrumentRegistry, Money, Position, Price, Signal, SignalType,
+    };
+    use std::sync::{Arc, RwLock};
+    use tokio::sync::mpsc;
 
-    use super::Account;
+    use super::{dispatch_signal, Account};
+    use crate::journal::{Journal, JournalData};
 
     #[test]
     fn test_acct() {
@@ -391,4 +517,47 @@ mod test_account {
         let s = serde_json::to_string(&acct).unwrap();
         println!("{}", s);
     }
+
+    #[test]
+    fn test_dispatch_rejected() {
+        let path = std::env::temp_dir().join(format!("bbq-dispatch-{}", std::process::id()));
+        let journal = Journal::new(sled::open(&path).unwrap(), "test").unwrap();
+
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(100000.0);
+        acct.cash_available = Money::from(100000.0);
+        journal.record(JournalData::Account(acct.clone()));
+        let account = Arc::new(RwLock::new(acct));
+        let instruments = InstrumentRegistry::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let signal = |signal_id: &str, volume: u32| Signal {
+            signal_id: signal_id.to_string(),
+            signal: SignalType::Buy,
+            code: "sh600063".to_string(),
+            price: Price::from(10.0),
+            volume,
+            ..Default::default()
+        };
+
+        let dispatch =
+            |signal: &Signal| dispatch_signal(&account, &instruments, signal, &tx, Some(&journal));
+        dispatch(&signal("s1", 1000)).unwrap();
+        // 资金不足, 委托被拒绝
+        assert!(dispatch(&signal("s2", 100000)).is_err());
+        assert!(rx.try_recv().is_ok());
+        assert!(rx.try_recv().is_err());
+        journal.flush().unwrap();
+
+        let replay = journal.replay(Arc::new(AShareFee::default())).unwrap();
+        let acct = account.read().unwrap();
+        assert_eq!(acct.signal.len(), 1);
+        assert_eq!(replay.signal.len(), 1);
+        assert_eq!(
+            serde_json::to_string(&*acct).unwrap(),
+            serde_json::to_string(&replay).unwrap()
+        );
+
+        drop(journal);
+        let _ = std::fs::remove_dir_all(&path);
+    }
 }
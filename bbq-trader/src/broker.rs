@@ -1,6 +1,6 @@
 use anyhow::{bail, Context, Ok, Result};
-use bbq_core::{trader::entrust::EntrustStatus, EntrustType};
-use bbq_core::{BrokerEvent, Event};
+use bbq_core::EntrustType;
+use bbq_core::{BrokerEvent, Event, SimMatcher};
 use bbq_strategy::NewBrokerFunc;
 use convert_case::{Case, Casing};
 use log::{debug, error, info};
@@ -302,6 +302,11 @@ impl Broker {
                                 error!("broker dll entrust failed: {}!", e);
                             }
                         },
+                        Event::Quot(quot) => {
+                            if let Err(e) = broker.on_quot(&quot).await {
+                                error!("broker dll quot failed: {}!", e);
+                            }
+                        },
                         Event::EventNone(_) => {
                             break;
                         },
@@ -323,6 +328,7 @@ impl Broker {
     }
     async fn run_default(&mut self) -> Result<()> {
         info!("run default broker(simulation broker)");
+        let mut matcher = SimMatcher::new();
         let mut is_entrust_tx_end = false;
         loop {
             tokio::select! {
@@ -336,23 +342,19 @@ impl Broker {
                     match event {
                         Event::Entrust(e) => {
                             info!("broker recv: {:?}", &e);
-                            let mut e = e.clone();
-                            e.desc = format!("default simulation broker handled! {}", &e.desc[..]);
-                            match &e.entrust_type {
-                                EntrustType::Buy | EntrustType::Sell  => {
-                                    e.status = EntrustStatus::Deal;
-                                    e.volume_deal = e.volume;
-                                    e.volume_cancel = 0;
-                                },
-                                EntrustType::Cancel => {
-                                    e.status = EntrustStatus::Cancel;
-                                    e.volume_deal = 0;
-                                    e.volume_cancel = e.volume;
-                                },
+                            for mut e in matcher.on_entrust(&e) {
+                                e.desc = format!("default simulation broker handled! {}", &e.desc[..]);
+                                if let Err(e) = self.tx.send(Event::Broker(BrokerEvent::Entrust(e))) {
+                                    error!("broker feedback entrust event failed: {}!", e);
+                                }
                             }
-
-                            if let Err(e) = self.tx.send(Event::Broker(BrokerEvent::Entrust(e))) {
-                                error!("broker feedback entrust event failed: {}!", e);
+                        },
+                        Event::Quot(quot) => {
+                            for mut e in matcher.on_quot(&quot) {
+                                e.desc = format!("default simulation broker handled! {}", &e.desc[..]);
+                                if let Err(e) = self.tx.send(Event::Broker(BrokerEvent::Entrust(e))) {
+                                    error!("broker feedback entrust event failed: {}!", e);
+                                }
                             }
                         },
                         Event::EventNone(_) => {
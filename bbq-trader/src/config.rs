@@ -1,16 +1,31 @@
-use std::{fs, path::Path};
-use anyhow::{Context, Ok, Result};
-use bbq_core::Kind;
+use std::{collections::HashMap, fmt::Display, fs, path::Path, sync::Arc, time::Duration};
+use anyhow::{bail, Context, Ok, Result};
+use bbq_core::{
+    data::{MarketDataStore, SyncOpts},
+    fetch::{FailoverFetcher, FailoverOpts, Fetcher, HttpOpts, Sina, SinaOpts, Tencent, TencentOpts},
+    AShareFee, FeeModel, FeeRule, Kind, LotMethod, Money, SyncPolicy, TradeCalendar,
+};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, default, rename_all = "snake_case")]
 pub struct Config {
-    pub init_cash: f64,
+    pub init_cash: Money,
     pub data_path: String,
     pub mongodb: Option<String>,
+    pub data_store: DataStore,
     pub kind: Kind,
+    pub sync_policy: SyncPolicy,
+    pub lot_method: LotMethod,
+    /// 基准指数代码, 如 sh000300, 不配置不计算相对基准绩效
+    pub benchmark: Option<String>,
+    /// 年化无风险利率, 用于计算夏普/索提诺比率及 alpha
+    pub risk_free: f64,
     pub fee: Fee,
+    pub quotation: Quotation,
+    pub calendar: Calendar,
+    pub sync: MarketSync,
     pub log: Log,
     pub listen: Listen,
     pub push: Push,
@@ -20,10 +35,6 @@ pub struct Config {
 impl Config {
     pub fn from_str(s: &str) -> Result<Self> {
         let cfg: Config = toml::from_str(s).with_context(|| "load cfg failed")?;
-        std::env::set_var(
-            "BBQ_TRADE_DATE",
-            format!("{}/trade_date.txt", cfg.data_path.as_str()).as_str(),
-        );
         Ok(cfg)
     }
     pub fn write<P: AsRef<Path>>(&self, path: Option<P>) -> Result<()> {
@@ -56,8 +67,16 @@ impl Default for Config {
         let def = Self {
             init_cash: Default::default(),
             data_path: Default::default(),
+            data_store: Default::default(),
             kind: Default::default(),
+            sync_policy: Default::default(),
+            lot_method: Default::default(),
+            benchmark: None,
+            risk_free: 0.0,
             fee: Default::default(),
+            quotation: Default::default(),
+            calendar: Default::default(),
+            sync: Default::default(),
             log: Default::default(),
             push: Default::default(),
             strategy: Default::default(),
@@ -81,11 +100,6 @@ impl Default for Config {
 
             let data_path = h.to_str().map(|s| String::from(s)).unwrap();
 
-            std::env::set_var(
-                "BBQ_TRADE_DATE",
-                format!("{}/trade_date.txt", data_path.as_str()).as_str(),
-            );
-
             let log_path = log.to_str().map(|s| String::from(s)).unwrap();
 
             let strategy = vec![strategy.to_str().map(|s| String::from(s)).unwrap()];
@@ -95,10 +109,18 @@ impl Default for Config {
             let risk = vec![risk.to_str().map(|s| String::from(s)).unwrap()];
 
             let def = Self {
-                init_cash: 10_000.0,
+                init_cash: Money::from(10_000.0),
                 data_path,
+                data_store: Default::default(),
                 kind: Default::default(),
+                sync_policy: Default::default(),
+                lot_method: Default::default(),
+                benchmark: None,
+                risk_free: 0.0,
                 fee: Default::default(),
+                quotation: Default::default(),
+                calendar: Default::default(),
+                sync: Default::default(),
                 log: Log {
                     level: "debug".to_string(),
                     path: log_path,
@@ -119,22 +141,242 @@ impl Default for Config {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
 pub struct Fee {
+    /// 费用模型: a_share
+    pub model: String,
     pub broker: f64,
+    pub min_broker: Money,
     pub transfer: f64,
     pub tax: f64,
+    /// 按 `市场.品种`/`品种`/`市场` 覆盖的费率规则, 如: sh.fund, bond, bj
+    pub rules: HashMap<String, FeeRule>,
 }
 
 impl Default for Fee {
     fn default() -> Self {
         Self {
+            model: "a_share".to_string(),
             broker: 0.00025,
+            min_broker: Money::from(5.0),
             transfer: 0.00002,
             tax: 0.001,
+            rules: HashMap::new(),
+        }
+    }
+}
+
+impl Fee {
+    pub fn fee_model(&self) -> Result<Arc<dyn FeeModel>> {
+        match self.model.as_str() {
+            "a_share" => {
+                let rule = FeeRule {
+                    broker: self.broker,
+                    min_broker: self.min_broker,
+                    transfer: self.transfer,
+                    tax: self.tax,
+                };
+                Ok(Arc::new(AShareFee::new(rule).with_rules(self.rules.clone())))
+            }
+            _ => bail!("unknown fee model: {}", &self.model),
         }
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct Quotation {
+    /// 行情源, 按优先级排列: sina / tencent
+    pub fetcher: Vec<String>,
+    /// 错误率超过该值时暂时降级
+    pub error_rate: f64,
+    /// 降级时长(秒)
+    pub demote_secs: u64,
+    /// 双源行情比对的最大价差比例, 不配置不比对
+    pub cross_check: Option<f64>,
+    /// 请求模式: live / record / replay
+    pub http: HttpOpts,
+    /// 行情源服务地址, 不配置使用默认地址
+    pub endpoint: HashMap<String, Endpoint>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct Endpoint {
+    pub quot_url: Option<String>,
+    pub kline_url: Option<String>,
+    // 复权因子, 仅 sina 支持
+    pub factor_url: Option<String>,
+    // 证券列表, 仅 sina 支持
+    pub list_url: Option<String>,
+}
+
+impl Default for Quotation {
+    fn default() -> Self {
+        Self {
+            fetcher: vec!["sina".to_string(), "tencent".to_string()],
+            error_rate: 0.5,
+            demote_secs: 60,
+            cross_check: None,
+            http: Default::default(),
+            endpoint: Default::default(),
+        }
+    }
+}
+
+impl Quotation {
+    pub fn fetcher(&self) -> Result<Box<dyn Fetcher>> {
+        if self.fetcher.is_empty() {
+            bail!("no quotation fetcher configured");
+        }
+        let opts = FailoverOpts {
+            error_rate: self.error_rate,
+            demote: Duration::from_secs(self.demote_secs),
+            cross_check: self.cross_check,
+            ..Default::default()
+        };
+        let mut fetcher = FailoverFetcher::new(opts);
+        for name in self.fetcher.iter() {
+            let endpoint = self.endpoint.get(name).cloned().unwrap_or_default();
+            let source: Box<dyn Fetcher> = match name.as_str() {
+                "sina" => {
+                    let mut opts = SinaOpts {
+                        http: self.http.clone(),
+                        ..Default::default()
+                    };
+                    if let Some(url) = endpoint.quot_url {
+                        opts.quot_url = url;
+                    }
+                    if let Some(url) = endpoint.kline_url {
+                        opts.kline_url = url;
+                    }
+                    if let Some(url) = endpoint.factor_url {
+                        opts.factor_url = url;
+                    }
+                    if let Some(url) = endpoint.list_url {
+                        opts.list_url = url;
+                    }
+                    Box::new(Sina::with_opts(opts))
+                }
+                "tencent" => {
+                    if endpoint.factor_url.is_some() || endpoint.list_url.is_some() {
+                        bail!("tencent fetcher does not support factor_url/list_url");
+                    }
+                    let mut opts = TencentOpts {
+                        http: self.http.clone(),
+                        ..Default::default()
+                    };
+                    if let Some(url) = endpoint.quot_url {
+                        opts.quot_url = url;
+                    }
+                    if let Some(url) = endpoint.kline_url {
+                        opts.kline_url = url;
+                    }
+                    Box::new(Tencent::with_opts(opts))
+                }
+                _ => bail!("unknown quotation fetcher: {}", name),
+            };
+            fetcher = fetcher.with_source(name, source);
+        }
+        Ok(Box::new(fetcher))
+    }
+}
+
+/// 行情数据存储
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataStore {
+    // MongoDB, 地址为 mongodb
+    #[default]
+    Mongo,
+    // 本地存储, {data_path}/market.db
+    Local,
+}
+
+impl Display for DataStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match &self {
+            DataStore::Mongo => "MongoDB",
+            DataStore::Local => "本地存储",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 行情数据同步
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct MarketSync {
+    /// 是否在每个交易日定时同步
+    pub enable: bool,
+    /// 同步模式, 数据类别, 代码, 起始日期及同步时间
+    #[serde(flatten)]
+    pub opts: SyncOpts,
+}
+
+/// 交易日历来源
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarSource {
+    // 内置日历
+    #[default]
+    Builtin,
+    // 交易日文件
+    File,
+    // 行情数据存储上证指数日线
+    #[serde(alias = "mongo")]
+    Store,
+    // 行情源上证指数日线
+    Fetcher,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "snake_case")]
+pub struct Calendar {
+    /// 交易日历来源: builtin / file / store / fetcher
+    pub source: CalendarSource,
+    /// 交易日文件, 默认为 {data_path}/trade_date.txt, 默认文件不存在时使用内置日历
+    pub path: Option<String>,
+    /// 半日市日期
+    pub half_day: Vec<NaiveDate>,
+}
+
+impl Calendar {
+    pub async fn load(
+        &self,
+        data_path: &str,
+        store: Option<&dyn MarketDataStore>,
+        quotation: &Quotation,
+    ) -> Result<TradeCalendar> {
+        let calendar = match self.source {
+            CalendarSource::Builtin => TradeCalendar::builtin(),
+            CalendarSource::File => match &self.path {
+                Some(path) => TradeCalendar::from_file(path)?,
+                None => {
+                    let path = format!("{}/trade_date.txt", data_path);
+                    if Path::new(&path).exists() {
+                        TradeCalendar::from_file(&path)?
+                    } else {
+                        TradeCalendar::builtin()
+                    }
+                }
+            },
+            CalendarSource::Store => match store {
+                Some(store) => TradeCalendar::from_store(store).await?,
+                None => bail!("no data store for trade calendar"),
+            },
+            CalendarSource::Fetcher => {
+                let fetcher = quotation.fetcher()?;
+                TradeCalendar::from_fetcher(fetcher.as_ref()).await?
+            }
+        };
+        Ok(self
+            .half_day
+            .iter()
+            .fold(calendar, |calendar, date| calendar.with_half_day(*date)))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Log {
     pub level: String,
@@ -194,7 +436,10 @@ pub struct Strategy {
 
 #[cfg(test)]
 mod test {
-    use crate::config::Config;
+    use crate::config::{Calendar, CalendarSource, Config, MarketSync, Quotation};
+    use bbq_core::data::{DataKind, SyncMode};
+    use bbq_core::fetch::HttpMode;
+    use chrono::{NaiveDate, NaiveTime};
 
     #[test]
     fn test_config() {
@@ -202,5 +447,137 @@ mod test {
         println!("cfg: {:?}", cfg);
 
         cfg.write::<String>(None).unwrap();
+        assert!(cfg.benchmark.is_none());
+
+        let cfg = Config::from_str(r#"benchmark = "sh000300""#).unwrap();
+        assert_eq!(cfg.benchmark.as_deref(), Some("sh000300"));
+        assert_eq!(cfg.risk_free, 0.0);
+
+        let cfg = Config::from_str("risk_free = 0.02").unwrap();
+        assert_eq!(cfg.risk_free, 0.02);
+    }
+
+    #[test]
+    fn test_quotation() {
+        let cfg: Quotation = toml::from_str(
+            r#"
+            fetcher = ["tencent", "sina"]
+            cross_check = 0.01
+
+            [http]
+            mode = "replay"
+            path = "/tmp/bbq-replay"
+
+            [endpoint.sina]
+            quot_url = "http://127.0.0.1:8080"
+            list_url = "http://127.0.0.1:8081"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.demote_secs, 60);
+        assert_eq!(cfg.http.mode, HttpMode::Replay);
+        assert_eq!(
+            cfg.endpoint["sina"].quot_url.as_deref(),
+            Some("http://127.0.0.1:8080")
+        );
+        assert!(cfg.endpoint["sina"].kline_url.is_none());
+        assert_eq!(
+            cfg.endpoint["sina"].list_url.as_deref(),
+            Some("http://127.0.0.1:8081")
+        );
+        assert!(cfg.fetcher().is_ok());
+
+        let mut tencent = cfg.clone();
+        tencent
+            .endpoint
+            .insert("tencent".to_string(), tencent.endpoint["sina"].clone());
+        assert!(tencent.fetcher().is_err());
+
+        let cfg = Quotation {
+            fetcher: vec!["unknown".to_string()],
+            ..Default::default()
+        };
+        assert!(cfg.fetcher().is_err());
+        let cfg = Quotation {
+            fetcher: vec![],
+            ..Default::default()
+        };
+        assert!(cfg.fetcher().is_err());
+    }
+
+    #[test]
+    fn test_sync() {
+        let cfg: MarketSync = toml::from_str(
+            r#"
+            enable = true
+            mode = "full"
+            time = "17:00:00"
+            kinds = ["stock_daily", "index_daily"]
+            start_date = "2020-01-01"
+            "#,
+        )
+        .unwrap();
+        assert!(cfg.enable);
+        assert_eq!(cfg.opts.mode, SyncMode::Full);
+        assert_eq!(cfg.opts.time, NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+        assert_eq!(cfg.opts.kinds, vec![DataKind::StockDaily, DataKind::IndexDaily]);
+        assert_eq!(cfg.opts.start_date, NaiveDate::from_ymd_opt(2020, 1, 1));
+        assert!(cfg.opts.codes.is_empty());
+
+        let cfg: MarketSync = toml::from_str("").unwrap();
+        assert!(!cfg.enable);
+        assert_eq!(cfg.opts.mode, SyncMode::Incremental);
+        assert!(cfg.opts.kinds.contains(&DataKind::StockFqFactor));
+        assert!(cfg.opts.validate().is_ok());
+
+        let cfg: MarketSync = toml::from_str(r#"kinds = ["stock_index"]"#).unwrap();
+        assert!(cfg.opts.validate().is_err());
+    }
+
+    #[test]
+    fn test_calendar() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let cfg: Calendar = toml::from_str(
+                r#"
+                source = "builtin"
+                half_day = ["2022-03-04"]
+                "#,
+            )
+            .unwrap();
+            assert_eq!(cfg.source, CalendarSource::Builtin);
+            let calendar = cfg.load("", None, &Quotation::default()).await.unwrap();
+            let half_day = NaiveDate::from_ymd_opt(2022, 3, 4).unwrap();
+            assert!(calendar.is_trading_day(half_day));
+            assert_eq!(calendar.sessions(half_day).len(), 1);
+
+            let cfg = Calendar {
+                source: CalendarSource::File,
+                path: Some(
+                    concat!(env!("CARGO_MANIFEST_DIR"), "/../bbq-core/src/trade_date.txt")
+                        .to_string(),
+                ),
+                ..Default::default()
+            };
+            assert!(cfg.load("", None, &Quotation::default()).await.is_ok());
+            let cfg = Calendar::default();
+            assert_eq!(cfg.source, CalendarSource::Builtin);
+            assert!(cfg.load("/nonexistent", None, &Quotation::default()).await.is_ok());
+
+            // 未配置路径且默认文件不存在时使用内置日历, 配置的路径不存在时返回错误
+            let mut cfg = Calendar {
+                source: CalendarSource::File,
+                ..Default::default()
+            };
+            assert!(cfg.load("/nonexistent", None, &Quotation::default()).await.is_ok());
+            cfg.path = Some("/nonexistent/trade_date.txt".to_string());
+            assert!(cfg.load("", None, &Quotation::default()).await.is_err());
+            let cfg: Calendar = toml::from_str(r#"source = "mongo""#).unwrap();
+            assert_eq!(cfg.source, CalendarSource::Store);
+            assert!(cfg.load("", None, &Quotation::default()).await.is_err());
+        });
     }
 }
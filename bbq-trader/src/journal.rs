@@ -0,0 +1,201 @@
+use anyhow::{Context, Result};
+use bbq_core::{Account, BrokerEvent, Entrust, Event, FeeModel, QuotData, Signal};
+use chrono::{Local, NaiveDateTime};
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// 交易日志内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalData {
+    /// 账户初始状态, 重放时以此为起点
+    Account(Account),
+    /// 行情
+    Quot(QuotData),
+    /// 策略事件
+    Strategy(Event),
+    /// 风控事件
+    Risk(Event),
+    /// 记账后发往券商的委托, 信号为按交易规则调整后的信号
+    Entrust((Signal, Entrust)),
+    /// 券商推送
+    Broker(BrokerEvent),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct JournalEntry {
+    pub seq: u64,
+    // 记录时间
+    pub time: NaiveDateTime,
+    pub data: JournalData,
+}
+
+/// 账户交易日志(sled), 顺序追加账户运行过程中的所有事件, 可重放重建账户
+#[derive(Clone)]
+pub struct Journal {
+    db: sled::Db,
+    tree: sled::Tree,
+}
+
+impl Journal {
+    pub fn new(db: sled::Db, account_id: &str) -> Result<Self> {
+        let tree = db
+            .open_tree(format!("journal:{}", account_id))
+            .with_context(|| format!("failed to open journal: {}", account_id))?;
+        Ok(Self { db, tree })
+    }
+
+    pub fn append(&self, data: JournalData) -> Result<u64> {
+        let seq = self
+            .db
+            .generate_id()
+            .with_context(|| "failed to generate journal id")?;
+        let entry = JournalEntry {
+            seq,
+            time: Local::now().naive_local(),
+            data,
+        };
+        let value = serde_json::to_vec(&entry).with_context(|| "failed to serialize journal")?;
+        self.tree
+            .insert(seq.to_be_bytes(), value)
+            .with_context(|| "failed to append journal")?;
+        Ok(seq)
+    }
+
+    /// 追加失败仅记录错误, 不影响交易
+    pub fn record(&self, data: JournalData) {
+        if let Err(e) = self.append(data) {
+            error!("journal record error: {}", e);
+        }
+    }
+
+    pub fn entries(&self) -> Result<Vec<JournalEntry>> {
+        let mut list = Vec::new();
+        for item in self.tree.iter() {
+            let (_, value) = item.with_context(|| "failed to read journal")?;
+            let entry =
+                serde_json::from_slice(&value).with_context(|| "failed to deserialize journal")?;
+            list.push(entry);
+        }
+        Ok(list)
+    }
+
+    pub fn flush(&self) -> Result<()> {
+        self.tree.flush().with_context(|| "failed to flush journal")?;
+        Ok(())
+    }
+
+    /// 重放日志重建账户, 费用模型不随账户序列化, 需由调用方提供
+    pub fn replay(&self, fee_model: Arc<dyn FeeModel>) -> Result<Account> {
+        let mut account = Account {
+            fee_model: fee_model.clone(),
+            ..Default::default()
+        };
+        for entry in self.entries()? {
+            match entry.data {
+                JournalData::Account(acct) => {
+                    account = acct;
+                    account.fee_model = fee_model.clone();
+                }
+                JournalData::Quot(quot) => account.update_account_quot(&quot),
+                JournalData::Entrust((signal, entrust)) => {
+                    account
+                        .update_account_entrust(&entrust)
+                        .with_context(|| format!("replay entrust failed, seq: {}", entry.seq))?;
+                    account.update_account_signal(&signal);
+                }
+                JournalData::Broker(event) => account.update_broker_push(&event),
+                JournalData::Strategy(_) | JournalData::Risk(_) => {}
+            }
+        }
+        Ok(account)
+    }
+}
+
+#[cfg(test)]
+mod test_journal {
+    use super::{Journal, JournalData};
+    use bbq_core::{
+        fetch::Quot, time_fmt, AShareFee, Account, BrokerEvent, Entrust, EntrustStatus, Money, Price,
+        QuotBar, QuotData, RtQuotBar, Signal, SignalType,
+    };
+    use std::sync::Arc;
+
+    fn quot(close: f64) -> QuotData {
+        let close = Price::from(close);
+        let mut bars = RtQuotBar::new();
+        bars.insert(
+            "sh600063".to_string(),
+            QuotBar {
+                frequency: 60,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                start: time_fmt::parse_datetime("2022-03-01 09:30:00").unwrap(),
+                end: time_fmt::parse_datetime("2022-03-01 09:31:00").unwrap(),
+                quot: Quot {
+                    code: "sh600063".to_string(),
+                    now: close,
+                    ..Default::default()
+                },
+                corp_action: None,
+            },
+        );
+        QuotData::Quot(bars)
+    }
+
+    #[test]
+    fn test_replay() {
+        let path = std::env::temp_dir().join(format!("bbq-journal-{}", std::process::id()));
+        let db = sled::open(&path).unwrap();
+        let journal = Journal::new(db, "test").unwrap();
+
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(100000.0);
+        acct.cash_available = Money::from(100000.0);
+        journal.record(JournalData::Account(acct.clone()));
+
+        let q = quot(10.0);
+        acct.update_account_quot(&q);
+        journal.record(JournalData::Quot(q));
+
+        let signal = Signal {
+            signal_id: "s1".to_string(),
+            signal: SignalType::Buy,
+            code: "sh600063".to_string(),
+            price: Price::from(10.0),
+            volume: 1000,
+            ..Default::default()
+        };
+        let entrust = Entrust::new_from_signal(&signal);
+        acct.update_account_entrust(&entrust).unwrap();
+        acct.update_account_signal(&signal);
+        journal.record(JournalData::Entrust((signal, entrust.clone())));
+
+        let mut deal = entrust.clone();
+        deal.status = EntrustStatus::Deal;
+        deal.volume_deal = deal.volume;
+        let event = BrokerEvent::Entrust(deal);
+        acct.update_broker_push(&event);
+        journal.record(JournalData::Broker(event));
+
+        let q = quot(11.0);
+        acct.update_account_quot(&q);
+        journal.record(JournalData::Quot(q));
+        journal.flush().unwrap();
+
+        let replay = journal.replay(Arc::new(AShareFee::default())).unwrap();
+        assert_eq!(
+            serde_json::to_string(&acct).unwrap(),
+            serde_json::to_string(&replay).unwrap()
+        );
+        assert_eq!(replay.position.get("sh600063").unwrap().volume, 1000);
+        assert_eq!(journal.entries().unwrap().len(), 5);
+
+        drop(journal);
+        let _ = std::fs::remove_dir_all(&path);
+    }
+}
@@ -6,6 +6,9 @@ pub mod trader;
 
 pub mod config;
 
+pub mod journal;
+pub mod store;
+
 pub mod broker;
 pub mod risk;
 pub mod strategy;
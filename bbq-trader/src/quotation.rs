@@ -1,17 +1,16 @@
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use bbq_core::{
-    data::mongo::{IndexDaily, MongoDB, StockDaily},
-    fetch::{is_index, is_trade_date, Fetcher, Quot, RtQuot, StockBar},
-    QuotBar, QuotData, QuotOpts, QuotStatus, RtQuotBar, FREQ_15M, FREQ_1D, FREQ_1M, FREQ_30M,
-    FREQ_5M, FREQ_60M,
+    data::MarketDataStore,
+    fetch::{is_index, AdjustMode, Fetcher, Quot, RtQuot, StockBar},
+    CorpAction, Price, QuotBar, QuotData, QuotOpts, QuotStatus, RtQuotBar, Session, TradeCalendar,
+    FREQ_15M, FREQ_1D, FREQ_1M, FREQ_30M, FREQ_5M, FREQ_60M,
 };
-use chrono::{Datelike, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
-use futures::TryStreamExt;
-use log::{debug, error, info};
-use mongodb::{bson::doc, options::FindOptions};
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
+use log::{debug, error, info, warn};
 use std::collections::{BTreeMap, HashSet};
 use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{
     broadcast,
@@ -29,6 +28,7 @@ pub trait Quotation: Sync + Send {
 pub struct MyQuotation {
     opts: QuotOpts,
     fetcher: Box<dyn Fetcher>,
+    calendar: Arc<TradeCalendar>,
 
     is_start: bool,
     is_end: bool,
@@ -71,25 +71,28 @@ impl MyQuotation {
         }
         Ok(None)
     }
+    /// 上午开盘/收盘, 下午开盘/收盘时间, 半日市下午开收盘时间同上午收盘
+    fn event_times(&self, date: NaiveDate) -> Option<[NaiveDateTime; 4]> {
+        let sessions = self.calendar.sessions(date);
+        let morning = *sessions.first()?;
+        let noon = sessions
+            .get(1)
+            .copied()
+            .unwrap_or_else(|| Session::new(morning.end, morning.end));
+        Some([
+            date.and_time(morning.start),
+            date.and_time(morning.end),
+            date.and_time(noon.start),
+            date.and_time(noon.end),
+        ])
+    }
     async fn get_base_event(&mut self, n: &NaiveDateTime) -> Result<Option<QuotData>> {
         if self.trade_date.is_some() && self.trade_date.unwrap() != n.date() {
-            for i in 0..4 {
-                if !self.base_event[i] {
-                    let date = self.trade_date.unwrap().format("%Y-%m-%d").to_string();
-                    let time = match i {
-                        0 => "09:30:00",
-                        1 => "11:30:00",
-                        2 => "13:00:00",
-                        3 => "15:00:00",
-                        _ => "",
-                    };
-                    let dt = NaiveDateTime::parse_from_str(
-                        format!("{} {}", date, time).as_str(),
-                        "%Y-%m-%d %H:%M:%S",
-                    )
-                    .unwrap();
-
-                    return self._base_event(i, &dt);
+            if let Some(times) = self.event_times(self.trade_date.unwrap()) {
+                for (i, time) in times.iter().enumerate() {
+                    if !self.base_event[i] {
+                        return self._base_event(i, time);
+                    }
                 }
             }
         }
@@ -99,15 +102,18 @@ impl MyQuotation {
                 self.base_event[i] = false;
             }
             self.trade_date = None;
-            if !is_trade_date(&n.date()) {
+            if !self.calendar.is_trading_day(n.date()) {
                 return Ok(None);
             }
             self.trade_date = Some(n.date());
         }
-        let m_start = NaiveDate::from_ymd(n.year(), n.month(), n.day()).and_hms(9, 15, 0);
-        let m_end = NaiveDate::from_ymd(n.year(), n.month(), n.day()).and_hms(11, 30, 0);
-        let n_start = NaiveDate::from_ymd(n.year(), n.month(), n.day()).and_hms(13, 0, 0);
-        let n_end = NaiveDate::from_ymd(n.year(), n.month(), n.day()).and_hms(15, 0, 0);
+        let times = match self.event_times(n.date()) {
+            Some(times) => times,
+            None => return Ok(None),
+        };
+        // 集合竞价开始
+        let m_start = times[0] - chrono::Duration::minutes(15);
+        let (m_end, n_start, n_end) = (times[1], times[2], times[3]);
 
         let idx = if *n > m_start && *n <= m_end {
             0
@@ -121,6 +127,12 @@ impl MyQuotation {
 
         self._base_event(idx, n)
     }
+    /// 基准随行情加载
+    fn add_benchmark(&mut self) {
+        if let Some(benchmark) = self.opts.benchmark.clone() {
+            self.add_quot_codes(&vec![benchmark]);
+        }
+    }
     fn add_quot_codes(&mut self, codes: &Vec<String>) {
         for code in codes.iter() {
             if !self.opts.codes.contains(code) {
@@ -136,16 +148,19 @@ pub struct RtQuotation {
 }
 
 impl RtQuotation {
-    pub fn new(opts: QuotOpts, fetcher: Box<dyn Fetcher>) -> Self {
+    pub fn new(opts: QuotOpts, fetcher: Box<dyn Fetcher>, calendar: Arc<TradeCalendar>) -> Self {
+        let mut quotation = MyQuotation {
+            opts,
+            fetcher,
+            calendar,
+            is_start: false,
+            is_end: false,
+            trade_date: None,
+            base_event: [false; 4],
+        };
+        quotation.add_benchmark();
         Self {
-            quotation: MyQuotation {
-                opts,
-                fetcher,
-                is_start: false,
-                is_end: false,
-                trade_date: None,
-                base_event: [false; 4],
-            },
+            quotation,
             bar: None,
         }
     }
@@ -154,7 +169,6 @@ impl RtQuotation {
             self.bar = Some(RtQuotBar::new());
         }
         let n = Local::now().naive_local();
-        let n_str = n.format("%Y-%m-%d %H:%M:%S").to_string();
 
         let (mut is_ready, mut is_test) = (false, false);
         let frequency = self.opts.frequency;
@@ -167,9 +181,10 @@ impl RtQuotation {
                     high: q.now,
                     low: q.now,
                     close: q.now,
-                    start: n_str.clone(),
-                    end: n_str.clone(),
+                    start: n,
+                    end: n,
                     quot: q.clone(),
+                    corp_action: None,
                 };
                 bar.insert(code.clone(), qb);
             }
@@ -182,15 +197,11 @@ impl RtQuotation {
                 qb.low = q.now;
             }
             qb.close = q.now;
-            qb.end = q.time.clone();
+            qb.end = q.time;
 
             if !is_test {
                 is_test = true;
-                let start = NaiveDateTime::parse_from_str(qb.start.as_str(), "%Y-%m-%d %H:%M:%S")
-                    .unwrap()
-                    .timestamp();
-                let end = n.timestamp();
-                if end - start >= qb.frequency as i64 {
+                if (n - qb.start).num_seconds() >= qb.frequency as i64 {
                     is_ready = true;
                 }
             }
@@ -257,28 +268,130 @@ pub struct BacktestQuotation {
     iter_vec: Vec<u64>,
     freq: Vec<u32>,
     codes: HashSet<String>,
-    db: Option<MongoDB>,
+    data_store: Option<Arc<dyn MarketDataStore>>,
 }
 
 impl BacktestQuotation {
-    pub fn new(opts: QuotOpts, fetcher: Box<dyn Fetcher>, db: Option<MongoDB>) -> Self {
+    pub fn new(
+        opts: QuotOpts,
+        fetcher: Box<dyn Fetcher>,
+        data_store: Option<Arc<dyn MarketDataStore>>,
+        calendar: Arc<TradeCalendar>,
+    ) -> Self {
+        let mut quotation = MyQuotation {
+            opts,
+            fetcher,
+            calendar,
+            is_start: false,
+            is_end: false,
+            trade_date: None,
+            base_event: [false; 4],
+        };
+        quotation.add_benchmark();
         Self {
-            quotation: MyQuotation {
-                opts,
-                fetcher,
-                is_start: false,
-                is_end: false,
-                trade_date: None,
-                base_event: [false; 4],
-            },
+            quotation,
             bar_list: BTreeMap::new(),
             index: 0,
             freq: vec![FREQ_1M, FREQ_5M, FREQ_15M, FREQ_30M, FREQ_60M, FREQ_1D],
             codes: HashSet::new(),
             iter_vec: vec![],
-            db,
+            data_store,
         }
     }
+
+    /// 除权除息, 优先取存储的除权除息数据, 否则由复权因子推算
+    ///
+    /// 复权因子无法区分派现与送转, 推算结果仅作为无除权除息数据时的兜底;
+    /// 无存储时复权因子取自行情源, 起始日之前无收盘价, 起始日的除权除息忽略
+    async fn load_corp_action(&self, code: &str, bars: &[StockBar]) -> Result<Vec<CorpAction>> {
+        if is_index(code) {
+            return Ok(vec![]);
+        }
+        let (start, end) = (self.opts.start_date, self.opts.end_date);
+        let mut closes: BTreeMap<NaiveDate, Price> = bars
+            .iter()
+            .map(|bar| (bar.time.date(), bar.close))
+            .collect();
+        let factors = match &self.data_store {
+            Some(store) => {
+                let actions = store
+                    .corp_action(code, start, end)
+                    .await
+                    .with_context(|| "query corp action failed")?;
+                if !actions.is_empty() {
+                    return Ok(actions);
+                }
+                let mut factors = store
+                    .fq_factor(code, start, end)
+                    .await
+                    .with_context(|| "query fq factor failed")?;
+                // 起始日除权需要起始日之前最后的复权因子及收盘价
+                if let Some(prev) = start.and_then(|start| start.pred_opt()) {
+                    let factor = store
+                        .fq_factor(code, None, Some(prev))
+                        .await
+                        .with_context(|| "query fq factor failed")?
+                        .pop();
+                    if let Some(factor) = factor {
+                        factors.insert(0, factor);
+                    }
+                    let bar = store
+                        .stock_daily(code, None, Some(prev))
+                        .await
+                        .with_context(|| "query stock daily failed")?
+                        .pop();
+                    if let Some(bar) = bar {
+                        closes.insert(bar.time.date(), bar.close);
+                    }
+                }
+                factors
+            }
+            None => {
+                warn!(
+                    "{}: no data store, derive corp actions from fetched fq factors",
+                    code
+                );
+                let factors = match self.fetcher.fetch_fq_factor(code).await {
+                    Ok(factors) => factors,
+                    Err(e) => {
+                        warn!(
+                            "{}: fetch fq factor failed, ignore corp actions: {:#}",
+                            code, e
+                        );
+                        return Ok(vec![]);
+                    }
+                };
+                // 保留区间内及起始日之前最后的复权因子
+                let first = factors
+                    .iter()
+                    .rposition(|f| start.is_some_and(|start| f.date < start))
+                    .unwrap_or(0);
+                factors
+                    .into_iter()
+                    .skip(first)
+                    .filter(|f| end.is_none_or(|end| f.date <= end))
+                    .collect()
+            }
+        };
+        let mut actions = vec![];
+        for w in factors.windows(2) {
+            let date = w[1].date;
+            let pre_close = closes.range(..date).next_back().map(|(_, close)| *close);
+            if let Some(pre_close) = pre_close {
+                let action = CorpAction::from_factor(
+                    code,
+                    date,
+                    pre_close,
+                    w[0].hfq_factor,
+                    w[1].hfq_factor,
+                );
+                if let Some(action) = action {
+                    actions.push(action);
+                }
+            }
+        }
+        Ok(actions)
+    }
 }
 impl Deref for BacktestQuotation {
     type Target = MyQuotation;
@@ -318,100 +431,50 @@ impl Quotation for BacktestQuotation {
                         .await
                         .with_context(|| "fetch stock minute error")?
                 } else {
-                    let mut q_data = Vec::new();
-                    if self.db.is_some() {
-                        let db = self.db.as_ref().unwrap();
-                        let filter = match (self.opts.start_date, self.opts.end_date) {
-                            (None, None) => doc! {"code": code.as_str()},
-                            (None, Some(end)) => {
-                                let s = end.and_hms(0, 0, 0);
-                                let s = Local.from_local_datetime(&s).unwrap();
-                                doc! {
-                                   "code": code.as_str(),
-                                   "trade_date": {"$lte": s}
-                                }
-                            }
-                            (Some(start), None) => {
-                                let s = start.and_hms(0, 0, 0);
-                                let s = Local.from_local_datetime(&s).unwrap();
-                                doc! {
-                                   "code": code.as_str(),
-                                   "trade_date": {"$gte": s}
-                                }
-                            }
-                            (Some(start), Some(end)) => {
-                                let s = start.and_hms(0, 0, 0);
-                                let s = Utc.from_local_datetime(&s).unwrap();
-
-                                let e = end.and_hms(0, 0, 0);
-                                let e = Utc.from_local_datetime(&e).unwrap();
-                                doc! {
-                                   "code": code.as_str(),
-                                   "trade_date": {"$gte": s, "$lte": e},
-                                }
-                            }
-                        };
-                        let opts = FindOptions::builder().sort(doc! {"trade_date": 1}).build();
-                        if is_index(code.as_str()) {
-                            let mut cursor = db
-                                .get_coll::<IndexDaily>("index_daily")?
-                                .find(filter, opts)
-                                .await
-                                .with_context(|| "query index_daily failed")?;
-                            while let Some(item) = cursor
-                                .try_next()
+                    let (start, end) = (self.opts.start_date, self.opts.end_date);
+                    let mut q_data = match &self.data_store {
+                        Some(store) if is_index(code.as_str()) => store
+                            .index_daily(code.as_str(), start, end)
+                            .await
+                            .with_context(|| "query index daily failed")?,
+                        Some(store) => store
+                            .stock_daily(code.as_str(), start, end)
+                            .await
+                            .with_context(|| "query stock daily failed")?,
+                        None => vec![],
+                    };
+                    if q_data.is_empty() {
+                        // 存储无数据时取自行情源, 不复权, 由除权除息事件调整持仓
+                        q_data = if is_index(code.as_str()) {
+                            self.fetcher
+                                .fetch_index_daily(code.as_str(), start, end)
                                 .await
-                                .with_context(|| "query index_daily failed")?
-                            {
-                                let time = item
-                                    .trade_date
-                                    .to_chrono()
-                                    .format("%Y-%m-%d %H:%M:%S")
-                                    .to_string();
-                                let s_bar = StockBar {
-                                    time,
-                                    open: item.open,
-                                    high: item.high,
-                                    low: item.low,
-                                    close: item.close,
-                                    vol: item.volume,
-                                };
-                                q_data.push(s_bar)
-                            }
+                                .with_context(|| "fetch index daily error")?
                         } else {
-                            let mut cursor = db
-                                .get_coll::<StockDaily>("stock_daily")?
-                                .find(filter, opts)
-                                .await
-                                .with_context(|| "query stock_daily failed")?;
-                            while let Some(item) = cursor
-                                .try_next()
+                            self.fetcher
+                                .fetch_daily(code.as_str(), start, end, AdjustMode::None)
                                 .await
-                                .with_context(|| "query stock_daily failed")?
-                            {
-                                let time = item
-                                    .trade_date
-                                    .to_chrono()
-                                    .format("%Y-%m-%d %H:%M:%S")
-                                    .to_string();
-                                let s_bar = StockBar {
-                                    time,
-                                    open: item.open,
-                                    high: item.high,
-                                    low: item.low,
-                                    close: item.close,
-                                    vol: item.volume as u64,
-                                };
-                                q_data.push(s_bar)
-                            }
+                                .with_context(|| "fetch stock daily error")?
+                        };
+                        // 与存储的日线时间(交易日 00:00:00)对齐
+                        for bar in q_data.iter_mut() {
+                            bar.time = bar.time.date().and_time(NaiveTime::MIN);
                         }
                     }
                     q_data
                 };
+                // 昨收取上一交易日最后一根k线的收盘价, 用于涨跌停检查
+                let mut last_close: Option<(NaiveDate, Price)> = None;
+                let mut pre_close = Price::ZERO;
                 for bar in r.iter() {
-                    let t = NaiveDateTime::parse_from_str(bar.time.as_str(), "%Y-%m-%d %H:%M:%S")
-                        .with_context(|| "parse time error")?;
-                    let t = t.timestamp();
+                    let date = bar.time.date();
+                    if let Some((last_date, close)) = last_close {
+                        if last_date != date {
+                            pre_close = close;
+                        }
+                    }
+                    last_close = Some((date, bar.close));
+                    let t = bar.time.and_utc().timestamp();
                     if !self.bar_list.contains_key(&(t as u64)) {
                         self.bar_list.insert(t as u64, RtQuotBar::new());
                     }
@@ -422,12 +485,10 @@ impl Quotation for BacktestQuotation {
                             frequency,
                             open: bar.open,
                             high: bar.high,
-                            low: bar.close,
-                            close: bar.high,
-                            start: NaiveDateTime::from_timestamp(t - (frequency as i64), 0)
-                                .format("%Y-%m-%d %H:%M:%S")
-                                .to_string(),
-                            end: bar.time.clone(),
+                            low: bar.low,
+                            close: bar.close,
+                            start: bar.time - chrono::Duration::seconds(frequency as i64),
+                            end: bar.time,
                             quot: Quot {
                                 code: code.clone(),
                                 open: bar.open,
@@ -437,21 +498,35 @@ impl Quotation for BacktestQuotation {
                                 buy: bar.close,
                                 sell: bar.close,
                                 vol: bar.vol,
-                                date: NaiveDateTime::parse_from_str(
-                                    &bar.time[..],
-                                    "%Y-%m-%d %H:%M:%S",
-                                )
-                                .unwrap()
-                                .date()
-                                .format("%Y-%m-%d")
-                                .to_string(),
-                                time: bar.time.clone(),
+                                pre_close,
+                                date,
+                                time: bar.time,
                                 ..Default::default()
                             },
+                            corp_action: None,
                         };
                         q_bar.insert(code.clone(), rt_q);
                     }
                 }
+                let actions = self
+                    .load_corp_action(code.as_str(), &r)
+                    .await
+                    .with_context(|| "load corp action error")?;
+                for action in actions {
+                    let date = action.date;
+                    let bar = self
+                        .bar_list
+                        .values_mut()
+                        .filter_map(|q_bar| q_bar.get_mut(&code))
+                        .find(|bar| Some(bar.end.date()) == date);
+                    if let Some(bar) = bar {
+                        // 除权除息日的涨跌停按除权除息参考价计算
+                        if bar.quot.pre_close > Price::ZERO {
+                            bar.quot.pre_close = action.ex_price(bar.quot.pre_close);
+                        }
+                        bar.corp_action = Some(action);
+                    }
+                }
             }
             self.iter_vec.clear();
             self.iter_vec.extend(self.bar_list.keys());
@@ -594,11 +669,17 @@ pub async fn run(
 #[cfg(test)]
 mod test_quotation {
     use crate::quotation::{BacktestQuotation, Quotation, RtQuotation};
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use bbq_core::data::{FqFactor, LocalStore, MarketDataStore, MongoDB};
     use bbq_core::fetch::Sina;
-    use bbq_core::{data::mongo::MongoDB, QuotOpts};
-    use chrono::NaiveDate;
+    use bbq_core::fetch::StockBar;
+    use bbq_core::fetch::{AdjustMode, Fetcher, RtQuot, StockBarList};
+    use bbq_core::{InstrumentRegistry, Price, QuotBar, QuotData, QuotOpts, TradeCalendar};
+    use chrono::{NaiveDate, NaiveDateTime};
     use mongodb::options::ClientOptions;
     use mongodb::Client;
+    use std::sync::Arc;
     use std::time::Duration;
     use tokio::time::sleep;
 
@@ -615,11 +696,16 @@ mod test_quotation {
             ],
             start_date: None,
             end_date: None,
+            benchmark: None,
         };
 
         let sina = Sina::new();
 
-        RtQuotation::new(quot_opts, Box::new(sina))
+        RtQuotation::new(
+            quot_opts,
+            Box::new(sina),
+            Arc::new(TradeCalendar::builtin()),
+        )
     }
 
     async fn bt_quot() -> BacktestQuotation {
@@ -633,6 +719,7 @@ mod test_quotation {
             ],
             start_date: Some(NaiveDate::parse_from_str("2022-03-01", "%Y-%m-%d").unwrap()),
             end_date: Some(NaiveDate::parse_from_str("2022-03-01", "%Y-%m-%d").unwrap()),
+            benchmark: Some("sh000300".to_string()),
         };
 
         let sina = Sina::new();
@@ -647,7 +734,253 @@ mod test_quotation {
 
         let s = MongoDB::new(client);
 
-        BacktestQuotation::new(quot_opts, Box::new(sina), Some(s))
+        BacktestQuotation::new(
+            quot_opts,
+            Box::new(sina),
+            Some(Arc::new(s)),
+            Arc::new(TradeCalendar::builtin()),
+        )
+    }
+
+    #[test]
+    fn test_base_event() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let half_day = NaiveDate::from_ymd_opt(2022, 3, 4).unwrap();
+            let calendar = TradeCalendar::parse("20220303,20220304")
+                .unwrap()
+                .with_half_day(half_day);
+            let mut quot = RtQuotation::new(
+                QuotOpts::default(),
+                Box::new(Sina::new()),
+                Arc::new(calendar),
+            );
+            let time = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+
+            let ev = quot.get_base_event(&time("2022-03-03 10:00:00")).await;
+            assert!(matches!(ev.unwrap(), Some(QuotData::MorningStart(_))));
+            assert!(quot.is_trading());
+
+            // 换日补发前一交易日未发出的事件
+            let mut events = vec![];
+            while let Some(ev) = quot
+                .get_base_event(&time("2022-03-04 12:00:00"))
+                .await
+                .unwrap()
+            {
+                events.push(ev);
+            }
+            assert_eq!(events.len(), 7);
+            match &events[2] {
+                QuotData::NoonEnd(status) => assert_eq!(status.time, time("2022-03-03 15:00:00")),
+                _ => panic!("expect noon end"),
+            }
+            // 半日市上午收盘后即为下午收盘
+            match &events[6] {
+                QuotData::NoonEnd(status) => assert_eq!(status.time, time("2022-03-04 12:00:00")),
+                _ => panic!("expect noon end"),
+            }
+            assert!(!quot.is_trading());
+
+            let ev = quot.get_base_event(&time("2022-03-05 10:00:00")).await;
+            assert!(ev.unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn test_bt_local() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            let path = std::env::temp_dir().join(format!("bbq-bt-local-{}", std::process::id()));
+            let store = LocalStore::open(&path).unwrap();
+            let bar = |d: u32, close: f64| StockBar {
+                time: date(d).and_hms_opt(0, 0, 0).unwrap(),
+                open: Price::from(close),
+                high: Price::from(close + 0.2),
+                low: Price::from(close - 0.2),
+                close: Price::from(close),
+                vol: 100,
+            };
+            store
+                .save_stock_daily("sh600063", &[bar(1, 5.5), bar(2, 5.0), bar(3, 5.1)])
+                .await
+                .unwrap();
+            let factor = |d: u32, hfq_factor: f64| FqFactor {
+                code: "sh600063".to_string(),
+                date: date(d),
+                hfq_factor,
+                qfq_factor: 1.0,
+            };
+            store
+                .save_fq_factor(&[factor(1, 1.0), factor(2, 1.1)])
+                .await
+                .unwrap();
+
+            // 无需数据库, 日线及除权除息取自本地存储
+            let opts = QuotOpts {
+                frequency: FREQ_1D,
+                codes: vec!["sh600063".to_string()],
+                start_date: Some(date(1)),
+                end_date: Some(date(3)),
+                ..Default::default()
+            };
+            let store = Arc::new(store);
+            let mut quot = BacktestQuotation::new(
+                opts.clone(),
+                Box::new(Sina::new()),
+                Some(store.clone()),
+                Arc::new(TradeCalendar::builtin()),
+            );
+            quot.add_codes(&vec![]).await.unwrap();
+            let bars: Vec<&QuotBar> = quot
+                .bar_list
+                .values()
+                .filter_map(|bars| bars.get("sh600063"))
+                .collect();
+            assert_eq!(bars.len(), 3);
+            assert_eq!(bars[0].quot.now, Price::from(5.5));
+            assert_eq!(bars[0].high, Price::from(5.7));
+            assert_eq!(bars[0].low, Price::from(5.3));
+            assert_eq!(bars[0].close, Price::from(5.5));
+            // 首日无昨收, 其后取上一日收盘价
+            assert_eq!(bars[0].quot.pre_close, Price::ZERO);
+            assert_eq!(bars[2].quot.pre_close, Price::from(5.0));
+            let mut registry = InstrumentRegistry::new();
+            let last = quot.bar_list.values().last().unwrap().clone();
+            registry.on_quot(&QuotData::Quot(last));
+            let pre_close = registry.pre_close("sh600063").unwrap();
+            let limit = registry.get("sh600063").unwrap().price_limit(pre_close);
+            assert_eq!(limit, Some((Price::from(4.5), Price::from(5.5))));
+            assert!(bars[0].corp_action.is_none());
+            let action = bars[1].corp_action.as_ref().unwrap();
+            assert_eq!(action.date, Some(date(2)));
+            assert!((action.share - 0.1).abs() < 1e-9);
+            assert_eq!(bars[1].quot.pre_close, Price::from(5.0));
+
+            // 起始日即除权日
+            let opts = QuotOpts {
+                start_date: Some(date(2)),
+                ..opts
+            };
+            let mut quot = BacktestQuotation::new(
+                opts,
+                Box::new(Sina::new()),
+                Some(store.clone()),
+                Arc::new(TradeCalendar::builtin()),
+            );
+            quot.add_codes(&vec![]).await.unwrap();
+            let bars: Vec<&QuotBar> = quot
+                .bar_list
+                .values()
+                .filter_map(|bars| bars.get("sh600063"))
+                .collect();
+            assert_eq!(bars.len(), 2);
+            let action = bars[0].corp_action.as_ref().unwrap();
+            assert_eq!(action.date, Some(date(2)));
+
+            drop(quot);
+            drop(store);
+            let _ = std::fs::remove_dir_all(&path);
+        });
+    }
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2022, 3, d).unwrap()
+    }
+
+    struct MockFetcher;
+
+    #[async_trait]
+    impl Fetcher for MockFetcher {
+        async fn fetch_stock_minute(&self, _code: &str, _min: u32) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_rt_quot(&self, _codes: &Vec<String>) -> Result<RtQuot> {
+            Ok(Default::default())
+        }
+
+        async fn fetch_daily(
+            &self,
+            _code: &str,
+            _start: Option<NaiveDate>,
+            _end: Option<NaiveDate>,
+            _adjust: AdjustMode,
+        ) -> Result<StockBarList> {
+            Ok([(1, 5.5), (2, 5.0), (3, 5.1)]
+                .into_iter()
+                .map(|(d, close)| StockBar {
+                    time: date(d).and_hms_opt(15, 0, 0).unwrap(),
+                    open: Price::from(close),
+                    high: Price::from(close),
+                    low: Price::from(close),
+                    close: Price::from(close),
+                    vol: 100,
+                })
+                .collect())
+        }
+
+        async fn fetch_index_daily(
+            &self,
+            _code: &str,
+            _start: Option<NaiveDate>,
+            _end: Option<NaiveDate>,
+        ) -> Result<StockBarList> {
+            Ok(vec![])
+        }
+
+        async fn fetch_fq_factor(&self, code: &str) -> Result<Vec<FqFactor>> {
+            let factor = |d: u32, hfq_factor: f64| FqFactor {
+                code: code.to_string(),
+                date: date(d),
+                hfq_factor,
+                qfq_factor: 1.0,
+            };
+            Ok(vec![factor(1, 1.0), factor(2, 1.1), factor(10, 1.2)])
+        }
+    }
+
+    #[test]
+    fn test_bt_fetcher() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        rt.block_on(async move {
+            // 无存储时除权除息由行情源的复权因子推算
+            let opts = QuotOpts {
+                frequency: FREQ_1D,
+                codes: vec!["sh600063".to_string()],
+                start_date: Some(date(1)),
+                end_date: Some(date(3)),
+                ..Default::default()
+            };
+            let mut quot = BacktestQuotation::new(
+                opts,
+                Box::new(MockFetcher),
+                None,
+                Arc::new(TradeCalendar::builtin()),
+            );
+            quot.add_codes(&vec![]).await.unwrap();
+            let bars: Vec<&QuotBar> = quot
+                .bar_list
+                .values()
+                .filter_map(|bars| bars.get("sh600063"))
+                .collect();
+            assert_eq!(bars.len(), 3);
+            assert!(bars[0].corp_action.is_none());
+            let action = bars[1].corp_action.as_ref().unwrap();
+            assert_eq!(action.date, Some(date(2)));
+            assert!((action.share - 0.1).abs() < 1e-9);
+            assert_eq!(bars[1].quot.pre_close, Price::from(5.0));
+            assert!(bars[2].corp_action.is_none());
+        });
     }
 
     #[test]
@@ -0,0 +1,156 @@
+use crate::journal::Journal;
+use anyhow::{Context, Result};
+use bbq_core::{analytics::Performance, Account, AccountSnapshot};
+use std::path::Path;
+
+/// 账户本地存储(sled)
+///
+/// - `account`: 账户id -> 账户
+/// - `snapshot:<账户id>`: 序号 -> 账户快照
+/// - `performance`: 账户id -> 回测绩效
+/// - `journal:<账户id>`: 序号 -> 交易日志
+#[derive(Clone)]
+pub struct Store {
+    db: sled::Db,
+}
+
+impl Store {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let db = sled::open(path.as_ref())
+            .with_context(|| format!("failed to open store: {:?}", path.as_ref()))?;
+        Ok(Self { db })
+    }
+
+    fn snapshot_tree(&self, account_id: &str) -> Result<sled::Tree> {
+        self.db
+            .open_tree(format!("snapshot:{}", account_id))
+            .with_context(|| format!("failed to open snapshot tree: {}", account_id))
+    }
+
+    pub fn save_account(&self, account: &Account) -> Result<()> {
+        let tree = self
+            .db
+            .open_tree("account")
+            .with_context(|| "failed to open account tree")?;
+        let value = serde_json::to_vec(account).with_context(|| "failed to serialize account")?;
+        tree.insert(account.account_id.as_bytes(), value)
+            .with_context(|| format!("failed to save account: {}", &account.account_id))?;
+        tree.flush().with_context(|| "failed to flush account tree")?;
+        Ok(())
+    }
+
+    pub fn load_account(&self, account_id: &str) -> Result<Option<Account>> {
+        let tree = self
+            .db
+            .open_tree("account")
+            .with_context(|| "failed to open account tree")?;
+        let value = tree
+            .get(account_id.as_bytes())
+            .with_context(|| format!("failed to load account: {}", account_id))?;
+        match value {
+            Some(value) => Ok(Some(
+                serde_json::from_slice(&value).with_context(|| "failed to deserialize account")?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    pub fn save_performance(&self, account_id: &str, performance: &Performance) -> Result<()> {
+        let tree = self
+            .db
+            .open_tree("performance")
+            .with_context(|| "failed to open performance tree")?;
+        let value =
+            serde_json::to_vec(performance).with_context(|| "failed to serialize performance")?;
+        tree.insert(account_id.as_bytes(), value)
+            .with_context(|| format!("failed to save performance: {}", account_id))?;
+        Ok(())
+    }
+
+    pub fn load_performance(&self, account_id: &str) -> Result<Option<Performance>> {
+        let tree = self
+            .db
+            .open_tree("performance")
+            .with_context(|| "failed to open performance tree")?;
+        let value = tree
+            .get(account_id.as_bytes())
+            .with_context(|| format!("failed to load performance: {}", account_id))?;
+        match value {
+            Some(value) => Ok(Some(
+                serde_json::from_slice(&value)
+                    .with_context(|| "failed to deserialize performance")?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// 保存第 `index` 个快照, 重复保存覆盖
+    pub fn save_snapshot(
+        &self,
+        account_id: &str,
+        index: usize,
+        snapshot: &AccountSnapshot,
+    ) -> Result<()> {
+        let tree = self.snapshot_tree(account_id)?;
+        let value = bincode::serialize(snapshot).with_context(|| "failed to serialize snapshot")?;
+        tree.insert((index as u64).to_be_bytes(), value)
+            .with_context(|| format!("failed to save snapshot: {}", account_id))?;
+        Ok(())
+    }
+
+    pub fn load_snapshot(&self, account_id: &str) -> Result<Vec<AccountSnapshot>> {
+        let tree = self.snapshot_tree(account_id)?;
+        let mut list = Vec::new();
+        for item in tree.iter() {
+            let (_, value) =
+                item.with_context(|| format!("failed to load snapshot: {}", account_id))?;
+            let snapshot =
+                bincode::deserialize(&value).with_context(|| "failed to deserialize snapshot")?;
+            list.push(snapshot);
+        }
+        Ok(list)
+    }
+
+    pub fn journal(&self, account_id: &str) -> Result<Journal> {
+        Journal::new(self.db.clone(), account_id)
+    }
+
+    pub fn flush(&self) -> Result<()> {
+        self.db.flush().with_context(|| "failed to flush store")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_store {
+    use super::Store;
+    use bbq_core::{Account, AccountSnapshot, Money, SnapshotType};
+
+    #[test]
+    fn test_store() {
+        let path = std::env::temp_dir().join(format!("bbq-store-{}", std::process::id()));
+        let store = Store::open(&path).unwrap();
+
+        let mut acct = Account::new("test".to_string());
+        acct.cash_init = Money::from(10000.0);
+        acct.cash_available = Money::from(10000.0);
+        acct.take_snapshot(SnapshotType::Bar, None);
+        acct.take_snapshot(SnapshotType::QuotEnd, None);
+        for (index, snapshot) in acct.snapshot.iter().enumerate() {
+            store.save_snapshot("test", index, snapshot).unwrap();
+        }
+        store.save_account(&acct).unwrap();
+
+        let list: Vec<AccountSnapshot> = store.load_snapshot("test").unwrap();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[1].typ, SnapshotType::QuotEnd);
+        assert_eq!(list[1].net_value, Money::from(10000.0));
+
+        let acct = store.load_account("test").unwrap().unwrap();
+        assert_eq!(acct.snapshot.len(), 2);
+        assert!(store.load_account("none").unwrap().is_none());
+
+        drop(store);
+        let _ = std::fs::remove_dir_all(&path);
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
     account::{self, AcctOpts},
-    config::Config,
+    config::{Config, DataStore},
+    store::Store,
 };
 use anyhow::{Context, Result};
 use backoff::{backoff::Backoff, ExponentialBackoff};
-use bbq_core::{data::mongo::MongoDB, Account, AcctType, Kind, QuotOpts};
+use bbq_core::{
+    data::{DataSync, LocalStore, MarketDataStore, MongoDB},
+    Account, AcctType, Kind, QuotOpts, TradeCalendar,
+};
 use chrono::NaiveDate;
-use log::{error, info};
+use log::{error, info, warn};
 use mongodb::{options::ClientOptions, Client};
 use std::collections::HashMap;
 use std::{
@@ -19,7 +23,9 @@ pub struct Trader {
     cfg: Config,
     shutdown: broadcast::Receiver<bool>,
     accounts: HashMap<String, Arc<RwLock<Account>>>,
-    db: Option<MongoDB>,
+    data_store: Option<Arc<dyn MarketDataStore>>,
+    store: Option<Store>,
+    calendar: Arc<TradeCalendar>,
 }
 
 impl Trader {
@@ -28,11 +34,13 @@ impl Trader {
             cfg,
             shutdown,
             accounts: HashMap::new(),
-            db: None,
+            data_store: None,
+            store: None,
+            calendar: Arc::new(TradeCalendar::builtin()),
         }
     }
 
-    async fn connect_mongo(&mut self) -> Result<()> {
+    async fn connect_mongo(&self) -> Result<Option<MongoDB>> {
         if let Some(db_uri) = &self.cfg.mongodb {
             let mut clt_opts = ClientOptions::parse(db_uri.as_str())
                 .await
@@ -45,26 +53,89 @@ impl Trader {
                 .list_databases(None, None)
                 .await
                 .with_context(|| format!("failed to connect to database: {}", db_uri))?;
-            self.db = Some(MongoDB::new(client));
+            return Ok(Some(MongoDB::new(client)));
         }
+        Ok(None)
+    }
+
+    async fn open_data_store(&mut self) -> Result<()> {
+        info!("data store: {}", &self.cfg.data_store);
+        self.data_store = match self.cfg.data_store {
+            DataStore::Mongo => self
+                .connect_mongo()
+                .await?
+                .map(|db| Arc::new(db) as Arc<dyn MarketDataStore>),
+            DataStore::Local => {
+                let path = format!("{}/market.db", &self.cfg.data_path);
+                Some(Arc::new(LocalStore::open(&path)?))
+            }
+        };
         Ok(())
     }
 
     pub async fn init(&mut self) -> Result<()> {
         fdlimit::raise_fd_limit();
 
-        self.connect_mongo().await?;
+        self.open_data_store().await?;
+        self.load_calendar().await;
 
         self.load_strategy().await?;
         Ok(())
     }
 
+    /// 加载失败时使用内置日历
+    async fn load_calendar(&mut self) {
+        let calendar = &self.cfg.calendar;
+        match calendar
+            .load(
+                &self.cfg.data_path,
+                self.data_store.as_deref(),
+                &self.cfg.quotation,
+            )
+            .await
+        {
+            Ok(calendar) => self.calendar = Arc::new(calendar),
+            Err(e) => warn!("load trade calendar failed: {:#}, use builtin", e),
+        }
+    }
+
+    /// 启用定时同步且有行情数据存储时, 后台同步行情数据
+    fn spawn_sync(&self, shutdown: broadcast::Receiver<bool>) -> Result<()> {
+        if !self.cfg.sync.enable {
+            return Ok(());
+        }
+        self.cfg.sync.opts.validate()?;
+        let store = match &self.data_store {
+            Some(store) => store.clone(),
+            None => {
+                warn!("data sync enabled but no data store");
+                return Ok(());
+            }
+        };
+        let fetcher = self.cfg.quotation.fetcher()?;
+        let sync = DataSync::new(
+            store,
+            fetcher,
+            self.calendar.clone(),
+            self.cfg.sync.opts.clone(),
+        );
+        info!(
+            "data sync enabled, {} at {}",
+            &self.cfg.sync.opts.mode, &self.cfg.sync.opts.time
+        );
+        tokio::spawn(async move {
+            sync.run(shutdown).await;
+        });
+        Ok(())
+    }
+
     async fn load_strategy(&mut self) -> Result<()> {
         Ok(())
     }
 
     async fn load_acct(&mut self) -> Result<()> {
-        // sled::open(path)
+        let path = format!("{}/bbq-trader.db", &self.cfg.data_path);
+        self.store = Some(Store::open(&path)?);
         Ok(())
     }
 
@@ -87,6 +158,7 @@ impl Trader {
         };
 
         let (s, _) = broadcast::channel(1);
+        self.spawn_sync(s.subscribe())?;
 
         loop {
             tokio::select! {
@@ -108,6 +180,11 @@ impl Trader {
 
                             let mut acct = Account::default();
                             acct.account_id = "TestAccount".to_string();
+                            acct.cash_init = self.cfg.init_cash;
+                            acct.cash_available = self.cfg.init_cash;
+                            acct.fee_model = self.cfg.fee.fee_model()?;
+                            acct.sync_policy = self.cfg.sync_policy.clone();
+                            acct.lot_method = self.cfg.lot_method.clone();
                             self.accounts.insert("test".to_string(), Arc::new(RwLock::new(acct)));
 
                             let acct = self.accounts.get(&"test".to_string())
@@ -133,8 +210,13 @@ impl Trader {
                                     ],
                                     start_date: Some(NaiveDate::parse_from_str("2022-03-01", "%Y-%m-%d")?),
                                     end_date: Some(NaiveDate::parse_from_str("2022-03-01", "%Y-%m-%d")?),
+                                    benchmark: self.cfg.benchmark.clone(),
                                 },
-                                db: self.db.clone(),
+                                quotation: self.cfg.quotation.clone(),
+                                calendar: self.calendar.clone(),
+                                data_store: self.data_store.clone(),
+                                store: self.store.clone(),
+                                risk_free: self.cfg.risk_free,
                                 // broker_path: Some("/Users/luoguochun/privt/proj/bbq-rs/target/debug/libbroker.dylib".to_string()),
                                 // broker_path: None,
                                 broker_path: Some("/Users/luoguochun/privt/proj/bbq-rs/bbq-strategy-py/example/example_broker.py".to_string()),